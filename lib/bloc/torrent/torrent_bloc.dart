@@ -157,7 +157,7 @@ class TorrentBloc extends Bloc<TorrentEvent, TorrentState> {
         debugPrint('Error creating directory: $e');
       }
 
-      final subscription = TorrentService.startDownload(source, outputDir).listen(
+      final subscription = TorrentService.startDownload(source, outputDir, selectedFileIndices: selectedFileIndices).listen(
         (status) {
           final progress = status.totalPieces.toInt() > 0
               ? status.completedPieces.toInt() / status.totalPieces.toInt()
@@ -170,7 +170,7 @@ class TorrentBloc extends Bloc<TorrentEvent, TorrentState> {
           add(UpdateTorrentProgress(
             index: index,
             progress: progress,
-            downloadSpeed: status.speedMbps * 1048576, // MB/s to B/s
+            downloadSpeed: status.speedMbps * 1048576, // MiB/s to B/s
             peers: status.peers.toInt(),
             status: itemStatus,
             totalSize: status.totalBytes.toInt(),
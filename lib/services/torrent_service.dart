@@ -1,3 +1,5 @@
+import 'dart:typed_data';
+
 import '../src/rust/api/simple.dart' as rust_api;
 import '../src/rust/frb_generated.dart';
 
@@ -51,9 +53,16 @@ class TorrentService {
     );
   }
 
-  /// Start downloading a torrent
-  static Stream<rust_api.AppTorrentStatus> startDownload(String source, String outputDir) {
-    return rust_api.startDownload(source: source, outputDir: outputDir);
+  /// Start downloading a torrent, only the selected files if any are given.
+  /// The session's default trackers are added unless [useDefaultTrackers] is false.
+  static Stream<rust_api.AppTorrentStatus> startDownload(String source, String outputDir,
+      {List<int>? selectedFileIndices, bool useDefaultTrackers = true}) {
+    return rust_api.startDownload(
+      source: source,
+      outputDir: outputDir,
+      selectedFileIndices: selectedFileIndices == null ? null : Uint64List.fromList(selectedFileIndices),
+      useDefaultTrackers: useDefaultTrackers,
+    );
   }
 
   /// Fetch metadata for a magnet link without starting download
@@ -62,10 +71,12 @@ class TorrentService {
   /// NOTE: This is a fallback implementation using parseMagnet.
   /// Full metadata (file sizes, piece count) requires the full Rust API.
   /// TODO: Once flutter_rust_bridge codegen works, use rust_api.fetchMagnetMetadata()
-  static Future<TorrentFileInfo> fetchMagnetMetadata(String magnetUri, {int timeoutSecs = 120}) async {
+  static Future<TorrentFileInfo> fetchMagnetMetadata(String magnetUri,
+      {int timeoutSecs = 120, bool useDefaultTrackers = true}) async {
     final info = await rust_api.fetchMagnetMetadata(
         magnetUri: magnetUri, 
-        timeoutSecs: timeoutSecs
+        timeoutSecs: timeoutSecs,
+        useDefaultTrackers: useDefaultTrackers,
     );
     
     return TorrentFileInfo(
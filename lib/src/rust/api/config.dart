@@ -0,0 +1,127 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_encryption`, `default_trackers`, `load_config`, `session_config`, `with_config`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Replace the session config, creating the configured directories
+Future<void>  setSessionConfig({required SessionConfig config }) => RustLib.instance.api.crateApiConfigSetSessionConfig(config: config);
+
+Future<SessionConfig>  getSessionConfig() => RustLib.instance.api.crateApiConfigGetSessionConfig();
+
+/// Restart the torrent session so settings librqbit only reads at startup
+/// (the ban list, the IP filter, the DHT, listen port, bind address and
+/// proxy settings) take effect.
+///
+/// Every torrent is added back under the same id, keeping its pause state
+/// and file selection, and its data is re-verified like on a recheck.
+/// Should the new settings keep the session from starting (a port in use,
+/// say), it is started with those it ran with before and the error is
+/// returned; the new settings stay saved for the next restart.
+Future<void>  restartSession() => RustLib.instance.api.crateApiConfigRestartSession();
+
+            /// librqbit has no protocol encryption, so every connection is plain:
+/// Prefer falls back to that, and Require is refused.
+enum EncryptionPolicy {
+                    disabled,
+prefer,
+require,
+                    ;
+                    static Future<EncryptionPolicy>  default_()=>RustLib.instance.api.crateApiConfigEncryptionPolicyDefault();
+
+
+                }
+
+/// A proxy for peer connections and tracker announces. librqbit's own
+/// announces follow the peer setting, and UDP trackers and the DHT are
+/// never proxied.
+class ProxyConfig  {
+                final ProxyType proxyType;
+final String host;
+final int port;
+final String? username;
+final String? password;
+final bool proxyPeers;
+final bool proxyTrackers;
+
+                const ProxyConfig({required this.proxyType ,required this.host ,required this.port ,this.username ,this.password ,required this.proxyPeers ,required this.proxyTrackers ,});
+
+                static Future<ProxyConfig>  default_()=>RustLib.instance.api.crateApiConfigProxyConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => proxyType.hashCode^host.hashCode^port.hashCode^username.hashCode^password.hashCode^proxyPeers.hashCode^proxyTrackers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProxyConfig &&
+                runtimeType == other.runtimeType
+                && proxyType == other.proxyType&& host == other.host&& port == other.port&& username == other.username&& password == other.password&& proxyPeers == other.proxyPeers&& proxyTrackers == other.proxyTrackers;
+        
+            }
+
+enum ProxyType {
+                    socks5,
+http,
+                    ;
+                    static Future<ProxyType>  default_()=>RustLib.instance.api.crateApiConfigProxyTypeDefault();
+
+
+                }
+
+/// Session-wide settings, applied to torrents added after they are set.
+/// They are saved and loaded again on the next launch.
+class SessionConfig  {
+                final String? incompleteDir;
+final String? completeDir;
+final bool partSuffix;
+final bool includeFileProgress;
+final List<String> defaultTrackers;
+final String? defaultTrackersFile;
+final int? dhtPort;
+final List<String> dhtBootstrapNodes;
+final int? listenPort;
+final int? listenPortMax;
+final bool upnp;
+final bool natpmp;
+final String? outgoingInterface;
+final String? bindAddress;
+final ProxyConfig? proxy;
+final EncryptionPolicy encryption;
+final int? maxActiveDownloads;
+
+                const SessionConfig({this.incompleteDir ,this.completeDir ,required this.partSuffix ,required this.includeFileProgress ,required this.defaultTrackers ,this.defaultTrackersFile ,this.dhtPort ,required this.dhtBootstrapNodes ,this.listenPort ,this.listenPortMax ,required this.upnp ,required this.natpmp ,this.outgoingInterface ,this.bindAddress ,this.proxy ,required this.encryption ,this.maxActiveDownloads ,});
+
+                static Future<SessionConfig>  default_()=>RustLib.instance.api.crateApiConfigSessionConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => incompleteDir.hashCode^completeDir.hashCode^partSuffix.hashCode^includeFileProgress.hashCode^defaultTrackers.hashCode^defaultTrackersFile.hashCode^dhtPort.hashCode^dhtBootstrapNodes.hashCode^listenPort.hashCode^listenPortMax.hashCode^upnp.hashCode^natpmp.hashCode^outgoingInterface.hashCode^bindAddress.hashCode^proxy.hashCode^encryption.hashCode^maxActiveDownloads.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionConfig &&
+                runtimeType == other.runtimeType
+                && incompleteDir == other.incompleteDir&& completeDir == other.completeDir&& partSuffix == other.partSuffix&& includeFileProgress == other.includeFileProgress&& defaultTrackers == other.defaultTrackers&& defaultTrackersFile == other.defaultTrackersFile&& dhtPort == other.dhtPort&& dhtBootstrapNodes == other.dhtBootstrapNodes&& listenPort == other.listenPort&& listenPortMax == other.listenPortMax&& upnp == other.upnp&& natpmp == other.natpmp&& outgoingInterface == other.outgoingInterface&& bindAddress == other.bindAddress&& proxy == other.proxy&& encryption == other.encryption&& maxActiveDownloads == other.maxActiveDownloads;
+        
+            }
+            
\ No newline at end of file
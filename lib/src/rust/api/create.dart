@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Create a .torrent for a local file or folder.
+///
+/// A file makes a single-file torrent, a folder a multi-file torrent named
+/// after it, holding every file below it. Hashing progress is streamed, and
+/// the last update carries the torrent. Closing the stream cancels it.
+Stream<CreateTorrentProgress>  createTorrent({required String path , required CreateTorrentOptions options }) => RustLib.instance.api.crateApiCreateCreateTorrent(path: path, options: options);
+
+            class CreateTorrentOptions  {
+                final String? name;
+final int? pieceLength;
+final List<String> trackers;
+final List<String> webSeeds;
+final String? comment;
+final bool private;
+final String? source;
+
+                const CreateTorrentOptions({this.name ,this.pieceLength ,required this.trackers ,required this.webSeeds ,this.comment ,required this.private ,this.source ,});
+
+                static Future<CreateTorrentOptions>  default_()=>RustLib.instance.api.crateApiCreateCreateTorrentOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^pieceLength.hashCode^trackers.hashCode^webSeeds.hashCode^comment.hashCode^private.hashCode^source.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateTorrentOptions &&
+                runtimeType == other.runtimeType
+                && name == other.name&& pieceLength == other.pieceLength&& trackers == other.trackers&& webSeeds == other.webSeeds&& comment == other.comment&& private == other.private&& source == other.source;
+        
+            }
+
+class CreateTorrentProgress  {
+                final BigInt hashedBytes;
+final BigInt totalBytes;
+final CreatedTorrent? torrent;
+
+                const CreateTorrentProgress({required this.hashedBytes ,required this.totalBytes ,this.torrent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hashedBytes.hashCode^totalBytes.hashCode^torrent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateTorrentProgress &&
+                runtimeType == other.runtimeType
+                && hashedBytes == other.hashedBytes&& totalBytes == other.totalBytes&& torrent == other.torrent;
+        
+            }
+
+class CreatedTorrent  {
+                final Uint8List torrentBytes;
+final String magnet;
+final String infoHash;
+final String name;
+final BigInt totalSize;
+final int pieceLength;
+final BigInt pieceCount;
+
+                const CreatedTorrent({required this.torrentBytes ,required this.magnet ,required this.infoHash ,required this.name ,required this.totalSize ,required this.pieceLength ,required this.pieceCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => torrentBytes.hashCode^magnet.hashCode^infoHash.hashCode^name.hashCode^totalSize.hashCode^pieceLength.hashCode^pieceCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreatedTorrent &&
+                runtimeType == other.runtimeType
+                && torrentBytes == other.torrentBytes&& magnet == other.magnet&& infoHash == other.infoHash&& name == other.name&& totalSize == other.totalSize&& pieceLength == other.pieceLength&& pieceCount == other.pieceCount;
+        
+            }
+            
\ No newline at end of file
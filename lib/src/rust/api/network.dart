@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'config.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `reset_incoming_seen`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            Future<DhtStats>  dhtStats() => RustLib.instance.api.crateApiNetworkDhtStats();
+
+Future<NetworkStatus>  networkStatus() => RustLib.instance.api.crateApiNetworkNetworkStatus();
+
+/// Check that `proxy` works: connect to it, authenticate and, if `target`
+/// ("host:port") is given, have it connect there. HTTP proxies can only be
+/// checked with a target. Returns the milliseconds taken; errors say which
+/// step failed.
+Future<int>  testProxy({required ProxyConfig proxy , String? target }) => RustLib.instance.api.crateApiNetworkTestProxy(proxy: proxy, target: target);
+
+            /// A bucket of the routing table: nodes whose id shares the first
+/// `prefix_bits` bits with the bucket's range
+class DhtBucket  {
+                final int prefixBits;
+final int nodes;
+
+                const DhtBucket({required this.prefixBits ,required this.nodes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => prefixBits.hashCode^nodes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DhtBucket &&
+                runtimeType == other.runtimeType
+                && prefixBits == other.prefixBits&& nodes == other.nodes;
+        
+            }
+
+/// State of the session's DHT
+class DhtStats  {
+                final bool enabled;
+final String nodeId;
+final int listenPort;
+final int nodes;
+final List<DhtBucket> buckets;
+final int outstandingQueries;
+
+                const DhtStats({required this.enabled ,required this.nodeId ,required this.listenPort ,required this.nodes ,required this.buckets ,required this.outstandingQueries ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => enabled.hashCode^nodeId.hashCode^listenPort.hashCode^nodes.hashCode^buckets.hashCode^outstandingQueries.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DhtStats &&
+                runtimeType == other.runtimeType
+                && enabled == other.enabled&& nodeId == other.nodeId&& listenPort == other.listenPort&& nodes == other.nodes&& buckets == other.buckets&& outstandingQueries == other.outstandingQueries;
+        
+            }
+
+/// Whether peers can reach us
+class NetworkStatus  {
+                final String? listenAddress;
+final String? portMapping;
+final String? externalAddress;
+final int? externalPort;
+final String? mappingError;
+final bool incomingSeen;
+final bool interfaceUp;
+final String? bindAddress;
+
+                const NetworkStatus({this.listenAddress ,this.portMapping ,this.externalAddress ,this.externalPort ,this.mappingError ,required this.incomingSeen ,required this.interfaceUp ,this.bindAddress ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => listenAddress.hashCode^portMapping.hashCode^externalAddress.hashCode^externalPort.hashCode^mappingError.hashCode^incomingSeen.hashCode^interfaceUp.hashCode^bindAddress.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NetworkStatus &&
+                runtimeType == other.runtimeType
+                && listenAddress == other.listenAddress&& portMapping == other.portMapping&& externalAddress == other.externalAddress&& externalPort == other.externalPort&& mappingError == other.mappingError&& incomingSeen == other.incomingSeen&& interfaceUp == other.interfaceUp&& bindAddress == other.bindAddress;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,152 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `banned_ips`, `disconnect_blocked`, `is_blocked`, `load_bans`, `load_saved_filter`, `parse_ip`, `save_bans`, `save_filter`, `take_ip_filter_ranges`, `update_bans`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LoadedFilter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// List the peers a torrent is currently connected to.
+/// Rates are averaged since the previous call for the same torrent, so the
+/// first call reports 0.
+Future<List<PeerInfo>>  getPeers({required BigInt id }) => RustLib.instance.api.crateApiPeersGetPeers(id: id);
+
+/// Connect a torrent to a specific peer, e.g. a private seedbox.
+///
+/// `address` is "ip:port" or "host:port". librqbit only takes extra peers
+/// when a torrent is added, so the torrent is re-added with the peer, which
+/// re-verifies its data like recheck_torrent. The peer is connected to
+/// again at every later add of the torrent.
+Future<void>  addPeer({required BigInt id , required String address }) => RustLib.instance.api.crateApiPeersAddPeer(id: id, address: address);
+
+/// Ban an IP from every torrent, for incoming and outgoing connections.
+///
+/// Bans are saved with the session config. Connections to the IP are dropped
+/// right away, but librqbit reads its blocklist when the session starts, so
+/// the peer is only refused from the next `restart_session` or app launch and
+/// may be connected to again until then, which the result tells.
+Future<PeerBan>  banPeer({required String address }) => RustLib.instance.api.crateApiPeersBanPeer(address: address);
+
+/// Lift a ban, from the next session start like ban_peer
+Future<void>  unbanPeer({required String address }) => RustLib.instance.api.crateApiPeersUnbanPeer(address: address);
+
+Future<List<String>>  listBans() => RustLib.instance.api.crateApiPeersListBans();
+
+/// Load an IP filter in the eMule (ipfilter.dat) or P2P format, plain or
+/// gzip-compressed, replacing the current one.
+///
+/// The filter is saved and, like bans, handed to librqbit when the session
+/// starts. Connections to blocked peers are dropped right away, but new ones
+/// are only refused from the next `restart_session` or app launch.
+Future<IpFilterStats>  loadIpFilter({required String path }) => RustLib.instance.api.crateApiPeersLoadIpFilter(path: path);
+
+/// Remove the IP filter, from the next session start like load_ip_filter
+Future<void>  clearIpFilter() => RustLib.instance.api.crateApiPeersClearIpFilter();
+
+Future<IpFilterStats>  ipFilterStats() => RustLib.instance.api.crateApiPeersIpFilterStats();
+
+            class IpFilterStats  {
+                final String? source;
+final String? format;
+final BigInt entries;
+final BigInt allowedEntries;
+final BigInt invalidLines;
+final BigInt ipv4Ranges;
+final BigInt ipv6Ranges;
+final BigInt ipv4Addresses;
+final bool active;
+
+                const IpFilterStats({this.source ,this.format ,required this.entries ,required this.allowedEntries ,required this.invalidLines ,required this.ipv4Ranges ,required this.ipv6Ranges ,required this.ipv4Addresses ,required this.active ,});
+
+                static Future<IpFilterStats>  default_()=>RustLib.instance.api.crateApiPeersIpFilterStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^format.hashCode^entries.hashCode^allowedEntries.hashCode^invalidLines.hashCode^ipv4Ranges.hashCode^ipv6Ranges.hashCode^ipv4Addresses.hashCode^active.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IpFilterStats &&
+                runtimeType == other.runtimeType
+                && source == other.source&& format == other.format&& entries == other.entries&& allowedEntries == other.allowedEntries&& invalidLines == other.invalidLines&& ipv4Ranges == other.ipv4Ranges&& ipv6Ranges == other.ipv6Ranges&& ipv4Addresses == other.ipv4Addresses&& active == other.active;
+        
+            }
+
+/// What banning an IP did to the running session
+class PeerBan  {
+                final int disconnected;
+final bool active;
+
+                const PeerBan({required this.disconnected ,required this.active ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => disconnected.hashCode^active.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PeerBan &&
+                runtimeType == other.runtimeType
+                && disconnected == other.disconnected&& active == other.active;
+        
+            }
+
+enum PeerDirection {
+                    incoming,
+outgoing,
+                    ;
+                    
+                }
+
+/// One connected peer of a torrent.
+///
+/// librqbit 8 only exposes per-peer download counters, so there is nothing to
+/// report about the peer's client, choke state, upload or its own progress.
+/// It has no protocol encryption either, so no connection is encrypted.
+class PeerInfo  {
+                final String address;
+final PeerDirection direction;
+final bool encrypted;
+final BigInt downloadBps;
+final BigInt downloadedBytes;
+final int piecesDownloaded;
+final int errors;
+
+                const PeerInfo({required this.address ,required this.direction ,required this.encrypted ,required this.downloadBps ,required this.downloadedBytes ,required this.piecesDownloaded ,required this.errors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => address.hashCode^direction.hashCode^encrypted.hashCode^downloadBps.hashCode^downloadedBytes.hashCode^piecesDownloaded.hashCode^errors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PeerInfo &&
+                runtimeType == other.runtimeType
+                && address == other.address&& direction == other.direction&& encrypted == other.encrypted&& downloadBps == other.downloadBps&& downloadedBytes == other.downloadedBytes&& piecesDownloaded == other.piecesDownloaded&& errors == other.errors;
+        
+            }
+            
\ No newline at end of file
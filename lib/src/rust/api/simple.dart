@@ -6,174 +6,375 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
-
-Future<MagnetInfo> parseMagnet({required String uri}) =>
-    RustLib.instance.api.crateApiSimpleParseMagnet(uri: uri);
-
-Future<TorrentInfo> getTorrentInfoFile({required String path}) =>
-    RustLib.instance.api.crateApiSimpleGetTorrentInfoFile(path: path);
-
-Stream<AppTorrentStatus> startDownload(
-        {required String source,
-        required String outputDir,
-        Uint64List? selectedFileIndices}) =>
-    RustLib.instance.api.crateApiSimpleStartDownload(
-        source: source,
-        outputDir: outputDir,
-        selectedFileIndices: selectedFileIndices);
-
-Future<TorrentInfo> fetchMagnetMetadata(
-        {required String magnetUri, required int timeoutSecs}) =>
-    RustLib.instance.api.crateApiSimpleFetchMagnetMetadata(
-        magnetUri: magnetUri, timeoutSecs: timeoutSecs);
-
-Future<List<AppTorrentStatus>> getTorrents() =>
-    RustLib.instance.api.crateApiSimpleGetTorrents();
-
-class AppTorrentStatus {
-  final int totalPieces;
-  final int completedPieces;
-  final int peers;
-  final double speedMbps;
-  final bool downloading;
-  final bool isFetchingMetadata;
-  final String statusMessage;
-  final String? error;
-  final BigInt totalBytes;
-  final BigInt downloadedBytes;
-
-  const AppTorrentStatus({
-    required this.totalPieces,
-    required this.completedPieces,
-    required this.peers,
-    required this.speedMbps,
-    required this.downloading,
-    required this.isFetchingMetadata,
-    required this.statusMessage,
-    this.error,
-    required this.totalBytes,
-    required this.downloadedBytes,
-  });
-
-  @override
-  int get hashCode =>
-      totalPieces.hashCode ^
-      completedPieces.hashCode ^
-      peers.hashCode ^
-      speedMbps.hashCode ^
-      downloading.hashCode ^
-      isFetchingMetadata.hashCode ^
-      statusMessage.hashCode ^
-      error.hashCode ^
-      totalBytes.hashCode ^
-      downloadedBytes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is AppTorrentStatus &&
-          runtimeType == other.runtimeType &&
-          totalPieces == other.totalPieces &&
-          completedPieces == other.completedPieces &&
-          peers == other.peers &&
-          speedMbps == other.speedMbps &&
-          downloading == other.downloading &&
-          isFetchingMetadata == other.isFetchingMetadata &&
-          statusMessage == other.statusMessage &&
-          error == other.error &&
-          totalBytes == other.totalBytes &&
-          downloadedBytes == other.downloadedBytes;
-}
-
-class FileInfo {
-  final String path;
-  final BigInt size;
-
-  const FileInfo({
-    required this.path,
-    required this.size,
-  });
-
-  @override
-  int get hashCode => path.hashCode ^ size.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FileInfo &&
-          runtimeType == other.runtimeType &&
-          path == other.path &&
-          size == other.size;
-}
-
-class MagnetInfo {
-  final String url;
-  final String infoHash;
-  final String? name;
-  final List<String> trackers;
-
-  const MagnetInfo({
-    required this.url,
-    required this.infoHash,
-    this.name,
-    required this.trackers,
-  });
-
-  @override
-  int get hashCode =>
-      url.hashCode ^ infoHash.hashCode ^ name.hashCode ^ trackers.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is MagnetInfo &&
-          runtimeType == other.runtimeType &&
-          url == other.url &&
-          infoHash == other.infoHash &&
-          name == other.name &&
-          trackers == other.trackers;
-}
-
-class TorrentInfo {
-  final String name;
-  final BigInt totalSize;
-  final BigInt pieceCount;
-  final BigInt pieceLength;
-  final List<FileInfo> files;
-  final String infoHash;
-  final String announce;
-
-  const TorrentInfo({
-    required this.name,
-    required this.totalSize,
-    required this.pieceCount,
-    required this.pieceLength,
-    required this.files,
-    required this.infoHash,
-    required this.announce,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      totalSize.hashCode ^
-      pieceCount.hashCode ^
-      pieceLength.hashCode ^
-      files.hashCode ^
-      infoHash.hashCode ^
-      announce.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TorrentInfo &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          totalSize == other.totalSize &&
-          pieceCount == other.pieceCount &&
-          pieceLength == other.pieceLength &&
-          files == other.files &&
-          infoHash == other.infoHash &&
-          announce == other.announce;
-}
+
+            // These functions are ignored because they are not marked as `pub`: `check_downloadable`, `check_magnet_downloadable`, `default_trackers_for`, `download_location`, `file_infos`, `file_progress`, `file_tree`, `librqbit_magnet`, `merkle_roots`, `prepare_add`, `register_download`, `torrent_info_from_metadata`, `torrent_info_from_v2`, `torrent_status`, `torrent_trackers`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PreparedAdd`, `TorrentTrackers`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            Future<MagnetInfo>  parseMagnet({required String uri }) => RustLib.instance.api.crateApiSimpleParseMagnet(uri: uri);
+
+Future<TorrentInfo>  getTorrentInfoFile({required String path }) => RustLib.instance.api.crateApiSimpleGetTorrentInfoFile(path: path);
+
+/// Add a torrent and stream its status until it completes.
+///
+/// `source` is a magnet link, a .torrent file path, or the http(s) URL of a
+/// .torrent file, which is downloaded first.
+///
+/// `use_existing_data_path` is the save folder another client used for the
+/// same torrent: it contains the torrent's file (single-file torrents) or its
+/// top-level folder (multi-file torrents). The data there is verified and the
+/// download continues in place instead of in `output_dir`, so only missing or
+/// corrupt pieces are fetched.
+///
+/// Otherwise the session config decides where data goes: it is written to
+/// `incomplete_dir` (or `output_dir`), optionally with a ".part" suffix per
+/// file, and moved to `complete_dir` (or `output_dir`) once finished.
+///
+/// The session's default trackers are added to the torrent's own unless
+/// `use_default_trackers` is false. Web seeds, from the torrent's url-list
+/// or the magnet link's ws= parameters, are downloaded from alongside peers.
+Stream<AppTorrentStatus>  startDownload({required String source , required String outputDir , Uint64List? selectedFileIndices , String? useExistingDataPath , required bool useDefaultTrackers }) => RustLib.instance.api.crateApiSimpleStartDownload(source: source, outputDir: outputDir, selectedFileIndices: selectedFileIndices, useExistingDataPath: useExistingDataPath, useDefaultTrackers: useDefaultTrackers);
+
+/// Add several torrents at once, from magnet links, .torrent files (local
+/// or http(s) URLs) or their contents, with a result per request in the
+/// same order.
+///
+/// Every request is checked before anything is added, and one that fails
+/// doesn't stop the others. Torrents already in the session, or earlier in
+/// the batch, aren't added again; their result has the existing id.
+///
+/// With `max_active_downloads` set in the session config, torrents beyond
+/// the free download slots are added paused and started in order as other
+/// downloads finish. Magnet links are added once their metadata is resolved,
+/// in the background: their id is set right away, and get_torrents lists
+/// them from then on. A torrent that then fails to be added is dropped, and
+/// its id refers to nothing. Data goes where start_download would put it.
+Future<List<AddResult>>  addTorrentsBatch({required List<AddRequest> requests }) => RustLib.instance.api.crateApiSimpleAddTorrentsBatch(requests: requests);
+
+/// Seed a torrent from data already on disk, such as the content a torrent
+/// was just created from, without downloading or copying anything.
+///
+/// `content_path` is the torrent's file (single-file torrents) or its
+/// top-level folder (multi-file torrents), named as in the torrent. Every
+/// piece is verified before the torrent is kept; if any fails, it is
+/// removed again, leaving the data untouched. Returns the torrent id.
+Future<BigInt>  seedLocal({required List<int> torrentBytes , required String contentPath }) => RustLib.instance.api.crateApiSimpleSeedLocal(torrentBytes: torrentBytes, contentPath: contentPath);
+
+/// Resolve a magnet link's metadata from peers, found with the link's
+/// trackers, the DHT and, unless `use_default_trackers` is false, the
+/// session's default trackers.
+Future<TorrentInfo>  fetchMagnetMetadata({required String magnetUri , required int timeoutSecs , required bool useDefaultTrackers }) => RustLib.instance.api.crateApiSimpleFetchMagnetMetadata(magnetUri: magnetUri, timeoutSecs: timeoutSecs, useDefaultTrackers: useDefaultTrackers);
+
+Future<List<AppTorrentStatus>>  getTorrents() => RustLib.instance.api.crateApiSimpleGetTorrents();
+
+/// Force a recheck of a torrent's data on disk.
+///
+/// The torrent is removed from the session and re-added under the same id,
+/// which makes librqbit re-hash every piece through our storage. Pieces that
+/// fail verification are downloaded again. Progress shows up in the status
+/// stream with `is_checking` set. If it can't be added back (its folder is
+/// gone, say), the error is returned and the torrent is kept out of the
+/// session until the next recheck or session restart adds it.
+Future<void>  recheckTorrent({required BigInt id }) => RustLib.instance.api.crateApiSimpleRecheckTorrent(id: id);
+
+/// Move a torrent's data to `new_dir`.
+///
+/// The torrent is paused while its files are moved (copied and deleted when
+/// `new_dir` is on another filesystem) and resumed afterwards if it was
+/// running. Progress shows up in the status stream with `is_moving` set. If
+/// any file can't be moved, everything is put back and the error returned.
+/// Should putting a file back fail too, the torrent stays wherever most of its
+/// data ended up and the error lists the files left at the other directory.
+Future<void>  moveStorage({required BigInt id , required String newDir }) => RustLib.instance.api.crateApiSimpleMoveStorage(id: id, newDir: newDir);
+
+/// Download a torrent's pieces in order so playback can start early.
+///
+/// With `file_index` only that file is downloaded front to back, otherwise
+/// every selected file is, in torrent order. The mode ends by itself once
+/// those files are complete; `enabled: false` ends it early.
+Future<void>  setSequentialDownload({required BigInt id , required bool enabled , BigInt? fileIndex }) => RustLib.instance.api.crateApiSimpleSetSequentialDownload(id: id, enabled: enabled, fileIndex: fileIndex);
+
+/// Get a local HTTP URL serving one file of a torrent, for media players.
+///
+/// The file can be played while it downloads: Range requests are supported,
+/// the pieces a player reads are downloaded first, and reads of data that is
+/// not there yet wait for it. The URL only works while the app is running.
+Future<String>  streamFile({required BigInt id , required BigInt fileIndex }) => RustLib.instance.api.crateApiSimpleStreamFile(id: id, fileIndex: fileIndex);
+
+/// List a torrent's files with their priority and downloaded bytes
+Future<List<FileInfo>>  getTorrentFiles({required BigInt id }) => RustLib.instance.api.crateApiSimpleGetTorrentFiles(id: id);
+
+/// Change the priority of some of a torrent's files, by index.
+///
+/// librqbit only selects or deselects files, so priorities are approximated:
+/// Skip deselects a file, Low files are selected once every Normal and High
+/// file is complete, and High files have their pieces requested first.
+Future<void>  setFilePriorities({required BigInt id , required List<(BigInt,FilePriority)> priorities }) => RustLib.instance.api.crateApiSimpleSetFilePriorities(id: id, priorities: priorities);
+
+/// Progress of each file of a torrent
+Future<List<FileProgress>>  getFileProgress({required BigInt id }) => RustLib.instance.api.crateApiSimpleGetFileProgress(id: id);
+
+            class AddRequest  {
+                final String? source;
+final Uint8List? torrentBytes;
+final String outputDir;
+final Uint64List? selectedFileIndices;
+final bool useDefaultTrackers;
+
+                const AddRequest({this.source ,this.torrentBytes ,required this.outputDir ,this.selectedFileIndices ,required this.useDefaultTrackers ,});
+
+                static Future<AddRequest>  default_()=>RustLib.instance.api.crateApiSimpleAddRequestDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^torrentBytes.hashCode^outputDir.hashCode^selectedFileIndices.hashCode^useDefaultTrackers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AddRequest &&
+                runtimeType == other.runtimeType
+                && source == other.source&& torrentBytes == other.torrentBytes&& outputDir == other.outputDir&& selectedFileIndices == other.selectedFileIndices&& useDefaultTrackers == other.useDefaultTrackers;
+        
+            }
+
+class AddResult  {
+                final BigInt? id;
+final String? infoHash;
+final String? name;
+final bool alreadyAdded;
+final bool queued;
+final String? error;
+
+                const AddResult({this.id ,this.infoHash ,this.name ,required this.alreadyAdded ,required this.queued ,this.error ,});
+
+                static Future<AddResult>  default_()=>RustLib.instance.api.crateApiSimpleAddResultDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^infoHash.hashCode^name.hashCode^alreadyAdded.hashCode^queued.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AddResult &&
+                runtimeType == other.runtimeType
+                && id == other.id&& infoHash == other.infoHash&& name == other.name&& alreadyAdded == other.alreadyAdded&& queued == other.queued&& error == other.error;
+        
+            }
+
+class AppTorrentStatus  {
+                final BigInt id;
+final int totalPieces;
+final int completedPieces;
+final int peers;
+final double speedMbps;
+final double webSeedSpeedMbps;
+final bool downloading;
+final bool isFetchingMetadata;
+final bool isChecking;
+final bool isMoving;
+final bool isSequential;
+final bool isNetworkPaused;
+final bool isQueued;
+final String statusMessage;
+final String? error;
+final BigInt totalBytes;
+final BigInt downloadedBytes;
+final BigInt webSeedDownloadedBytes;
+final List<FileProgress>? files;
+
+                const AppTorrentStatus({required this.id ,required this.totalPieces ,required this.completedPieces ,required this.peers ,required this.speedMbps ,required this.webSeedSpeedMbps ,required this.downloading ,required this.isFetchingMetadata ,required this.isChecking ,required this.isMoving ,required this.isSequential ,required this.isNetworkPaused ,required this.isQueued ,required this.statusMessage ,this.error ,required this.totalBytes ,required this.downloadedBytes ,required this.webSeedDownloadedBytes ,this.files ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^totalPieces.hashCode^completedPieces.hashCode^peers.hashCode^speedMbps.hashCode^webSeedSpeedMbps.hashCode^downloading.hashCode^isFetchingMetadata.hashCode^isChecking.hashCode^isMoving.hashCode^isSequential.hashCode^isNetworkPaused.hashCode^isQueued.hashCode^statusMessage.hashCode^error.hashCode^totalBytes.hashCode^downloadedBytes.hashCode^webSeedDownloadedBytes.hashCode^files.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AppTorrentStatus &&
+                runtimeType == other.runtimeType
+                && id == other.id&& totalPieces == other.totalPieces&& completedPieces == other.completedPieces&& peers == other.peers&& speedMbps == other.speedMbps&& webSeedSpeedMbps == other.webSeedSpeedMbps&& downloading == other.downloading&& isFetchingMetadata == other.isFetchingMetadata&& isChecking == other.isChecking&& isMoving == other.isMoving&& isSequential == other.isSequential&& isNetworkPaused == other.isNetworkPaused&& isQueued == other.isQueued&& statusMessage == other.statusMessage&& error == other.error&& totalBytes == other.totalBytes&& downloadedBytes == other.downloadedBytes&& webSeedDownloadedBytes == other.webSeedDownloadedBytes&& files == other.files;
+        
+            }
+
+class FileInfo  {
+                final BigInt index;
+final String path;
+final BigInt size;
+final String? originalPath;
+final FilePriority priority;
+final BigInt downloadedBytes;
+final String? merkleRoot;
+
+                const FileInfo({required this.index ,required this.path ,required this.size ,this.originalPath ,required this.priority ,required this.downloadedBytes ,this.merkleRoot ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^path.hashCode^size.hashCode^originalPath.hashCode^priority.hashCode^downloadedBytes.hashCode^merkleRoot.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileInfo &&
+                runtimeType == other.runtimeType
+                && index == other.index&& path == other.path&& size == other.size&& originalPath == other.originalPath&& priority == other.priority&& downloadedBytes == other.downloadedBytes&& merkleRoot == other.merkleRoot;
+        
+            }
+
+enum FilePriority {
+                    skip,
+low,
+normal,
+high,
+                    ;
+                    static Future<FilePriority>  default_()=>RustLib.instance.api.crateApiSimpleFilePriorityDefault();
+
+
+                }
+
+class FileProgress  {
+                final BigInt index;
+final String path;
+final BigInt size;
+final BigInt downloaded;
+final bool complete;
+
+                const FileProgress({required this.index ,required this.path ,required this.size ,required this.downloaded ,required this.complete ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^path.hashCode^size.hashCode^downloaded.hashCode^complete.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileProgress &&
+                runtimeType == other.runtimeType
+                && index == other.index&& path == other.path&& size == other.size&& downloaded == other.downloaded&& complete == other.complete;
+        
+            }
+
+class MagnetInfo  {
+                final String url;
+final String infoHash;
+final String? infoHashV2;
+final String? name;
+final List<String> trackers;
+final List<String> webSeeds;
+
+                const MagnetInfo({required this.url ,required this.infoHash ,this.infoHashV2 ,this.name ,required this.trackers ,required this.webSeeds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^infoHash.hashCode^infoHashV2.hashCode^name.hashCode^trackers.hashCode^webSeeds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MagnetInfo &&
+                runtimeType == other.runtimeType
+                && url == other.url&& infoHash == other.infoHash&& infoHashV2 == other.infoHashV2&& name == other.name&& trackers == other.trackers&& webSeeds == other.webSeeds;
+        
+            }
+
+enum MetaVersion {
+                    v1,
+v2,
+hybrid,
+                    ;
+                    static Future<MetaVersion>  default_()=>RustLib.instance.api.crateApiSimpleMetaVersionDefault();
+
+
+                }
+
+class TorrentInfo  {
+                final String name;
+final BigInt totalSize;
+final BigInt pieceCount;
+final BigInt pieceLength;
+final List<FileInfo> files;
+final String infoHash;
+final String? infoHashV2;
+final MetaVersion metaVersion;
+final String announce;
+final List<String> webSeeds;
+final List<V2FileInfo> fileTree;
+
+                const TorrentInfo({required this.name ,required this.totalSize ,required this.pieceCount ,required this.pieceLength ,required this.files ,required this.infoHash ,this.infoHashV2 ,required this.metaVersion ,required this.announce ,required this.webSeeds ,required this.fileTree ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^totalSize.hashCode^pieceCount.hashCode^pieceLength.hashCode^files.hashCode^infoHash.hashCode^infoHashV2.hashCode^metaVersion.hashCode^announce.hashCode^webSeeds.hashCode^fileTree.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TorrentInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& totalSize == other.totalSize&& pieceCount == other.pieceCount&& pieceLength == other.pieceLength&& files == other.files&& infoHash == other.infoHash&& infoHashV2 == other.infoHashV2&& metaVersion == other.metaVersion&& announce == other.announce&& webSeeds == other.webSeeds&& fileTree == other.fileTree;
+        
+            }
+
+class V2FileInfo  {
+                final String path;
+final BigInt size;
+final String? merkleRoot;
+
+                const V2FileInfo({required this.path ,required this.size ,this.merkleRoot ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^size.hashCode^merkleRoot.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is V2FileInfo &&
+                runtimeType == other.runtimeType
+                && path == other.path&& size == other.size&& merkleRoot == other.merkleRoot;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,160 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `parse_tracker`, `unix_time`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// The trackers of a torrent by tier, with their status
+Future<List<TrackerInfo>>  getTrackers({required BigInt id }) => RustLib.instance.api.crateApiTrackersGetTrackers(id: id);
+
+/// Add trackers to a torrent, each in a tier of its own after the existing
+/// ones. Trackers it already has are skipped.
+///
+/// librqbit only reads trackers when a torrent is added, so the torrent is
+/// re-added with the new trackers in its torrent file, which re-verifies its
+/// data like recheck_torrent. librqbit then announces to them itself.
+Future<void>  addTrackers({required BigInt id , required List<String> urls }) => RustLib.instance.api.crateApiTrackersAddTrackers(id: id, urls: urls);
+
+/// Remove a tracker from a torrent. Like add_trackers, the torrent is
+/// re-added without it for librqbit to stop announcing to it.
+Future<void>  removeTracker({required BigInt id , required String url }) => RustLib.instance.api.crateApiTrackersRemoveTracker(id: id, url: url);
+
+/// The web seeds of a torrent, from its url-list or the magnet's ws=
+Future<List<WebSeedInfo>>  getWebSeeds({required BigInt id }) => RustLib.instance.api.crateApiTrackersGetWebSeeds(id: id);
+
+/// Announce a running torrent to all its trackers now and look it up in
+/// the DHT, and report how many new peers each source turned up.
+///
+/// librqbit can neither be told to announce nor be handed peers while a
+/// torrent runs. The new peers are added to the torrent like add_peer does
+/// and it is re-added with them, which re-verifies its data like
+/// recheck_torrent; librqbit then announces to its trackers again too.
+/// Those trackers are only scraped from here, so their peers aren't
+/// counted. The torrent's current connections are re-established.
+Future<ReannounceSummary>  forceReannounce({required BigInt id }) => RustLib.instance.api.crateApiTrackersForceReannounce(id: id);
+
+            /// Peers one source found for force_reannounce
+class PeerSourceResult  {
+                final String source;
+final int? peers;
+final int? newPeers;
+final String? error;
+
+                const PeerSourceResult({required this.source ,this.peers ,this.newPeers ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^peers.hashCode^newPeers.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PeerSourceResult &&
+                runtimeType == other.runtimeType
+                && source == other.source&& peers == other.peers&& newPeers == other.newPeers&& error == other.error;
+        
+            }
+
+class ReannounceSummary  {
+                final List<PeerSourceResult> sources;
+final int newPeers;
+
+                const ReannounceSummary({required this.sources ,required this.newPeers ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sources.hashCode^newPeers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReannounceSummary &&
+                runtimeType == other.runtimeType
+                && sources == other.sources&& newPeers == other.newPeers;
+        
+            }
+
+/// One tracker of a torrent and the outcome of the last request to it.
+///
+/// librqbit announces to the trackers it was given with the torrent but
+/// doesn't report the responses, so those are scraped for their counts.
+/// Trackers added since are announced to directly until the torrent's next
+/// re-add hands them to librqbit. Times are Unix timestamps in seconds. The
+/// counts are those of the last successful request, None if the tracker
+/// didn't send them.
+class TrackerInfo  {
+                final String url;
+final BigInt tier;
+final BigInt? lastUpdate;
+final BigInt? nextUpdate;
+final bool isScraped;
+final BigInt? seeders;
+final BigInt? leechers;
+final BigInt? completed;
+final int? peers;
+final String? lastError;
+final bool isUpdating;
+
+                const TrackerInfo({required this.url ,required this.tier ,this.lastUpdate ,this.nextUpdate ,required this.isScraped ,this.seeders ,this.leechers ,this.completed ,this.peers ,this.lastError ,required this.isUpdating ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^tier.hashCode^lastUpdate.hashCode^nextUpdate.hashCode^isScraped.hashCode^seeders.hashCode^leechers.hashCode^completed.hashCode^peers.hashCode^lastError.hashCode^isUpdating.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrackerInfo &&
+                runtimeType == other.runtimeType
+                && url == other.url&& tier == other.tier&& lastUpdate == other.lastUpdate&& nextUpdate == other.nextUpdate&& isScraped == other.isScraped&& seeders == other.seeders&& leechers == other.leechers&& completed == other.completed&& peers == other.peers&& lastError == other.lastError&& isUpdating == other.isUpdating;
+        
+            }
+
+/// One web seed of a torrent and what was downloaded from it
+class WebSeedInfo  {
+                final String url;
+final BigInt downloadedBytes;
+final String? lastError;
+
+                const WebSeedInfo({required this.url ,required this.downloadedBytes ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^downloadedBytes.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WebSeedInfo &&
+                runtimeType == other.runtimeType
+                && url == other.url&& downloadedBytes == other.downloadedBytes&& lastError == other.lastError;
+        
+            }
+            
\ No newline at end of file
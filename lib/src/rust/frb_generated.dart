@@ -3,820 +3,2734 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/config.dart';
+import 'api/create.dart';
+import 'api/network.dart';
+import 'api/peers.dart';
 import 'api/simple.dart';
+import 'api/trackers.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -137581715;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_torrent_app',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<TorrentInfo> crateApiSimpleFetchMagnetMetadata(
-      {required String magnetUri, required int timeoutSecs});
-
-  Future<TorrentInfo> crateApiSimpleGetTorrentInfoFile({required String path});
-
-  Future<List<AppTorrentStatus>> crateApiSimpleGetTorrents();
-
-  Future<void> crateApiSimpleInitApp();
-
-  Future<MagnetInfo> crateApiSimpleParseMagnet({required String uri});
-
-  Stream<AppTorrentStatus> crateApiSimpleStartDownload(
-      {required String source,
-      required String outputDir,
-      Uint64List? selectedFileIndices});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<TorrentInfo> crateApiSimpleFetchMagnetMetadata(
-      {required String magnetUri, required int timeoutSecs}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(magnetUri, serializer);
-        sse_encode_u_32(timeoutSecs, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_torrent_info,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSimpleFetchMagnetMetadataConstMeta,
-      argValues: [magnetUri, timeoutSecs],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleFetchMagnetMetadataConstMeta =>
-      const TaskConstMeta(
-        debugName: "fetch_magnet_metadata",
-        argNames: ["magnetUri", "timeoutSecs"],
-      );
-
-  @override
-  Future<TorrentInfo> crateApiSimpleGetTorrentInfoFile({required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_torrent_info,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSimpleGetTorrentInfoFileConstMeta,
-      argValues: [path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleGetTorrentInfoFileConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_torrent_info_file",
-        argNames: ["path"],
-      );
-
-  @override
-  Future<List<AppTorrentStatus>> crateApiSimpleGetTorrents() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_app_torrent_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSimpleGetTorrentsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleGetTorrentsConstMeta => const TaskConstMeta(
-        debugName: "get_torrents",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSimpleInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  @override
-  Future<MagnetInfo> crateApiSimpleParseMagnet({required String uri}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(uri, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_magnet_info,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSimpleParseMagnetConstMeta,
-      argValues: [uri],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleParseMagnetConstMeta => const TaskConstMeta(
-        debugName: "parse_magnet",
-        argNames: ["uri"],
-      );
-
-  @override
-  Stream<AppTorrentStatus> crateApiSimpleStartDownload(
-      {required String source,
-      required String outputDir,
-      Uint64List? selectedFileIndices}) {
-    final streamSink = RustStreamSink<AppTorrentStatus>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(source, serializer);
-        sse_encode_String(outputDir, serializer);
-        sse_encode_StreamSink_app_torrent_status_Sse(streamSink, serializer);
-        sse_encode_opt_list_prim_usize_strict(selectedFileIndices, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSimpleStartDownloadConstMeta,
-      argValues: [source, outputDir, streamSink, selectedFileIndices],
-      apiImpl: this,
-    )));
-    return streamSink.stream;
-  }
-
-  TaskConstMeta get kCrateApiSimpleStartDownloadConstMeta =>
-      const TaskConstMeta(
-        debugName: "start_download",
-        argNames: ["source", "outputDir", "streamSink", "selectedFileIndices"],
-      );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  RustStreamSink<AppTorrentStatus> dco_decode_StreamSink_app_torrent_status_Sse(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  AppTorrentStatus dco_decode_app_torrent_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return AppTorrentStatus(
-      totalPieces: dco_decode_u_32(arr[0]),
-      completedPieces: dco_decode_u_32(arr[1]),
-      peers: dco_decode_u_32(arr[2]),
-      speedMbps: dco_decode_f_64(arr[3]),
-      downloading: dco_decode_bool(arr[4]),
-      isFetchingMetadata: dco_decode_bool(arr[5]),
-      statusMessage: dco_decode_String(arr[6]),
-      error: dco_decode_opt_String(arr[7]),
-      totalBytes: dco_decode_u_64(arr[8]),
-      downloadedBytes: dco_decode_u_64(arr[9]),
-    );
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  FileInfo dco_decode_file_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return FileInfo(
-      path: dco_decode_String(arr[0]),
-      size: dco_decode_usize(arr[1]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<AppTorrentStatus> dco_decode_list_app_torrent_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_app_torrent_status).toList();
-  }
-
-  @protected
-  List<FileInfo> dco_decode_list_file_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_file_info).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  Uint64List dco_decode_list_prim_usize_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint64List;
-  }
-
-  @protected
-  MagnetInfo dco_decode_magnet_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return MagnetInfo(
-      url: dco_decode_String(arr[0]),
-      infoHash: dco_decode_String(arr[1]),
-      name: dco_decode_opt_String(arr[2]),
-      trackers: dco_decode_list_String(arr[3]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  Uint64List? dco_decode_opt_list_prim_usize_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_usize_strict(raw);
-  }
-
-  @protected
-  TorrentInfo dco_decode_torrent_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return TorrentInfo(
-      name: dco_decode_String(arr[0]),
-      totalSize: dco_decode_usize(arr[1]),
-      pieceCount: dco_decode_usize(arr[2]),
-      pieceLength: dco_decode_usize(arr[3]),
-      files: dco_decode_list_file_info(arr[4]),
-      infoHash: dco_decode_String(arr[5]),
-      announce: dco_decode_String(arr[6]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  RustStreamSink<AppTorrentStatus> sse_decode_StreamSink_app_torrent_status_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  AppTorrentStatus sse_decode_app_torrent_status(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalPieces = sse_decode_u_32(deserializer);
-    var var_completedPieces = sse_decode_u_32(deserializer);
-    var var_peers = sse_decode_u_32(deserializer);
-    var var_speedMbps = sse_decode_f_64(deserializer);
-    var var_downloading = sse_decode_bool(deserializer);
-    var var_isFetchingMetadata = sse_decode_bool(deserializer);
-    var var_statusMessage = sse_decode_String(deserializer);
-    var var_error = sse_decode_opt_String(deserializer);
-    var var_totalBytes = sse_decode_u_64(deserializer);
-    var var_downloadedBytes = sse_decode_u_64(deserializer);
-    return AppTorrentStatus(
-        totalPieces: var_totalPieces,
-        completedPieces: var_completedPieces,
-        peers: var_peers,
-        speedMbps: var_speedMbps,
-        downloading: var_downloading,
-        isFetchingMetadata: var_isFetchingMetadata,
-        statusMessage: var_statusMessage,
-        error: var_error,
-        totalBytes: var_totalBytes,
-        downloadedBytes: var_downloadedBytes);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  FileInfo sse_decode_file_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_size = sse_decode_usize(deserializer);
-    return FileInfo(path: var_path, size: var_size);
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<AppTorrentStatus> sse_decode_list_app_torrent_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AppTorrentStatus>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_app_torrent_status(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FileInfo> sse_decode_list_file_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FileInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_file_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint64List sse_decode_list_prim_usize_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint64List(len_);
-  }
-
-  @protected
-  MagnetInfo sse_decode_magnet_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_url = sse_decode_String(deserializer);
-    var var_infoHash = sse_decode_String(deserializer);
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_trackers = sse_decode_list_String(deserializer);
-    return MagnetInfo(
-        url: var_url,
-        infoHash: var_infoHash,
-        name: var_name,
-        trackers: var_trackers);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Uint64List? sse_decode_opt_list_prim_usize_strict(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_usize_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  TorrentInfo sse_decode_torrent_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_totalSize = sse_decode_usize(deserializer);
-    var var_pieceCount = sse_decode_usize(deserializer);
-    var var_pieceLength = sse_decode_usize(deserializer);
-    var var_files = sse_decode_list_file_info(deserializer);
-    var var_infoHash = sse_decode_String(deserializer);
-    var var_announce = sse_decode_String(deserializer);
-    return TorrentInfo(
-        name: var_name,
-        totalSize: var_totalSize,
-        pieceCount: var_pieceCount,
-        pieceLength: var_pieceLength,
-        files: var_files,
-        infoHash: var_infoHash,
-        announce: var_announce);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_app_torrent_status_Sse(
-      RustStreamSink<AppTorrentStatus> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_app_torrent_status,
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -989607099;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_torrent_app',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiPeersAddPeer({required BigInt id , required String address });
+
+Future<AddRequest> crateApiSimpleAddRequestDefault();
+
+Future<AddResult> crateApiSimpleAddResultDefault();
+
+Future<List<AddResult>> crateApiSimpleAddTorrentsBatch({required List<AddRequest> requests });
+
+Future<void> crateApiTrackersAddTrackers({required BigInt id , required List<String> urls });
+
+Future<PeerBan> crateApiPeersBanPeer({required String address });
+
+Future<void> crateApiPeersClearIpFilter();
+
+Stream<CreateTorrentProgress> crateApiCreateCreateTorrent({required String path , required CreateTorrentOptions options });
+
+Future<CreateTorrentOptions> crateApiCreateCreateTorrentOptionsDefault();
+
+Future<DhtStats> crateApiNetworkDhtStats();
+
+Future<EncryptionPolicy> crateApiConfigEncryptionPolicyDefault();
+
+Future<TorrentInfo> crateApiSimpleFetchMagnetMetadata({required String magnetUri , required int timeoutSecs , required bool useDefaultTrackers });
+
+Future<FilePriority> crateApiSimpleFilePriorityDefault();
+
+Future<ReannounceSummary> crateApiTrackersForceReannounce({required BigInt id });
+
+Future<List<FileProgress>> crateApiSimpleGetFileProgress({required BigInt id });
+
+Future<List<PeerInfo>> crateApiPeersGetPeers({required BigInt id });
+
+Future<SessionConfig> crateApiConfigGetSessionConfig();
+
+Future<List<FileInfo>> crateApiSimpleGetTorrentFiles({required BigInt id });
+
+Future<TorrentInfo> crateApiSimpleGetTorrentInfoFile({required String path });
+
+Future<List<AppTorrentStatus>> crateApiSimpleGetTorrents();
+
+Future<List<TrackerInfo>> crateApiTrackersGetTrackers({required BigInt id });
+
+Future<List<WebSeedInfo>> crateApiTrackersGetWebSeeds({required BigInt id });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<IpFilterStats> crateApiPeersIpFilterStats();
+
+Future<IpFilterStats> crateApiPeersIpFilterStatsDefault();
+
+Future<List<String>> crateApiPeersListBans();
+
+Future<IpFilterStats> crateApiPeersLoadIpFilter({required String path });
+
+Future<MetaVersion> crateApiSimpleMetaVersionDefault();
+
+Future<void> crateApiSimpleMoveStorage({required BigInt id , required String newDir });
+
+Future<NetworkStatus> crateApiNetworkNetworkStatus();
+
+Future<MagnetInfo> crateApiSimpleParseMagnet({required String uri });
+
+Future<ProxyConfig> crateApiConfigProxyConfigDefault();
+
+Future<ProxyType> crateApiConfigProxyTypeDefault();
+
+Future<void> crateApiSimpleRecheckTorrent({required BigInt id });
+
+Future<void> crateApiTrackersRemoveTracker({required BigInt id , required String url });
+
+Future<void> crateApiConfigRestartSession();
+
+Future<BigInt> crateApiSimpleSeedLocal({required List<int> torrentBytes , required String contentPath });
+
+Future<SessionConfig> crateApiConfigSessionConfigDefault();
+
+Future<void> crateApiSimpleSetFilePriorities({required BigInt id , required List<(BigInt,FilePriority)> priorities });
+
+Future<void> crateApiSimpleSetSequentialDownload({required BigInt id , required bool enabled , BigInt? fileIndex });
+
+Future<void> crateApiConfigSetSessionConfig({required SessionConfig config });
+
+Stream<AppTorrentStatus> crateApiSimpleStartDownload({required String source , required String outputDir , Uint64List? selectedFileIndices , String? useExistingDataPath , required bool useDefaultTrackers });
+
+Future<String> crateApiSimpleStreamFile({required BigInt id , required BigInt fileIndex });
+
+Future<int> crateApiNetworkTestProxy({required ProxyConfig proxy , String? target });
+
+Future<void> crateApiPeersUnbanPeer({required String address });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiPeersAddPeer({required BigInt id , required String address })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_String(address, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPeersAddPeerConstMeta,
+            argValues: [id, address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersAddPeerConstMeta => const TaskConstMeta(
+            debugName: "add_peer",
+            argNames: ["id", "address"],
+        );
+        
+
+@override Future<AddRequest> crateApiSimpleAddRequestDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_add_request,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleAddRequestDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleAddRequestDefaultConstMeta => const TaskConstMeta(
+            debugName: "add_request_default",
+            argNames: [],
+        );
+        
+
+@override Future<AddResult> crateApiSimpleAddResultDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_add_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleAddResultDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleAddResultDefaultConstMeta => const TaskConstMeta(
+            debugName: "add_result_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<AddResult>> crateApiSimpleAddTorrentsBatch({required List<AddRequest> requests })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_add_request(requests, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_add_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleAddTorrentsBatchConstMeta,
+            argValues: [requests],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleAddTorrentsBatchConstMeta => const TaskConstMeta(
+            debugName: "add_torrents_batch",
+            argNames: ["requests"],
+        );
+        
+
+@override Future<void> crateApiTrackersAddTrackers({required BigInt id , required List<String> urls })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_list_String(urls, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTrackersAddTrackersConstMeta,
+            argValues: [id, urls],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackersAddTrackersConstMeta => const TaskConstMeta(
+            debugName: "add_trackers",
+            argNames: ["id", "urls"],
+        );
+        
+
+@override Future<PeerBan> crateApiPeersBanPeer({required String address })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(address, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_peer_ban,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPeersBanPeerConstMeta,
+            argValues: [address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersBanPeerConstMeta => const TaskConstMeta(
+            debugName: "ban_peer",
+            argNames: ["address"],
+        );
+        
+
+@override Future<void> crateApiPeersClearIpFilter()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPeersClearIpFilterConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersClearIpFilterConstMeta => const TaskConstMeta(
+            debugName: "clear_ip_filter",
+            argNames: [],
+        );
+        
+
+@override Stream<CreateTorrentProgress> crateApiCreateCreateTorrent({required String path , required CreateTorrentOptions options })  { 
+            final progressSink = RustStreamSink<CreateTorrentProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_create_torrent_options(options, serializer);
+sse_encode_StreamSink_create_torrent_progress_Sse(progressSink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCreateCreateTorrentConstMeta,
+            argValues: [path, options, progressSink],
+            apiImpl: this,
+        )));
+            return progressSink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCreateCreateTorrentConstMeta => const TaskConstMeta(
+            debugName: "create_torrent",
+            argNames: ["path", "options", "progressSink"],
+        );
+        
+
+@override Future<CreateTorrentOptions> crateApiCreateCreateTorrentOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_create_torrent_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCreateCreateTorrentOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateCreateTorrentOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "create_torrent_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<DhtStats> crateApiNetworkDhtStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dht_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNetworkDhtStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNetworkDhtStatsConstMeta => const TaskConstMeta(
+            debugName: "dht_stats",
+            argNames: [],
+        );
+        
+
+@override Future<EncryptionPolicy> crateApiConfigEncryptionPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_encryption_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConfigEncryptionPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigEncryptionPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "encryption_policy_default",
+            argNames: [],
+        );
+        
+
+@override Future<TorrentInfo> crateApiSimpleFetchMagnetMetadata({required String magnetUri , required int timeoutSecs , required bool useDefaultTrackers })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(magnetUri, serializer);
+sse_encode_u_32(timeoutSecs, serializer);
+sse_encode_bool(useDefaultTrackers, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_torrent_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleFetchMagnetMetadataConstMeta,
+            argValues: [magnetUri, timeoutSecs, useDefaultTrackers],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleFetchMagnetMetadataConstMeta => const TaskConstMeta(
+            debugName: "fetch_magnet_metadata",
+            argNames: ["magnetUri", "timeoutSecs", "useDefaultTrackers"],
+        );
+        
+
+@override Future<FilePriority> crateApiSimpleFilePriorityDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_file_priority,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleFilePriorityDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleFilePriorityDefaultConstMeta => const TaskConstMeta(
+            debugName: "file_priority_default",
+            argNames: [],
+        );
+        
+
+@override Future<ReannounceSummary> crateApiTrackersForceReannounce({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reannounce_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTrackersForceReannounceConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackersForceReannounceConstMeta => const TaskConstMeta(
+            debugName: "force_reannounce",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<FileProgress>> crateApiSimpleGetFileProgress({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_file_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleGetFileProgressConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGetFileProgressConstMeta => const TaskConstMeta(
+            debugName: "get_file_progress",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<PeerInfo>> crateApiPeersGetPeers({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_peer_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPeersGetPeersConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersGetPeersConstMeta => const TaskConstMeta(
+            debugName: "get_peers",
+            argNames: ["id"],
+        );
+        
+
+@override Future<SessionConfig> crateApiConfigGetSessionConfig()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConfigGetSessionConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigGetSessionConfigConstMeta => const TaskConstMeta(
+            debugName: "get_session_config",
+            argNames: [],
+        );
+        
+
+@override Future<List<FileInfo>> crateApiSimpleGetTorrentFiles({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_file_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleGetTorrentFilesConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGetTorrentFilesConstMeta => const TaskConstMeta(
+            debugName: "get_torrent_files",
+            argNames: ["id"],
+        );
+        
+
+@override Future<TorrentInfo> crateApiSimpleGetTorrentInfoFile({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_torrent_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleGetTorrentInfoFileConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGetTorrentInfoFileConstMeta => const TaskConstMeta(
+            debugName: "get_torrent_info_file",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<AppTorrentStatus>> crateApiSimpleGetTorrents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_app_torrent_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleGetTorrentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGetTorrentsConstMeta => const TaskConstMeta(
+            debugName: "get_torrents",
+            argNames: [],
+        );
+        
+
+@override Future<List<TrackerInfo>> crateApiTrackersGetTrackers({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_tracker_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTrackersGetTrackersConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackersGetTrackersConstMeta => const TaskConstMeta(
+            debugName: "get_trackers",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<WebSeedInfo>> crateApiTrackersGetWebSeeds({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_web_seed_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTrackersGetWebSeedsConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackersGetWebSeedsConstMeta => const TaskConstMeta(
+            debugName: "get_web_seeds",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<IpFilterStats> crateApiPeersIpFilterStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ip_filter_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPeersIpFilterStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersIpFilterStatsConstMeta => const TaskConstMeta(
+            debugName: "ip_filter_stats",
+            argNames: [],
+        );
+        
+
+@override Future<IpFilterStats> crateApiPeersIpFilterStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ip_filter_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPeersIpFilterStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersIpFilterStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "ip_filter_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiPeersListBans()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPeersListBansConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersListBansConstMeta => const TaskConstMeta(
+            debugName: "list_bans",
+            argNames: [],
+        );
+        
+
+@override Future<IpFilterStats> crateApiPeersLoadIpFilter({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ip_filter_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPeersLoadIpFilterConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersLoadIpFilterConstMeta => const TaskConstMeta(
+            debugName: "load_ip_filter",
+            argNames: ["path"],
+        );
+        
+
+@override Future<MetaVersion> crateApiSimpleMetaVersionDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_meta_version,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleMetaVersionDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleMetaVersionDefaultConstMeta => const TaskConstMeta(
+            debugName: "meta_version_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleMoveStorage({required BigInt id , required String newDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_String(newDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleMoveStorageConstMeta,
+            argValues: [id, newDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleMoveStorageConstMeta => const TaskConstMeta(
+            debugName: "move_storage",
+            argNames: ["id", "newDir"],
+        );
+        
+
+@override Future<NetworkStatus> crateApiNetworkNetworkStatus()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNetworkNetworkStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNetworkNetworkStatusConstMeta => const TaskConstMeta(
+            debugName: "network_status",
+            argNames: [],
+        );
+        
+
+@override Future<MagnetInfo> crateApiSimpleParseMagnet({required String uri })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(uri, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_magnet_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleParseMagnetConstMeta,
+            argValues: [uri],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleParseMagnetConstMeta => const TaskConstMeta(
+            debugName: "parse_magnet",
+            argNames: ["uri"],
+        );
+        
+
+@override Future<ProxyConfig> crateApiConfigProxyConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_proxy_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConfigProxyConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigProxyConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "proxy_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<ProxyType> crateApiConfigProxyTypeDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_proxy_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConfigProxyTypeDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigProxyTypeDefaultConstMeta => const TaskConstMeta(
+            debugName: "proxy_type_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleRecheckTorrent({required BigInt id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleRecheckTorrentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleRecheckTorrentConstMeta => const TaskConstMeta(
+            debugName: "recheck_torrent",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiTrackersRemoveTracker({required BigInt id , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTrackersRemoveTrackerConstMeta,
+            argValues: [id, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTrackersRemoveTrackerConstMeta => const TaskConstMeta(
+            debugName: "remove_tracker",
+            argNames: ["id", "url"],
+        );
+        
+
+@override Future<void> crateApiConfigRestartSession()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConfigRestartSessionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigRestartSessionConstMeta => const TaskConstMeta(
+            debugName: "restart_session",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiSimpleSeedLocal({required List<int> torrentBytes , required String contentPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(torrentBytes, serializer);
+sse_encode_String(contentPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleSeedLocalConstMeta,
+            argValues: [torrentBytes, contentPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleSeedLocalConstMeta => const TaskConstMeta(
+            debugName: "seed_local",
+            argNames: ["torrentBytes", "contentPath"],
+        );
+        
+
+@override Future<SessionConfig> crateApiConfigSessionConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConfigSessionConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigSessionConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "session_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleSetFilePriorities({required BigInt id , required List<(BigInt,FilePriority)> priorities })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_list_record_usize_file_priority(priorities, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleSetFilePrioritiesConstMeta,
+            argValues: [id, priorities],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleSetFilePrioritiesConstMeta => const TaskConstMeta(
+            debugName: "set_file_priorities",
+            argNames: ["id", "priorities"],
+        );
+        
+
+@override Future<void> crateApiSimpleSetSequentialDownload({required BigInt id , required bool enabled , BigInt? fileIndex })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_bool(enabled, serializer);
+sse_encode_opt_box_autoadd_usize(fileIndex, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleSetSequentialDownloadConstMeta,
+            argValues: [id, enabled, fileIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleSetSequentialDownloadConstMeta => const TaskConstMeta(
+            debugName: "set_sequential_download",
+            argNames: ["id", "enabled", "fileIndex"],
+        );
+        
+
+@override Future<void> crateApiConfigSetSessionConfig({required SessionConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_session_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConfigSetSessionConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfigSetSessionConfigConstMeta => const TaskConstMeta(
+            debugName: "set_session_config",
+            argNames: ["config"],
+        );
+        
+
+@override Stream<AppTorrentStatus> crateApiSimpleStartDownload({required String source , required String outputDir , Uint64List? selectedFileIndices , String? useExistingDataPath , required bool useDefaultTrackers })  { 
+            final streamSink = RustStreamSink<AppTorrentStatus>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(source, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_StreamSink_app_torrent_status_Sse(streamSink, serializer);
+sse_encode_opt_list_prim_usize_strict(selectedFileIndices, serializer);
+sse_encode_opt_String(useExistingDataPath, serializer);
+sse_encode_bool(useDefaultTrackers, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleStartDownloadConstMeta,
+            argValues: [source, outputDir, streamSink, selectedFileIndices, useExistingDataPath, useDefaultTrackers],
+            apiImpl: this,
+        )));
+            return streamSink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSimpleStartDownloadConstMeta => const TaskConstMeta(
+            debugName: "start_download",
+            argNames: ["source", "outputDir", "streamSink", "selectedFileIndices", "useExistingDataPath", "useDefaultTrackers"],
+        );
+        
+
+@override Future<String> crateApiSimpleStreamFile({required BigInt id , required BigInt fileIndex })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(id, serializer);
+sse_encode_usize(fileIndex, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSimpleStreamFileConstMeta,
+            argValues: [id, fileIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleStreamFileConstMeta => const TaskConstMeta(
+            debugName: "stream_file",
+            argNames: ["id", "fileIndex"],
+        );
+        
+
+@override Future<int> crateApiNetworkTestProxy({required ProxyConfig proxy , String? target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_proxy_config(proxy, serializer);
+sse_encode_opt_String(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNetworkTestProxyConstMeta,
+            argValues: [proxy, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNetworkTestProxyConstMeta => const TaskConstMeta(
+            debugName: "test_proxy",
+            argNames: ["proxy", "target"],
+        );
+        
+
+@override Future<void> crateApiPeersUnbanPeer({required String address })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(address, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_app_torrent_status(
-      AppTorrentStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.totalPieces, serializer);
-    sse_encode_u_32(self.completedPieces, serializer);
-    sse_encode_u_32(self.peers, serializer);
-    sse_encode_f_64(self.speedMbps, serializer);
-    sse_encode_bool(self.downloading, serializer);
-    sse_encode_bool(self.isFetchingMetadata, serializer);
-    sse_encode_String(self.statusMessage, serializer);
-    sse_encode_opt_String(self.error, serializer);
-    sse_encode_u_64(self.totalBytes, serializer);
-    sse_encode_u_64(self.downloadedBytes, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_file_info(FileInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.path, serializer);
-    sse_encode_usize(self.size, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_app_torrent_status(
-      List<AppTorrentStatus> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_app_torrent_status(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_file_info(
-      List<FileInfo> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_file_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_usize_strict(
-      Uint64List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint64List(self);
-  }
-
-  @protected
-  void sse_encode_magnet_info(MagnetInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.url, serializer);
-    sse_encode_String(self.infoHash, serializer);
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_list_String(self.trackers, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_usize_strict(
-      Uint64List? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_usize_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_torrent_info(TorrentInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_usize(self.totalSize, serializer);
-    sse_encode_usize(self.pieceCount, serializer);
-    sse_encode_usize(self.pieceLength, serializer);
-    sse_encode_list_file_info(self.files, serializer);
-    sse_encode_String(self.infoHash, serializer);
-    sse_encode_String(self.announce, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiPeersUnbanPeerConstMeta,
+            argValues: [address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPeersUnbanPeerConstMeta => const TaskConstMeta(
+            debugName: "unban_peer",
+            argNames: ["address"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<AppTorrentStatus> dco_decode_StreamSink_app_torrent_status_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<CreateTorrentProgress> dco_decode_StreamSink_create_torrent_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AddRequest dco_decode_add_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AddRequest(source: dco_decode_opt_String(arr[0]),
+torrentBytes: dco_decode_opt_list_prim_u_8_strict(arr[1]),
+outputDir: dco_decode_String(arr[2]),
+selectedFileIndices: dco_decode_opt_list_prim_usize_strict(arr[3]),
+useDefaultTrackers: dco_decode_bool(arr[4]),); }
+
+@protected AddResult dco_decode_add_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AddResult(id: dco_decode_opt_box_autoadd_usize(arr[0]),
+infoHash: dco_decode_opt_String(arr[1]),
+name: dco_decode_opt_String(arr[2]),
+alreadyAdded: dco_decode_bool(arr[3]),
+queued: dco_decode_bool(arr[4]),
+error: dco_decode_opt_String(arr[5]),); }
+
+@protected AppTorrentStatus dco_decode_app_torrent_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 19) throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
+                return AppTorrentStatus(id: dco_decode_usize(arr[0]),
+totalPieces: dco_decode_u_32(arr[1]),
+completedPieces: dco_decode_u_32(arr[2]),
+peers: dco_decode_u_32(arr[3]),
+speedMbps: dco_decode_f_64(arr[4]),
+webSeedSpeedMbps: dco_decode_f_64(arr[5]),
+downloading: dco_decode_bool(arr[6]),
+isFetchingMetadata: dco_decode_bool(arr[7]),
+isChecking: dco_decode_bool(arr[8]),
+isMoving: dco_decode_bool(arr[9]),
+isSequential: dco_decode_bool(arr[10]),
+isNetworkPaused: dco_decode_bool(arr[11]),
+isQueued: dco_decode_bool(arr[12]),
+statusMessage: dco_decode_String(arr[13]),
+error: dco_decode_opt_String(arr[14]),
+totalBytes: dco_decode_u_64(arr[15]),
+downloadedBytes: dco_decode_u_64(arr[16]),
+webSeedDownloadedBytes: dco_decode_u_64(arr[17]),
+files: dco_decode_opt_list_file_progress(arr[18]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CreateTorrentOptions dco_decode_box_autoadd_create_torrent_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_torrent_options(raw); }
+
+@protected CreatedTorrent dco_decode_box_autoadd_created_torrent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_created_torrent(raw); }
+
+@protected ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_proxy_config(raw); }
+
+@protected SessionConfig dco_decode_box_autoadd_session_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_session_config(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usize(raw); }
+
+@protected CreateTorrentOptions dco_decode_create_torrent_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CreateTorrentOptions(name: dco_decode_opt_String(arr[0]),
+pieceLength: dco_decode_opt_box_autoadd_u_32(arr[1]),
+trackers: dco_decode_list_String(arr[2]),
+webSeeds: dco_decode_list_String(arr[3]),
+comment: dco_decode_opt_String(arr[4]),
+private: dco_decode_bool(arr[5]),
+source: dco_decode_opt_String(arr[6]),); }
+
+@protected CreateTorrentProgress dco_decode_create_torrent_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CreateTorrentProgress(hashedBytes: dco_decode_u_64(arr[0]),
+totalBytes: dco_decode_u_64(arr[1]),
+torrent: dco_decode_opt_box_autoadd_created_torrent(arr[2]),); }
+
+@protected CreatedTorrent dco_decode_created_torrent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CreatedTorrent(torrentBytes: dco_decode_list_prim_u_8_strict(arr[0]),
+magnet: dco_decode_String(arr[1]),
+infoHash: dco_decode_String(arr[2]),
+name: dco_decode_String(arr[3]),
+totalSize: dco_decode_u_64(arr[4]),
+pieceLength: dco_decode_u_32(arr[5]),
+pieceCount: dco_decode_usize(arr[6]),); }
+
+@protected DhtBucket dco_decode_dht_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DhtBucket(prefixBits: dco_decode_u_8(arr[0]),
+nodes: dco_decode_u_32(arr[1]),); }
+
+@protected DhtStats dco_decode_dht_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return DhtStats(enabled: dco_decode_bool(arr[0]),
+nodeId: dco_decode_String(arr[1]),
+listenPort: dco_decode_u_16(arr[2]),
+nodes: dco_decode_u_32(arr[3]),
+buckets: dco_decode_list_dht_bucket(arr[4]),
+outstandingQueries: dco_decode_u_32(arr[5]),); }
+
+@protected EncryptionPolicy dco_decode_encryption_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EncryptionPolicy.values[raw as int]; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileInfo dco_decode_file_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FileInfo(index: dco_decode_usize(arr[0]),
+path: dco_decode_String(arr[1]),
+size: dco_decode_usize(arr[2]),
+originalPath: dco_decode_opt_String(arr[3]),
+priority: dco_decode_file_priority(arr[4]),
+downloadedBytes: dco_decode_u_64(arr[5]),
+merkleRoot: dco_decode_opt_String(arr[6]),); }
+
+@protected FilePriority dco_decode_file_priority(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FilePriority.values[raw as int]; }
+
+@protected FileProgress dco_decode_file_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FileProgress(index: dco_decode_usize(arr[0]),
+path: dco_decode_String(arr[1]),
+size: dco_decode_u_64(arr[2]),
+downloaded: dco_decode_u_64(arr[3]),
+complete: dco_decode_bool(arr[4]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected IpFilterStats dco_decode_ip_filter_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return IpFilterStats(source: dco_decode_opt_String(arr[0]),
+format: dco_decode_opt_String(arr[1]),
+entries: dco_decode_usize(arr[2]),
+allowedEntries: dco_decode_usize(arr[3]),
+invalidLines: dco_decode_usize(arr[4]),
+ipv4Ranges: dco_decode_usize(arr[5]),
+ipv6Ranges: dco_decode_usize(arr[6]),
+ipv4Addresses: dco_decode_u_64(arr[7]),
+active: dco_decode_bool(arr[8]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AddRequest> dco_decode_list_add_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_add_request).toList(); }
+
+@protected List<AddResult> dco_decode_list_add_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_add_result).toList(); }
+
+@protected List<AppTorrentStatus> dco_decode_list_app_torrent_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_app_torrent_status).toList(); }
+
+@protected List<DhtBucket> dco_decode_list_dht_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_dht_bucket).toList(); }
+
+@protected List<FileInfo> dco_decode_list_file_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_file_info).toList(); }
+
+@protected List<FileProgress> dco_decode_list_file_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_file_progress).toList(); }
+
+@protected List<PeerInfo> dco_decode_list_peer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_info).toList(); }
+
+@protected List<PeerSourceResult> dco_decode_list_peer_source_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_source_result).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected Uint64List dco_decode_list_prim_usize_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint64List; }
+
+@protected List<(BigInt,FilePriority)> dco_decode_list_record_usize_file_priority(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_usize_file_priority).toList(); }
+
+@protected List<TrackerInfo> dco_decode_list_tracker_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_tracker_info).toList(); }
+
+@protected List<V2FileInfo> dco_decode_list_v_2_file_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_v_2_file_info).toList(); }
+
+@protected List<WebSeedInfo> dco_decode_list_web_seed_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_web_seed_info).toList(); }
+
+@protected MagnetInfo dco_decode_magnet_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MagnetInfo(url: dco_decode_String(arr[0]),
+infoHash: dco_decode_String(arr[1]),
+infoHashV2: dco_decode_opt_String(arr[2]),
+name: dco_decode_opt_String(arr[3]),
+trackers: dco_decode_list_String(arr[4]),
+webSeeds: dco_decode_list_String(arr[5]),); }
+
+@protected MetaVersion dco_decode_meta_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MetaVersion.values[raw as int]; }
+
+@protected NetworkStatus dco_decode_network_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return NetworkStatus(listenAddress: dco_decode_opt_String(arr[0]),
+portMapping: dco_decode_opt_String(arr[1]),
+externalAddress: dco_decode_opt_String(arr[2]),
+externalPort: dco_decode_opt_box_autoadd_u_16(arr[3]),
+mappingError: dco_decode_opt_String(arr[4]),
+incomingSeen: dco_decode_bool(arr[5]),
+interfaceUp: dco_decode_bool(arr[6]),
+bindAddress: dco_decode_opt_String(arr[7]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected CreatedTorrent? dco_decode_opt_box_autoadd_created_torrent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_created_torrent(raw); }
+
+@protected ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_proxy_config(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usize(raw); }
+
+@protected List<FileProgress>? dco_decode_opt_list_file_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_file_progress(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected Uint64List? dco_decode_opt_list_prim_usize_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_usize_strict(raw); }
+
+@protected PeerBan dco_decode_peer_ban(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PeerBan(disconnected: dco_decode_u_32(arr[0]),
+active: dco_decode_bool(arr[1]),); }
+
+@protected PeerDirection dco_decode_peer_direction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PeerDirection.values[raw as int]; }
+
+@protected PeerInfo dco_decode_peer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PeerInfo(address: dco_decode_String(arr[0]),
+direction: dco_decode_peer_direction(arr[1]),
+encrypted: dco_decode_bool(arr[2]),
+downloadBps: dco_decode_u_64(arr[3]),
+downloadedBytes: dco_decode_u_64(arr[4]),
+piecesDownloaded: dco_decode_u_32(arr[5]),
+errors: dco_decode_u_32(arr[6]),); }
+
+@protected PeerSourceResult dco_decode_peer_source_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PeerSourceResult(source: dco_decode_String(arr[0]),
+peers: dco_decode_opt_box_autoadd_u_32(arr[1]),
+newPeers: dco_decode_opt_box_autoadd_u_32(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected ProxyConfig dco_decode_proxy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ProxyConfig(proxyType: dco_decode_proxy_type(arr[0]),
+host: dco_decode_String(arr[1]),
+port: dco_decode_u_16(arr[2]),
+username: dco_decode_opt_String(arr[3]),
+password: dco_decode_opt_String(arr[4]),
+proxyPeers: dco_decode_bool(arr[5]),
+proxyTrackers: dco_decode_bool(arr[6]),); }
+
+@protected ProxyType dco_decode_proxy_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProxyType.values[raw as int]; }
+
+@protected ReannounceSummary dco_decode_reannounce_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ReannounceSummary(sources: dco_decode_list_peer_source_result(arr[0]),
+newPeers: dco_decode_u_32(arr[1]),); }
+
+@protected (BigInt,FilePriority) dco_decode_record_usize_file_priority(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_usize(arr[0]),dco_decode_file_priority(arr[1]),); }
+
+@protected SessionConfig dco_decode_session_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+                return SessionConfig(incompleteDir: dco_decode_opt_String(arr[0]),
+completeDir: dco_decode_opt_String(arr[1]),
+partSuffix: dco_decode_bool(arr[2]),
+includeFileProgress: dco_decode_bool(arr[3]),
+defaultTrackers: dco_decode_list_String(arr[4]),
+defaultTrackersFile: dco_decode_opt_String(arr[5]),
+dhtPort: dco_decode_opt_box_autoadd_u_16(arr[6]),
+dhtBootstrapNodes: dco_decode_list_String(arr[7]),
+listenPort: dco_decode_opt_box_autoadd_u_16(arr[8]),
+listenPortMax: dco_decode_opt_box_autoadd_u_16(arr[9]),
+upnp: dco_decode_bool(arr[10]),
+natpmp: dco_decode_bool(arr[11]),
+outgoingInterface: dco_decode_opt_String(arr[12]),
+bindAddress: dco_decode_opt_String(arr[13]),
+proxy: dco_decode_opt_box_autoadd_proxy_config(arr[14]),
+encryption: dco_decode_encryption_policy(arr[15]),
+maxActiveDownloads: dco_decode_opt_box_autoadd_u_32(arr[16]),); }
+
+@protected TorrentInfo dco_decode_torrent_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return TorrentInfo(name: dco_decode_String(arr[0]),
+totalSize: dco_decode_usize(arr[1]),
+pieceCount: dco_decode_usize(arr[2]),
+pieceLength: dco_decode_usize(arr[3]),
+files: dco_decode_list_file_info(arr[4]),
+infoHash: dco_decode_String(arr[5]),
+infoHashV2: dco_decode_opt_String(arr[6]),
+metaVersion: dco_decode_meta_version(arr[7]),
+announce: dco_decode_String(arr[8]),
+webSeeds: dco_decode_list_String(arr[9]),
+fileTree: dco_decode_list_v_2_file_info(arr[10]),); }
+
+@protected TrackerInfo dco_decode_tracker_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return TrackerInfo(url: dco_decode_String(arr[0]),
+tier: dco_decode_usize(arr[1]),
+lastUpdate: dco_decode_opt_box_autoadd_u_64(arr[2]),
+nextUpdate: dco_decode_opt_box_autoadd_u_64(arr[3]),
+isScraped: dco_decode_bool(arr[4]),
+seeders: dco_decode_opt_box_autoadd_u_64(arr[5]),
+leechers: dco_decode_opt_box_autoadd_u_64(arr[6]),
+completed: dco_decode_opt_box_autoadd_u_64(arr[7]),
+peers: dco_decode_opt_box_autoadd_u_32(arr[8]),
+lastError: dco_decode_opt_String(arr[9]),
+isUpdating: dco_decode_bool(arr[10]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected V2FileInfo dco_decode_v_2_file_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return V2FileInfo(path: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),
+merkleRoot: dco_decode_opt_String(arr[2]),); }
+
+@protected WebSeedInfo dco_decode_web_seed_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return WebSeedInfo(url: dco_decode_String(arr[0]),
+downloadedBytes: dco_decode_u_64(arr[1]),
+lastError: dco_decode_opt_String(arr[2]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<AppTorrentStatus> sse_decode_StreamSink_app_torrent_status_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<CreateTorrentProgress> sse_decode_StreamSink_create_torrent_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AddRequest sse_decode_add_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_opt_String(deserializer);
+var var_torrentBytes = sse_decode_opt_list_prim_u_8_strict(deserializer);
+var var_outputDir = sse_decode_String(deserializer);
+var var_selectedFileIndices = sse_decode_opt_list_prim_usize_strict(deserializer);
+var var_useDefaultTrackers = sse_decode_bool(deserializer);
+return AddRequest(source: var_source, torrentBytes: var_torrentBytes, outputDir: var_outputDir, selectedFileIndices: var_selectedFileIndices, useDefaultTrackers: var_useDefaultTrackers); }
+
+@protected AddResult sse_decode_add_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_infoHash = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_alreadyAdded = sse_decode_bool(deserializer);
+var var_queued = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return AddResult(id: var_id, infoHash: var_infoHash, name: var_name, alreadyAdded: var_alreadyAdded, queued: var_queued, error: var_error); }
+
+@protected AppTorrentStatus sse_decode_app_torrent_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_usize(deserializer);
+var var_totalPieces = sse_decode_u_32(deserializer);
+var var_completedPieces = sse_decode_u_32(deserializer);
+var var_peers = sse_decode_u_32(deserializer);
+var var_speedMbps = sse_decode_f_64(deserializer);
+var var_webSeedSpeedMbps = sse_decode_f_64(deserializer);
+var var_downloading = sse_decode_bool(deserializer);
+var var_isFetchingMetadata = sse_decode_bool(deserializer);
+var var_isChecking = sse_decode_bool(deserializer);
+var var_isMoving = sse_decode_bool(deserializer);
+var var_isSequential = sse_decode_bool(deserializer);
+var var_isNetworkPaused = sse_decode_bool(deserializer);
+var var_isQueued = sse_decode_bool(deserializer);
+var var_statusMessage = sse_decode_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+var var_downloadedBytes = sse_decode_u_64(deserializer);
+var var_webSeedDownloadedBytes = sse_decode_u_64(deserializer);
+var var_files = sse_decode_opt_list_file_progress(deserializer);
+return AppTorrentStatus(id: var_id, totalPieces: var_totalPieces, completedPieces: var_completedPieces, peers: var_peers, speedMbps: var_speedMbps, webSeedSpeedMbps: var_webSeedSpeedMbps, downloading: var_downloading, isFetchingMetadata: var_isFetchingMetadata, isChecking: var_isChecking, isMoving: var_isMoving, isSequential: var_isSequential, isNetworkPaused: var_isNetworkPaused, isQueued: var_isQueued, statusMessage: var_statusMessage, error: var_error, totalBytes: var_totalBytes, downloadedBytes: var_downloadedBytes, webSeedDownloadedBytes: var_webSeedDownloadedBytes, files: var_files); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected CreateTorrentOptions sse_decode_box_autoadd_create_torrent_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_torrent_options(deserializer)); }
+
+@protected CreatedTorrent sse_decode_box_autoadd_created_torrent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_created_torrent(deserializer)); }
+
+@protected ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_proxy_config(deserializer)); }
+
+@protected SessionConfig sse_decode_box_autoadd_session_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_session_config(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usize(deserializer)); }
+
+@protected CreateTorrentOptions sse_decode_create_torrent_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_pieceLength = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_trackers = sse_decode_list_String(deserializer);
+var var_webSeeds = sse_decode_list_String(deserializer);
+var var_comment = sse_decode_opt_String(deserializer);
+var var_private = sse_decode_bool(deserializer);
+var var_source = sse_decode_opt_String(deserializer);
+return CreateTorrentOptions(name: var_name, pieceLength: var_pieceLength, trackers: var_trackers, webSeeds: var_webSeeds, comment: var_comment, private: var_private, source: var_source); }
+
+@protected CreateTorrentProgress sse_decode_create_torrent_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hashedBytes = sse_decode_u_64(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+var var_torrent = sse_decode_opt_box_autoadd_created_torrent(deserializer);
+return CreateTorrentProgress(hashedBytes: var_hashedBytes, totalBytes: var_totalBytes, torrent: var_torrent); }
+
+@protected CreatedTorrent sse_decode_created_torrent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_torrentBytes = sse_decode_list_prim_u_8_strict(deserializer);
+var var_magnet = sse_decode_String(deserializer);
+var var_infoHash = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_totalSize = sse_decode_u_64(deserializer);
+var var_pieceLength = sse_decode_u_32(deserializer);
+var var_pieceCount = sse_decode_usize(deserializer);
+return CreatedTorrent(torrentBytes: var_torrentBytes, magnet: var_magnet, infoHash: var_infoHash, name: var_name, totalSize: var_totalSize, pieceLength: var_pieceLength, pieceCount: var_pieceCount); }
+
+@protected DhtBucket sse_decode_dht_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_prefixBits = sse_decode_u_8(deserializer);
+var var_nodes = sse_decode_u_32(deserializer);
+return DhtBucket(prefixBits: var_prefixBits, nodes: var_nodes); }
+
+@protected DhtStats sse_decode_dht_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_enabled = sse_decode_bool(deserializer);
+var var_nodeId = sse_decode_String(deserializer);
+var var_listenPort = sse_decode_u_16(deserializer);
+var var_nodes = sse_decode_u_32(deserializer);
+var var_buckets = sse_decode_list_dht_bucket(deserializer);
+var var_outstandingQueries = sse_decode_u_32(deserializer);
+return DhtStats(enabled: var_enabled, nodeId: var_nodeId, listenPort: var_listenPort, nodes: var_nodes, buckets: var_buckets, outstandingQueries: var_outstandingQueries); }
+
+@protected EncryptionPolicy sse_decode_encryption_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return EncryptionPolicy.values[inner]; }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FileInfo sse_decode_file_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_usize(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_usize(deserializer);
+var var_originalPath = sse_decode_opt_String(deserializer);
+var var_priority = sse_decode_file_priority(deserializer);
+var var_downloadedBytes = sse_decode_u_64(deserializer);
+var var_merkleRoot = sse_decode_opt_String(deserializer);
+return FileInfo(index: var_index, path: var_path, size: var_size, originalPath: var_originalPath, priority: var_priority, downloadedBytes: var_downloadedBytes, merkleRoot: var_merkleRoot); }
+
+@protected FilePriority sse_decode_file_priority(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FilePriority.values[inner]; }
+
+@protected FileProgress sse_decode_file_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_usize(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_downloaded = sse_decode_u_64(deserializer);
+var var_complete = sse_decode_bool(deserializer);
+return FileProgress(index: var_index, path: var_path, size: var_size, downloaded: var_downloaded, complete: var_complete); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected IpFilterStats sse_decode_ip_filter_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_opt_String(deserializer);
+var var_format = sse_decode_opt_String(deserializer);
+var var_entries = sse_decode_usize(deserializer);
+var var_allowedEntries = sse_decode_usize(deserializer);
+var var_invalidLines = sse_decode_usize(deserializer);
+var var_ipv4Ranges = sse_decode_usize(deserializer);
+var var_ipv6Ranges = sse_decode_usize(deserializer);
+var var_ipv4Addresses = sse_decode_u_64(deserializer);
+var var_active = sse_decode_bool(deserializer);
+return IpFilterStats(source: var_source, format: var_format, entries: var_entries, allowedEntries: var_allowedEntries, invalidLines: var_invalidLines, ipv4Ranges: var_ipv4Ranges, ipv6Ranges: var_ipv6Ranges, ipv4Addresses: var_ipv4Addresses, active: var_active); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AddRequest> sse_decode_list_add_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AddRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_add_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AddResult> sse_decode_list_add_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AddResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_add_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AppTorrentStatus> sse_decode_list_app_torrent_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AppTorrentStatus>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_app_torrent_status(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DhtBucket> sse_decode_list_dht_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DhtBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_dht_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FileInfo> sse_decode_list_file_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FileInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_file_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FileProgress> sse_decode_list_file_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FileProgress>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_file_progress(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerSourceResult> sse_decode_list_peer_source_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerSourceResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_source_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint64List sse_decode_list_prim_usize_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<(BigInt,FilePriority)> sse_decode_list_record_usize_file_priority(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(BigInt,FilePriority)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_usize_file_priority(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TrackerInfo> sse_decode_list_tracker_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrackerInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_tracker_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<V2FileInfo> sse_decode_list_v_2_file_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <V2FileInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_v_2_file_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WebSeedInfo> sse_decode_list_web_seed_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WebSeedInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_web_seed_info(deserializer)); }
+        return ans_;
+         }
+
+@protected MagnetInfo sse_decode_magnet_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_infoHash = sse_decode_String(deserializer);
+var var_infoHashV2 = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_trackers = sse_decode_list_String(deserializer);
+var var_webSeeds = sse_decode_list_String(deserializer);
+return MagnetInfo(url: var_url, infoHash: var_infoHash, infoHashV2: var_infoHashV2, name: var_name, trackers: var_trackers, webSeeds: var_webSeeds); }
+
+@protected MetaVersion sse_decode_meta_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MetaVersion.values[inner]; }
+
+@protected NetworkStatus sse_decode_network_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_listenAddress = sse_decode_opt_String(deserializer);
+var var_portMapping = sse_decode_opt_String(deserializer);
+var var_externalAddress = sse_decode_opt_String(deserializer);
+var var_externalPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_mappingError = sse_decode_opt_String(deserializer);
+var var_incomingSeen = sse_decode_bool(deserializer);
+var var_interfaceUp = sse_decode_bool(deserializer);
+var var_bindAddress = sse_decode_opt_String(deserializer);
+return NetworkStatus(listenAddress: var_listenAddress, portMapping: var_portMapping, externalAddress: var_externalAddress, externalPort: var_externalPort, mappingError: var_mappingError, incomingSeen: var_incomingSeen, interfaceUp: var_interfaceUp, bindAddress: var_bindAddress); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CreatedTorrent? sse_decode_opt_box_autoadd_created_torrent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_created_torrent(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_proxy_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usize(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<FileProgress>? sse_decode_opt_list_file_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_file_progress(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint64List? sse_decode_opt_list_prim_usize_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_usize_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PeerBan sse_decode_peer_ban(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_disconnected = sse_decode_u_32(deserializer);
+var var_active = sse_decode_bool(deserializer);
+return PeerBan(disconnected: var_disconnected, active: var_active); }
+
+@protected PeerDirection sse_decode_peer_direction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PeerDirection.values[inner]; }
+
+@protected PeerInfo sse_decode_peer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_direction = sse_decode_peer_direction(deserializer);
+var var_encrypted = sse_decode_bool(deserializer);
+var var_downloadBps = sse_decode_u_64(deserializer);
+var var_downloadedBytes = sse_decode_u_64(deserializer);
+var var_piecesDownloaded = sse_decode_u_32(deserializer);
+var var_errors = sse_decode_u_32(deserializer);
+return PeerInfo(address: var_address, direction: var_direction, encrypted: var_encrypted, downloadBps: var_downloadBps, downloadedBytes: var_downloadedBytes, piecesDownloaded: var_piecesDownloaded, errors: var_errors); }
+
+@protected PeerSourceResult sse_decode_peer_source_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_String(deserializer);
+var var_peers = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_newPeers = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return PeerSourceResult(source: var_source, peers: var_peers, newPeers: var_newPeers, error: var_error); }
+
+@protected ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_proxyType = sse_decode_proxy_type(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_username = sse_decode_opt_String(deserializer);
+var var_password = sse_decode_opt_String(deserializer);
+var var_proxyPeers = sse_decode_bool(deserializer);
+var var_proxyTrackers = sse_decode_bool(deserializer);
+return ProxyConfig(proxyType: var_proxyType, host: var_host, port: var_port, username: var_username, password: var_password, proxyPeers: var_proxyPeers, proxyTrackers: var_proxyTrackers); }
+
+@protected ProxyType sse_decode_proxy_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProxyType.values[inner]; }
+
+@protected ReannounceSummary sse_decode_reannounce_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sources = sse_decode_list_peer_source_result(deserializer);
+var var_newPeers = sse_decode_u_32(deserializer);
+return ReannounceSummary(sources: var_sources, newPeers: var_newPeers); }
+
+@protected (BigInt,FilePriority) sse_decode_record_usize_file_priority(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_usize(deserializer);
+var var_field1 = sse_decode_file_priority(deserializer);
+return (var_field0, var_field1); }
+
+@protected SessionConfig sse_decode_session_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_incompleteDir = sse_decode_opt_String(deserializer);
+var var_completeDir = sse_decode_opt_String(deserializer);
+var var_partSuffix = sse_decode_bool(deserializer);
+var var_includeFileProgress = sse_decode_bool(deserializer);
+var var_defaultTrackers = sse_decode_list_String(deserializer);
+var var_defaultTrackersFile = sse_decode_opt_String(deserializer);
+var var_dhtPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_dhtBootstrapNodes = sse_decode_list_String(deserializer);
+var var_listenPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_listenPortMax = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_upnp = sse_decode_bool(deserializer);
+var var_natpmp = sse_decode_bool(deserializer);
+var var_outgoingInterface = sse_decode_opt_String(deserializer);
+var var_bindAddress = sse_decode_opt_String(deserializer);
+var var_proxy = sse_decode_opt_box_autoadd_proxy_config(deserializer);
+var var_encryption = sse_decode_encryption_policy(deserializer);
+var var_maxActiveDownloads = sse_decode_opt_box_autoadd_u_32(deserializer);
+return SessionConfig(incompleteDir: var_incompleteDir, completeDir: var_completeDir, partSuffix: var_partSuffix, includeFileProgress: var_includeFileProgress, defaultTrackers: var_defaultTrackers, defaultTrackersFile: var_defaultTrackersFile, dhtPort: var_dhtPort, dhtBootstrapNodes: var_dhtBootstrapNodes, listenPort: var_listenPort, listenPortMax: var_listenPortMax, upnp: var_upnp, natpmp: var_natpmp, outgoingInterface: var_outgoingInterface, bindAddress: var_bindAddress, proxy: var_proxy, encryption: var_encryption, maxActiveDownloads: var_maxActiveDownloads); }
+
+@protected TorrentInfo sse_decode_torrent_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_totalSize = sse_decode_usize(deserializer);
+var var_pieceCount = sse_decode_usize(deserializer);
+var var_pieceLength = sse_decode_usize(deserializer);
+var var_files = sse_decode_list_file_info(deserializer);
+var var_infoHash = sse_decode_String(deserializer);
+var var_infoHashV2 = sse_decode_opt_String(deserializer);
+var var_metaVersion = sse_decode_meta_version(deserializer);
+var var_announce = sse_decode_String(deserializer);
+var var_webSeeds = sse_decode_list_String(deserializer);
+var var_fileTree = sse_decode_list_v_2_file_info(deserializer);
+return TorrentInfo(name: var_name, totalSize: var_totalSize, pieceCount: var_pieceCount, pieceLength: var_pieceLength, files: var_files, infoHash: var_infoHash, infoHashV2: var_infoHashV2, metaVersion: var_metaVersion, announce: var_announce, webSeeds: var_webSeeds, fileTree: var_fileTree); }
+
+@protected TrackerInfo sse_decode_tracker_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_tier = sse_decode_usize(deserializer);
+var var_lastUpdate = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_nextUpdate = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_isScraped = sse_decode_bool(deserializer);
+var var_seeders = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_leechers = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_completed = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_peers = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+var var_isUpdating = sse_decode_bool(deserializer);
+return TrackerInfo(url: var_url, tier: var_tier, lastUpdate: var_lastUpdate, nextUpdate: var_nextUpdate, isScraped: var_isScraped, seeders: var_seeders, leechers: var_leechers, completed: var_completed, peers: var_peers, lastError: var_lastError, isUpdating: var_isUpdating); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected V2FileInfo sse_decode_v_2_file_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_merkleRoot = sse_decode_opt_String(deserializer);
+return V2FileInfo(path: var_path, size: var_size, merkleRoot: var_merkleRoot); }
+
+@protected WebSeedInfo sse_decode_web_seed_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_downloadedBytes = sse_decode_u_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return WebSeedInfo(url: var_url, downloadedBytes: var_downloadedBytes, lastError: var_lastError); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_app_torrent_status_Sse(RustStreamSink<AppTorrentStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_app_torrent_status,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_create_torrent_progress_Sse(RustStreamSink<CreateTorrentProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_create_torrent_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_add_request(AddRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.source, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.torrentBytes, serializer);
+sse_encode_String(self.outputDir, serializer);
+sse_encode_opt_list_prim_usize_strict(self.selectedFileIndices, serializer);
+sse_encode_bool(self.useDefaultTrackers, serializer);
+ }
+
+@protected void sse_encode_add_result(AddResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_usize(self.id, serializer);
+sse_encode_opt_String(self.infoHash, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_bool(self.alreadyAdded, serializer);
+sse_encode_bool(self.queued, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_app_torrent_status(AppTorrentStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.id, serializer);
+sse_encode_u_32(self.totalPieces, serializer);
+sse_encode_u_32(self.completedPieces, serializer);
+sse_encode_u_32(self.peers, serializer);
+sse_encode_f_64(self.speedMbps, serializer);
+sse_encode_f_64(self.webSeedSpeedMbps, serializer);
+sse_encode_bool(self.downloading, serializer);
+sse_encode_bool(self.isFetchingMetadata, serializer);
+sse_encode_bool(self.isChecking, serializer);
+sse_encode_bool(self.isMoving, serializer);
+sse_encode_bool(self.isSequential, serializer);
+sse_encode_bool(self.isNetworkPaused, serializer);
+sse_encode_bool(self.isQueued, serializer);
+sse_encode_String(self.statusMessage, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_u_64(self.totalBytes, serializer);
+sse_encode_u_64(self.downloadedBytes, serializer);
+sse_encode_u_64(self.webSeedDownloadedBytes, serializer);
+sse_encode_opt_list_file_progress(self.files, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_create_torrent_options(CreateTorrentOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_torrent_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_created_torrent(CreatedTorrent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_created_torrent(self, serializer); }
+
+@protected void sse_encode_box_autoadd_proxy_config(ProxyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_proxy_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_session_config(SessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_session_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self, serializer); }
+
+@protected void sse_encode_create_torrent_options(CreateTorrentOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_box_autoadd_u_32(self.pieceLength, serializer);
+sse_encode_list_String(self.trackers, serializer);
+sse_encode_list_String(self.webSeeds, serializer);
+sse_encode_opt_String(self.comment, serializer);
+sse_encode_bool(self.private, serializer);
+sse_encode_opt_String(self.source, serializer);
+ }
+
+@protected void sse_encode_create_torrent_progress(CreateTorrentProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.hashedBytes, serializer);
+sse_encode_u_64(self.totalBytes, serializer);
+sse_encode_opt_box_autoadd_created_torrent(self.torrent, serializer);
+ }
+
+@protected void sse_encode_created_torrent(CreatedTorrent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.torrentBytes, serializer);
+sse_encode_String(self.magnet, serializer);
+sse_encode_String(self.infoHash, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.totalSize, serializer);
+sse_encode_u_32(self.pieceLength, serializer);
+sse_encode_usize(self.pieceCount, serializer);
+ }
+
+@protected void sse_encode_dht_bucket(DhtBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.prefixBits, serializer);
+sse_encode_u_32(self.nodes, serializer);
+ }
+
+@protected void sse_encode_dht_stats(DhtStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.enabled, serializer);
+sse_encode_String(self.nodeId, serializer);
+sse_encode_u_16(self.listenPort, serializer);
+sse_encode_u_32(self.nodes, serializer);
+sse_encode_list_dht_bucket(self.buckets, serializer);
+sse_encode_u_32(self.outstandingQueries, serializer);
+ }
+
+@protected void sse_encode_encryption_policy(EncryptionPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_file_info(FileInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.index, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_usize(self.size, serializer);
+sse_encode_opt_String(self.originalPath, serializer);
+sse_encode_file_priority(self.priority, serializer);
+sse_encode_u_64(self.downloadedBytes, serializer);
+sse_encode_opt_String(self.merkleRoot, serializer);
+ }
+
+@protected void sse_encode_file_priority(FilePriority self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_file_progress(FileProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.index, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_u_64(self.downloaded, serializer);
+sse_encode_bool(self.complete, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_ip_filter_stats(IpFilterStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.source, serializer);
+sse_encode_opt_String(self.format, serializer);
+sse_encode_usize(self.entries, serializer);
+sse_encode_usize(self.allowedEntries, serializer);
+sse_encode_usize(self.invalidLines, serializer);
+sse_encode_usize(self.ipv4Ranges, serializer);
+sse_encode_usize(self.ipv6Ranges, serializer);
+sse_encode_u_64(self.ipv4Addresses, serializer);
+sse_encode_bool(self.active, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_add_request(List<AddRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_add_request(item, serializer); } }
+
+@protected void sse_encode_list_add_result(List<AddResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_add_result(item, serializer); } }
+
+@protected void sse_encode_list_app_torrent_status(List<AppTorrentStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_app_torrent_status(item, serializer); } }
+
+@protected void sse_encode_list_dht_bucket(List<DhtBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_dht_bucket(item, serializer); } }
+
+@protected void sse_encode_list_file_info(List<FileInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_file_info(item, serializer); } }
+
+@protected void sse_encode_list_file_progress(List<FileProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_file_progress(item, serializer); } }
+
+@protected void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_info(item, serializer); } }
+
+@protected void sse_encode_list_peer_source_result(List<PeerSourceResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_source_result(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_prim_usize_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_record_usize_file_priority(List<(BigInt,FilePriority)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_usize_file_priority(item, serializer); } }
+
+@protected void sse_encode_list_tracker_info(List<TrackerInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_tracker_info(item, serializer); } }
+
+@protected void sse_encode_list_v_2_file_info(List<V2FileInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_v_2_file_info(item, serializer); } }
+
+@protected void sse_encode_list_web_seed_info(List<WebSeedInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_web_seed_info(item, serializer); } }
+
+@protected void sse_encode_magnet_info(MagnetInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.infoHash, serializer);
+sse_encode_opt_String(self.infoHashV2, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_list_String(self.trackers, serializer);
+sse_encode_list_String(self.webSeeds, serializer);
+ }
+
+@protected void sse_encode_meta_version(MetaVersion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_network_status(NetworkStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.listenAddress, serializer);
+sse_encode_opt_String(self.portMapping, serializer);
+sse_encode_opt_String(self.externalAddress, serializer);
+sse_encode_opt_box_autoadd_u_16(self.externalPort, serializer);
+sse_encode_opt_String(self.mappingError, serializer);
+sse_encode_bool(self.incomingSeen, serializer);
+sse_encode_bool(self.interfaceUp, serializer);
+sse_encode_opt_String(self.bindAddress, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_created_torrent(CreatedTorrent? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_created_torrent(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_proxy_config(ProxyConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_proxy_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usize(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_file_progress(List<FileProgress>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_file_progress(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_usize_strict(Uint64List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_usize_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_peer_ban(PeerBan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.disconnected, serializer);
+sse_encode_bool(self.active, serializer);
+ }
+
+@protected void sse_encode_peer_direction(PeerDirection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_peer_info(PeerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_peer_direction(self.direction, serializer);
+sse_encode_bool(self.encrypted, serializer);
+sse_encode_u_64(self.downloadBps, serializer);
+sse_encode_u_64(self.downloadedBytes, serializer);
+sse_encode_u_32(self.piecesDownloaded, serializer);
+sse_encode_u_32(self.errors, serializer);
+ }
+
+@protected void sse_encode_peer_source_result(PeerSourceResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.source, serializer);
+sse_encode_opt_box_autoadd_u_32(self.peers, serializer);
+sse_encode_opt_box_autoadd_u_32(self.newPeers, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_proxy_type(self.proxyType, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.username, serializer);
+sse_encode_opt_String(self.password, serializer);
+sse_encode_bool(self.proxyPeers, serializer);
+sse_encode_bool(self.proxyTrackers, serializer);
+ }
+
+@protected void sse_encode_proxy_type(ProxyType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_reannounce_summary(ReannounceSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_peer_source_result(self.sources, serializer);
+sse_encode_u_32(self.newPeers, serializer);
+ }
+
+@protected void sse_encode_record_usize_file_priority((BigInt,FilePriority) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.$1, serializer);
+sse_encode_file_priority(self.$2, serializer);
+ }
+
+@protected void sse_encode_session_config(SessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.incompleteDir, serializer);
+sse_encode_opt_String(self.completeDir, serializer);
+sse_encode_bool(self.partSuffix, serializer);
+sse_encode_bool(self.includeFileProgress, serializer);
+sse_encode_list_String(self.defaultTrackers, serializer);
+sse_encode_opt_String(self.defaultTrackersFile, serializer);
+sse_encode_opt_box_autoadd_u_16(self.dhtPort, serializer);
+sse_encode_list_String(self.dhtBootstrapNodes, serializer);
+sse_encode_opt_box_autoadd_u_16(self.listenPort, serializer);
+sse_encode_opt_box_autoadd_u_16(self.listenPortMax, serializer);
+sse_encode_bool(self.upnp, serializer);
+sse_encode_bool(self.natpmp, serializer);
+sse_encode_opt_String(self.outgoingInterface, serializer);
+sse_encode_opt_String(self.bindAddress, serializer);
+sse_encode_opt_box_autoadd_proxy_config(self.proxy, serializer);
+sse_encode_encryption_policy(self.encryption, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxActiveDownloads, serializer);
+ }
+
+@protected void sse_encode_torrent_info(TorrentInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_usize(self.totalSize, serializer);
+sse_encode_usize(self.pieceCount, serializer);
+sse_encode_usize(self.pieceLength, serializer);
+sse_encode_list_file_info(self.files, serializer);
+sse_encode_String(self.infoHash, serializer);
+sse_encode_opt_String(self.infoHashV2, serializer);
+sse_encode_meta_version(self.metaVersion, serializer);
+sse_encode_String(self.announce, serializer);
+sse_encode_list_String(self.webSeeds, serializer);
+sse_encode_list_v_2_file_info(self.fileTree, serializer);
+ }
+
+@protected void sse_encode_tracker_info(TrackerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_usize(self.tier, serializer);
+sse_encode_opt_box_autoadd_u_64(self.lastUpdate, serializer);
+sse_encode_opt_box_autoadd_u_64(self.nextUpdate, serializer);
+sse_encode_bool(self.isScraped, serializer);
+sse_encode_opt_box_autoadd_u_64(self.seeders, serializer);
+sse_encode_opt_box_autoadd_u_64(self.leechers, serializer);
+sse_encode_opt_box_autoadd_u_64(self.completed, serializer);
+sse_encode_opt_box_autoadd_u_32(self.peers, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+sse_encode_bool(self.isUpdating, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_v_2_file_info(V2FileInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_opt_String(self.merkleRoot, serializer);
+ }
+
+@protected void sse_encode_web_seed_info(WebSeedInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_u_64(self.downloadedBytes, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+                }
+                
@@ -7,7 +7,12 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/config.dart';
+import 'api/create.dart';
+import 'api/network.dart';
+import 'api/peers.dart';
 import 'api/simple.dart';
+import 'api/trackers.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -30,34 +35,140 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected RustStreamSink<AppTorrentStatus> dco_decode_StreamSink_app_torrent_status_Sse(dynamic raw);
 
+@protected RustStreamSink<CreateTorrentProgress> dco_decode_StreamSink_create_torrent_progress_Sse(dynamic raw);
+
 @protected String dco_decode_String(dynamic raw);
 
+@protected AddRequest dco_decode_add_request(dynamic raw);
+
+@protected AddResult dco_decode_add_result(dynamic raw);
+
 @protected AppTorrentStatus dco_decode_app_torrent_status(dynamic raw);
 
 @protected bool dco_decode_bool(dynamic raw);
 
+@protected CreateTorrentOptions dco_decode_box_autoadd_create_torrent_options(dynamic raw);
+
+@protected CreatedTorrent dco_decode_box_autoadd_created_torrent(dynamic raw);
+
+@protected ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw);
+
+@protected SessionConfig dco_decode_box_autoadd_session_config(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+@protected CreateTorrentOptions dco_decode_create_torrent_options(dynamic raw);
+
+@protected CreateTorrentProgress dco_decode_create_torrent_progress(dynamic raw);
+
+@protected CreatedTorrent dco_decode_created_torrent(dynamic raw);
+
+@protected DhtBucket dco_decode_dht_bucket(dynamic raw);
+
+@protected DhtStats dco_decode_dht_stats(dynamic raw);
+
+@protected EncryptionPolicy dco_decode_encryption_policy(dynamic raw);
+
 @protected double dco_decode_f_64(dynamic raw);
 
 @protected FileInfo dco_decode_file_info(dynamic raw);
 
+@protected FilePriority dco_decode_file_priority(dynamic raw);
+
+@protected FileProgress dco_decode_file_progress(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected IpFilterStats dco_decode_ip_filter_stats(dynamic raw);
+
 @protected List<String> dco_decode_list_String(dynamic raw);
 
+@protected List<AddRequest> dco_decode_list_add_request(dynamic raw);
+
+@protected List<AddResult> dco_decode_list_add_result(dynamic raw);
+
 @protected List<AppTorrentStatus> dco_decode_list_app_torrent_status(dynamic raw);
 
+@protected List<DhtBucket> dco_decode_list_dht_bucket(dynamic raw);
+
 @protected List<FileInfo> dco_decode_list_file_info(dynamic raw);
 
+@protected List<FileProgress> dco_decode_list_file_progress(dynamic raw);
+
+@protected List<PeerInfo> dco_decode_list_peer_info(dynamic raw);
+
+@protected List<PeerSourceResult> dco_decode_list_peer_source_result(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
 @protected Uint64List dco_decode_list_prim_usize_strict(dynamic raw);
 
+@protected List<(BigInt,FilePriority)> dco_decode_list_record_usize_file_priority(dynamic raw);
+
+@protected List<TrackerInfo> dco_decode_list_tracker_info(dynamic raw);
+
+@protected List<V2FileInfo> dco_decode_list_v_2_file_info(dynamic raw);
+
+@protected List<WebSeedInfo> dco_decode_list_web_seed_info(dynamic raw);
+
 @protected MagnetInfo dco_decode_magnet_info(dynamic raw);
 
+@protected MetaVersion dco_decode_meta_version(dynamic raw);
+
+@protected NetworkStatus dco_decode_network_status(dynamic raw);
+
 @protected String? dco_decode_opt_String(dynamic raw);
 
+@protected CreatedTorrent? dco_decode_opt_box_autoadd_created_torrent(dynamic raw);
+
+@protected ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+@protected List<FileProgress>? dco_decode_opt_list_file_progress(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
 @protected Uint64List? dco_decode_opt_list_prim_usize_strict(dynamic raw);
 
+@protected PeerBan dco_decode_peer_ban(dynamic raw);
+
+@protected PeerDirection dco_decode_peer_direction(dynamic raw);
+
+@protected PeerInfo dco_decode_peer_info(dynamic raw);
+
+@protected PeerSourceResult dco_decode_peer_source_result(dynamic raw);
+
+@protected ProxyConfig dco_decode_proxy_config(dynamic raw);
+
+@protected ProxyType dco_decode_proxy_type(dynamic raw);
+
+@protected ReannounceSummary dco_decode_reannounce_summary(dynamic raw);
+
+@protected (BigInt,FilePriority) dco_decode_record_usize_file_priority(dynamic raw);
+
+@protected SessionConfig dco_decode_session_config(dynamic raw);
+
 @protected TorrentInfo dco_decode_torrent_info(dynamic raw);
 
+@protected TrackerInfo dco_decode_tracker_info(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
 @protected int dco_decode_u_32(dynamic raw);
 
 @protected BigInt dco_decode_u_64(dynamic raw);
@@ -68,38 +179,148 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected BigInt dco_decode_usize(dynamic raw);
 
+@protected V2FileInfo dco_decode_v_2_file_info(dynamic raw);
+
+@protected WebSeedInfo dco_decode_web_seed_info(dynamic raw);
+
 @protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
 @protected RustStreamSink<AppTorrentStatus> sse_decode_StreamSink_app_torrent_status_Sse(SseDeserializer deserializer);
 
+@protected RustStreamSink<CreateTorrentProgress> sse_decode_StreamSink_create_torrent_progress_Sse(SseDeserializer deserializer);
+
 @protected String sse_decode_String(SseDeserializer deserializer);
 
+@protected AddRequest sse_decode_add_request(SseDeserializer deserializer);
+
+@protected AddResult sse_decode_add_result(SseDeserializer deserializer);
+
 @protected AppTorrentStatus sse_decode_app_torrent_status(SseDeserializer deserializer);
 
 @protected bool sse_decode_bool(SseDeserializer deserializer);
 
+@protected CreateTorrentOptions sse_decode_box_autoadd_create_torrent_options(SseDeserializer deserializer);
+
+@protected CreatedTorrent sse_decode_box_autoadd_created_torrent(SseDeserializer deserializer);
+
+@protected ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+@protected SessionConfig sse_decode_box_autoadd_session_config(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected CreateTorrentOptions sse_decode_create_torrent_options(SseDeserializer deserializer);
+
+@protected CreateTorrentProgress sse_decode_create_torrent_progress(SseDeserializer deserializer);
+
+@protected CreatedTorrent sse_decode_created_torrent(SseDeserializer deserializer);
+
+@protected DhtBucket sse_decode_dht_bucket(SseDeserializer deserializer);
+
+@protected DhtStats sse_decode_dht_stats(SseDeserializer deserializer);
+
+@protected EncryptionPolicy sse_decode_encryption_policy(SseDeserializer deserializer);
+
 @protected double sse_decode_f_64(SseDeserializer deserializer);
 
 @protected FileInfo sse_decode_file_info(SseDeserializer deserializer);
 
+@protected FilePriority sse_decode_file_priority(SseDeserializer deserializer);
+
+@protected FileProgress sse_decode_file_progress(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected IpFilterStats sse_decode_ip_filter_stats(SseDeserializer deserializer);
+
 @protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+@protected List<AddRequest> sse_decode_list_add_request(SseDeserializer deserializer);
+
+@protected List<AddResult> sse_decode_list_add_result(SseDeserializer deserializer);
+
 @protected List<AppTorrentStatus> sse_decode_list_app_torrent_status(SseDeserializer deserializer);
 
+@protected List<DhtBucket> sse_decode_list_dht_bucket(SseDeserializer deserializer);
+
 @protected List<FileInfo> sse_decode_list_file_info(SseDeserializer deserializer);
 
+@protected List<FileProgress> sse_decode_list_file_progress(SseDeserializer deserializer);
+
+@protected List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer);
+
+@protected List<PeerSourceResult> sse_decode_list_peer_source_result(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
 @protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
 @protected Uint64List sse_decode_list_prim_usize_strict(SseDeserializer deserializer);
 
+@protected List<(BigInt,FilePriority)> sse_decode_list_record_usize_file_priority(SseDeserializer deserializer);
+
+@protected List<TrackerInfo> sse_decode_list_tracker_info(SseDeserializer deserializer);
+
+@protected List<V2FileInfo> sse_decode_list_v_2_file_info(SseDeserializer deserializer);
+
+@protected List<WebSeedInfo> sse_decode_list_web_seed_info(SseDeserializer deserializer);
+
 @protected MagnetInfo sse_decode_magnet_info(SseDeserializer deserializer);
 
+@protected MetaVersion sse_decode_meta_version(SseDeserializer deserializer);
+
+@protected NetworkStatus sse_decode_network_status(SseDeserializer deserializer);
+
 @protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
+@protected CreatedTorrent? sse_decode_opt_box_autoadd_created_torrent(SseDeserializer deserializer);
+
+@protected ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected List<FileProgress>? sse_decode_opt_list_file_progress(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
 @protected Uint64List? sse_decode_opt_list_prim_usize_strict(SseDeserializer deserializer);
 
+@protected PeerBan sse_decode_peer_ban(SseDeserializer deserializer);
+
+@protected PeerDirection sse_decode_peer_direction(SseDeserializer deserializer);
+
+@protected PeerInfo sse_decode_peer_info(SseDeserializer deserializer);
+
+@protected PeerSourceResult sse_decode_peer_source_result(SseDeserializer deserializer);
+
+@protected ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer);
+
+@protected ProxyType sse_decode_proxy_type(SseDeserializer deserializer);
+
+@protected ReannounceSummary sse_decode_reannounce_summary(SseDeserializer deserializer);
+
+@protected (BigInt,FilePriority) sse_decode_record_usize_file_priority(SseDeserializer deserializer);
+
+@protected SessionConfig sse_decode_session_config(SseDeserializer deserializer);
+
 @protected TorrentInfo sse_decode_torrent_info(SseDeserializer deserializer);
 
+@protected TrackerInfo sse_decode_tracker_info(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
 @protected int sse_decode_u_32(SseDeserializer deserializer);
 
 @protected BigInt sse_decode_u_64(SseDeserializer deserializer);
@@ -110,40 +331,148 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected BigInt sse_decode_usize(SseDeserializer deserializer);
 
-@protected int sse_decode_i_32(SseDeserializer deserializer);
+@protected V2FileInfo sse_decode_v_2_file_info(SseDeserializer deserializer);
+
+@protected WebSeedInfo sse_decode_web_seed_info(SseDeserializer deserializer);
 
 @protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
 
 @protected void sse_encode_StreamSink_app_torrent_status_Sse(RustStreamSink<AppTorrentStatus> self, SseSerializer serializer);
 
+@protected void sse_encode_StreamSink_create_torrent_progress_Sse(RustStreamSink<CreateTorrentProgress> self, SseSerializer serializer);
+
 @protected void sse_encode_String(String self, SseSerializer serializer);
 
+@protected void sse_encode_add_request(AddRequest self, SseSerializer serializer);
+
+@protected void sse_encode_add_result(AddResult self, SseSerializer serializer);
+
 @protected void sse_encode_app_torrent_status(AppTorrentStatus self, SseSerializer serializer);
 
 @protected void sse_encode_bool(bool self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_create_torrent_options(CreateTorrentOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_created_torrent(CreatedTorrent self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_proxy_config(ProxyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_session_config(SessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_create_torrent_options(CreateTorrentOptions self, SseSerializer serializer);
+
+@protected void sse_encode_create_torrent_progress(CreateTorrentProgress self, SseSerializer serializer);
+
+@protected void sse_encode_created_torrent(CreatedTorrent self, SseSerializer serializer);
+
+@protected void sse_encode_dht_bucket(DhtBucket self, SseSerializer serializer);
+
+@protected void sse_encode_dht_stats(DhtStats self, SseSerializer serializer);
+
+@protected void sse_encode_encryption_policy(EncryptionPolicy self, SseSerializer serializer);
+
 @protected void sse_encode_f_64(double self, SseSerializer serializer);
 
 @protected void sse_encode_file_info(FileInfo self, SseSerializer serializer);
 
+@protected void sse_encode_file_priority(FilePriority self, SseSerializer serializer);
+
+@protected void sse_encode_file_progress(FileProgress self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_ip_filter_stats(IpFilterStats self, SseSerializer serializer);
+
 @protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+@protected void sse_encode_list_add_request(List<AddRequest> self, SseSerializer serializer);
+
+@protected void sse_encode_list_add_result(List<AddResult> self, SseSerializer serializer);
+
 @protected void sse_encode_list_app_torrent_status(List<AppTorrentStatus> self, SseSerializer serializer);
 
+@protected void sse_encode_list_dht_bucket(List<DhtBucket> self, SseSerializer serializer);
+
 @protected void sse_encode_list_file_info(List<FileInfo> self, SseSerializer serializer);
 
+@protected void sse_encode_list_file_progress(List<FileProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_source_result(List<PeerSourceResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
 @protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
 @protected void sse_encode_list_prim_usize_strict(Uint64List self, SseSerializer serializer);
 
+@protected void sse_encode_list_record_usize_file_priority(List<(BigInt,FilePriority)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tracker_info(List<TrackerInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_v_2_file_info(List<V2FileInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_web_seed_info(List<WebSeedInfo> self, SseSerializer serializer);
+
 @protected void sse_encode_magnet_info(MagnetInfo self, SseSerializer serializer);
 
+@protected void sse_encode_meta_version(MetaVersion self, SseSerializer serializer);
+
+@protected void sse_encode_network_status(NetworkStatus self, SseSerializer serializer);
+
 @protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_created_torrent(CreatedTorrent? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_proxy_config(ProxyConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_file_progress(List<FileProgress>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_list_prim_usize_strict(Uint64List? self, SseSerializer serializer);
 
+@protected void sse_encode_peer_ban(PeerBan self, SseSerializer serializer);
+
+@protected void sse_encode_peer_direction(PeerDirection self, SseSerializer serializer);
+
+@protected void sse_encode_peer_info(PeerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_peer_source_result(PeerSourceResult self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_type(ProxyType self, SseSerializer serializer);
+
+@protected void sse_encode_reannounce_summary(ReannounceSummary self, SseSerializer serializer);
+
+@protected void sse_encode_record_usize_file_priority((BigInt,FilePriority) self, SseSerializer serializer);
+
+@protected void sse_encode_session_config(SessionConfig self, SseSerializer serializer);
+
 @protected void sse_encode_torrent_info(TorrentInfo self, SseSerializer serializer);
 
+@protected void sse_encode_tracker_info(TrackerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
 @protected void sse_encode_u_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
@@ -154,7 +483,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected void sse_encode_usize(BigInt self, SseSerializer serializer);
 
-@protected void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_v_2_file_info(V2FileInfo self, SseSerializer serializer);
+
+@protected void sse_encode_web_seed_info(WebSeedInfo self, SseSerializer serializer);
                 }
                 
 
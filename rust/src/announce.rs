@@ -0,0 +1,851 @@
+// Per-tracker status, from scrapes and our own announces.
+//
+// librqbit announces to a torrent's trackers but keeps the responses to
+// itself. The trackers it announces to are scraped (BEP 48) from here for
+// their swarm counts rather than announced to a second time. Trackers it
+// skips, all but the first of a private torrent, are announced to from here
+// with the session's peer id and the usual started, completed and stopped
+// events. The outcome of the last
+// request is kept for get_trackers.
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, SystemTime};
+use anyhow::{bail, Context, Result};
+use futures::StreamExt;
+use librqbit::api::TorrentIdOrHash;
+use librqbit::dht::Id20;
+use librqbit::{generate_azereus_style, ByteBufOwned, ManagedTorrent, Session, TorrentStatsState};
+use serde::Deserialize;
+use tokio::net::UdpSocket;
+use url::Url;
+use crate::{get_session, interface, port_mapping, proxy, torrents};
+
+const ANNOUNCE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Wait for each UDP packet before sending it again
+const UDP_RETRY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Wait after a failed announce, as librqbit does
+const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+/// Lower bound for the interval trackers ask for
+const MIN_INTERVAL: Duration = Duration::from_secs(60);
+/// How often trackers librqbit announces to are scraped
+const SCRAPE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Peer id of the session, given to librqbit so our announces are for the
+/// same peer as its own
+pub static PEER_ID: LazyLock<Id20> = LazyLock::new(|| generate_azereus_style(*b"rQ", (8, 1, 1, 0)));
+
+/// Outcome of the scrapes of or announces to one tracker
+#[derive(Debug, Clone, Default)]
+pub struct TrackerState {
+    pub last_update: Option<SystemTime>,
+    /// None while a request is under way or the torrent isn't live
+    pub next_update: Option<SystemTime>,
+    /// librqbit announces to the tracker, so it is scraped rather than
+    /// announced to
+    pub scraped: bool,
+    pub seeders: Option<u64>,
+    pub leechers: Option<u64>,
+    pub completed: Option<u64>,
+    /// Peers in the last successful announce, None for scrapes
+    pub peers: Option<usize>,
+    pub last_error: Option<String>,
+    pub updating: bool,
+    /// A started event was sent, and no stopped one since
+    started: bool,
+    /// The torrent was complete when started, or the completed event was sent
+    completed_sent: bool,
+}
+
+/// Announce events (BEP 3)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    None,
+    Started,
+    Completed,
+    Stopped,
+}
+
+impl Event {
+    fn http(self) -> Option<&'static str> {
+        match self {
+            Event::None => None,
+            Event::Started => Some("started"),
+            Event::Completed => Some("completed"),
+            Event::Stopped => Some("stopped"),
+        }
+    }
+
+    fn udp(self) -> u32 {
+        match self {
+            Event::None => 0,
+            Event::Completed => 1,
+            Event::Started => 2,
+            Event::Stopped => 3,
+        }
+    }
+}
+
+/// The event the next announce to a tracker carries
+fn next_event(state: &TrackerState, complete: bool) -> Event {
+    if !state.started {
+        Event::Started
+    } else if complete && !state.completed_sent {
+        Event::Completed
+    } else {
+        Event::None
+    }
+}
+
+static STATES: LazyLock<Mutex<HashMap<usize, HashMap<Url, TrackerState>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn with_states<R>(id: usize, f: impl FnOnce(&mut HashMap<Url, TrackerState>) -> R) -> R {
+    let mut states = STATES.lock().unwrap_or_else(|e| e.into_inner());
+    f(states.entry(id).or_default())
+}
+
+/// Status of every tracker of a torrent scraped or announced to so far
+pub fn tracker_states(id: usize) -> HashMap<Url, TrackerState> {
+    with_states(id, |s| s.clone())
+}
+
+/// What is sent with an announce
+struct Request {
+    info_hash: Id20,
+    port: u16,
+    uploaded: u64,
+    downloaded: u64,
+    left: u64,
+    event: Event,
+}
+
+/// A tracker's response to an announce or a scrape
+pub struct Response {
+    pub interval: Duration,
+    pub seeders: Option<u64>,
+    pub leechers: Option<u64>,
+    pub completed: Option<u64>,
+    /// None for scrapes
+    pub peers: Option<Vec<SocketAddr>>,
+}
+
+/// Trackers librqbit announces to itself: all of the torrent's, or only the
+/// first for a private torrent with several
+pub fn librqbit_trackers(handle: &ManagedTorrent) -> HashSet<Url> {
+    let trackers = &handle.shared().trackers;
+    let is_private = handle.with_metadata(|m| m.info.private).unwrap_or(false);
+    if is_private && trackers.len() > 1 {
+        trackers.iter().take(1).cloned().collect()
+    } else {
+        trackers.clone()
+    }
+}
+
+/// Whether a torrent has all of its selected data
+fn is_complete(handle: &ManagedTorrent) -> bool {
+    let stats = handle.stats();
+    stats.total_bytes > 0 && stats.progress_bytes >= stats.total_bytes
+}
+
+/// Announce a torrent to one tracker, with its current transfer stats
+async fn announce(session: &Session, handle: &ManagedTorrent, tracker: &Url, event: Event) -> Result<Response> {
+    let stats = handle.stats();
+    let request = Request {
+        info_hash: handle.info_hash(),
+        port: port_mapping::announce_port(session.tcp_listen_port()).unwrap_or(0),
+        uploaded: stats.uploaded_bytes,
+        downloaded: stats.progress_bytes,
+        left: stats.total_bytes.saturating_sub(stats.progress_bytes),
+        event,
+    };
+    let response = async {
+        match tracker.scheme() {
+            "http" | "https" => announce_http(tracker, &request).await,
+            "udp" => announce_udp(tracker, &request).await,
+            scheme => bail!("Unsupported tracker scheme: {}", scheme),
+        }
+    };
+    tokio::time::timeout(ANNOUNCE_TIMEOUT, response).await
+        .map_err(|_| anyhow::anyhow!("Tracker timed out"))?
+}
+
+/// Scrape one tracker for the swarm counts of a torrent
+async fn scrape(tracker: &Url, info_hash: Id20) -> Result<Response> {
+    let response = async {
+        match tracker.scheme() {
+            "http" | "https" => scrape_http(tracker, info_hash).await,
+            "udp" => scrape_udp(tracker, info_hash).await,
+            scheme => bail!("Unsupported tracker scheme: {}", scheme),
+        }
+    };
+    tokio::time::timeout(ANNOUNCE_TIMEOUT, response).await
+        .map_err(|_| anyhow::anyhow!("Tracker timed out"))?
+}
+
+#[derive(Deserialize)]
+struct HttpResponse {
+    #[serde(rename = "failure reason")]
+    failure_reason: Option<ByteBufOwned>,
+    interval: Option<u64>,
+    complete: Option<u64>,
+    incomplete: Option<u64>,
+    downloaded: Option<u64>,
+    peers: Option<HttpPeers>,
+    peers6: Option<ByteBufOwned>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HttpPeers {
+    Compact(ByteBufOwned),
+    Dicts(Vec<HttpPeer>),
+}
+
+#[derive(Deserialize)]
+struct HttpPeer {
+    ip: String,
+    port: u16,
+}
+
+/// Percent-encode raw bytes for a query string
+fn url_encode(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut out, b| {
+        if b.is_ascii_alphanumeric() || b"-._~".contains(b) {
+            out.push(*b as char);
+        } else {
+            let _ = write!(out, "%{:02X}", b);
+        }
+        out
+    })
+}
+
+/// Peers in the compact format: 4 (IPv4) or 16 (IPv6) address bytes, then
+/// the port, big-endian
+fn compact_peers(bytes: &[u8], ipv6: bool) -> Vec<SocketAddr> {
+    let ip_len = if ipv6 { 16 } else { 4 };
+    bytes.chunks_exact(ip_len + 2)
+        .map(|chunk| {
+            let ip = if ipv6 {
+                IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(&chunk[..16]).unwrap()))
+            } else {
+                IpAddr::V4(Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3]))
+            };
+            SocketAddr::new(ip, u16::from_be_bytes([chunk[ip_len], chunk[ip_len + 1]]))
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct ScrapeResponse {
+    #[serde(rename = "failure reason")]
+    failure_reason: Option<ByteBufOwned>,
+    files: Option<HashMap<ByteBufOwned, ScrapeFile>>,
+}
+
+#[derive(Deserialize)]
+struct ScrapeFile {
+    complete: Option<u64>,
+    incomplete: Option<u64>,
+    downloaded: Option<u64>,
+}
+
+/// Add `query` to a tracker URL, after any query it already has, such as a
+/// passkey
+fn with_query(tracker: &Url, query: &str) -> Url {
+    let mut url = tracker.clone();
+    match tracker.query().filter(|q| !q.is_empty()) {
+        Some(existing) => url.set_query(Some(&format!("{}&{}", existing, query))),
+        None => url.set_query(Some(query)),
+    }
+    url
+}
+
+/// The scrape URL of an HTTP tracker: its announce URL with the last path
+/// segment's leading "announce" replaced by "scrape" (BEP 48). Trackers whose
+/// URL doesn't end that way don't support scraping.
+fn scrape_url(tracker: &Url) -> Option<Url> {
+    let path = tracker.path();
+    let (dir, last) = path.rsplit_once('/')?;
+    let rest = last.strip_prefix("announce")?;
+    let mut url = tracker.clone();
+    url.set_path(&format!("{}/scrape{}", dir, rest));
+    Some(url)
+}
+
+async fn http_get(url: Url) -> Result<bytes::Bytes> {
+    let response = proxy::http_client(proxy::tracker_proxy())?.get(url).send().await?;
+    if !response.status().is_success() {
+        bail!("Tracker responded with {}", response.status());
+    }
+    Ok(response.bytes().await?)
+}
+
+async fn scrape_http(tracker: &Url, info_hash: Id20) -> Result<Response> {
+    let url = scrape_url(tracker).context("Tracker doesn't support scraping")?;
+    let bytes = http_get(with_query(&url, &format!("info_hash={}", url_encode(&info_hash.0)))).await?;
+    let response: ScrapeResponse = librqbit_bencode::from_bytes(&bytes)
+        .context("Invalid tracker response")?;
+    if let Some(reason) = response.failure_reason {
+        bail!("Tracker error: {}", String::from_utf8_lossy(reason.as_ref()));
+    }
+    let file = response.files.unwrap_or_default().into_iter()
+        .find(|(hash, _)| hash.as_ref() == info_hash.0)
+        .map(|(_, file)| file)
+        .context("Tracker doesn't know the torrent")?;
+    Ok(Response {
+        interval: SCRAPE_INTERVAL,
+        seeders: file.complete,
+        leechers: file.incomplete,
+        completed: file.downloaded,
+        peers: None,
+    })
+}
+
+async fn announce_http(tracker: &Url, request: &Request) -> Result<Response> {
+    let mut query = format!(
+        "info_hash={}&peer_id={}&port={}&uploaded={}&downloaded={}&left={}&compact=1&no_peer_id=1",
+        url_encode(&request.info_hash.0),
+        url_encode(&PEER_ID.0),
+        request.port,
+        request.uploaded,
+        request.downloaded,
+        request.left,
+    );
+    if let Some(event) = request.event.http() {
+        query.push_str("&event=");
+        query.push_str(event);
+    }
+
+    let bytes = http_get(with_query(tracker, &query)).await?;
+    let response: HttpResponse = librqbit_bencode::from_bytes(&bytes)
+        .context("Invalid tracker response")?;
+    if let Some(reason) = response.failure_reason {
+        bail!("Tracker error: {}", String::from_utf8_lossy(reason.as_ref()));
+    }
+
+    let mut peers = match response.peers {
+        Some(HttpPeers::Compact(bytes)) => compact_peers(bytes.as_ref(), false),
+        Some(HttpPeers::Dicts(dicts)) => dicts.iter()
+            .filter_map(|p| Some(SocketAddr::new(p.ip.parse().ok()?, p.port)))
+            .collect(),
+        None => Vec::new(),
+    };
+    if let Some(bytes) = response.peers6 {
+        peers.extend(compact_peers(bytes.as_ref(), true));
+    }
+    Ok(Response {
+        interval: Duration::from_secs(response.interval.context("Tracker sent no interval")?),
+        seeders: response.complete,
+        leechers: response.incomplete,
+        completed: response.downloaded,
+        peers: Some(peers),
+    })
+}
+
+// UDP tracker protocol (BEP 15)
+const UDP_PROTOCOL_ID: u64 = 0x41727101980;
+const UDP_CONNECT: u32 = 0;
+const UDP_ANNOUNCE: u32 = 1;
+const UDP_SCRAPE: u32 = 2;
+const UDP_ERROR: u32 = 3;
+
+/// Send a UDP tracker request until a response to it arrives. Requests
+/// start with the connection id (the protocol id when connecting), then the
+/// action and transaction id. Returns the response after those.
+async fn udp_request(socket: &UdpSocket, connection_id: [u8; 8], action: u32, body: &[u8]) -> Result<Vec<u8>> {
+    let transaction_id: u32 = rand::random();
+    let mut packet = connection_id.to_vec();
+    packet.extend_from_slice(&action.to_be_bytes());
+    packet.extend_from_slice(&transaction_id.to_be_bytes());
+    packet.extend_from_slice(body);
+
+    let mut buf = vec![0u8; 65536];
+    loop {
+        socket.send(&packet).await?;
+        let deadline = tokio::time::Instant::now() + UDP_RETRY_TIMEOUT;
+        while let Ok(received) = tokio::time::timeout_at(deadline, socket.recv(&mut buf)).await {
+            let len = received?;
+            if len < 8 || buf[4..8] != transaction_id.to_be_bytes() {
+                continue;
+            }
+            let response_action = u32::from_be_bytes(buf[..4].try_into().unwrap());
+            if response_action == UDP_ERROR {
+                bail!("Tracker error: {}", String::from_utf8_lossy(&buf[8..len]));
+            }
+            if response_action != action {
+                bail!("Unexpected tracker response action {}", response_action);
+            }
+            return Ok(buf[8..len].to_vec());
+        }
+    }
+}
+
+/// Connect to a UDP tracker, returning the socket and connection id
+async fn udp_connect(tracker: &Url) -> Result<(UdpSocket, [u8; 8])> {
+    if proxy::tracker_proxy().is_some() {
+        bail!("UDP trackers are skipped while announces go through a proxy");
+    }
+    let host = tracker.host_str().context("Tracker URL has no host")?;
+    let port = tracker.port().context("Tracker URL has no port")?;
+    let address = tokio::net::lookup_host((host, port)).await?
+        .next()
+        .context(format!("Could not resolve {}", host))?;
+    let socket = match (address, interface::bind_ip()) {
+        (_, Some(ip)) if ip.is_ipv4() == address.is_ipv4() => UdpSocket::bind((ip, 0)).await?,
+        (_, Some(ip)) => bail!("Tracker address {} can't be reached from {}", address, ip),
+        (SocketAddr::V4(_), None) => UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?,
+        (SocketAddr::V6(_), None) => UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0)).await?,
+    };
+    socket.connect(address).await?;
+
+    let response = udp_request(&socket, UDP_PROTOCOL_ID.to_be_bytes(), UDP_CONNECT, &[]).await?;
+    let connection_id: [u8; 8] = response.get(..8)
+        .context("Short tracker connect response")?
+        .try_into()?;
+    Ok((socket, connection_id))
+}
+
+async fn scrape_udp(tracker: &Url, info_hash: Id20) -> Result<Response> {
+    let (socket, connection_id) = udp_connect(tracker).await?;
+    let response = udp_request(&socket, connection_id, UDP_SCRAPE, &info_hash.0).await?;
+    if response.len() < 12 {
+        bail!("Short tracker scrape response");
+    }
+    let field = |i: usize| u32::from_be_bytes(response[i * 4..i * 4 + 4].try_into().unwrap()) as u64;
+    Ok(Response {
+        interval: SCRAPE_INTERVAL,
+        seeders: Some(field(0)),
+        completed: Some(field(1)),
+        leechers: Some(field(2)),
+        peers: None,
+    })
+}
+
+async fn announce_udp(tracker: &Url, request: &Request) -> Result<Response> {
+    let (socket, connection_id) = udp_connect(tracker).await?;
+    let address = socket.peer_addr()?;
+
+    let mut body = request.info_hash.0.to_vec();
+    body.extend_from_slice(&PEER_ID.0);
+    body.extend_from_slice(&request.downloaded.to_be_bytes());
+    body.extend_from_slice(&request.left.to_be_bytes());
+    body.extend_from_slice(&request.uploaded.to_be_bytes());
+    body.extend_from_slice(&request.event.udp().to_be_bytes());
+    body.extend_from_slice(&0u32.to_be_bytes()); // IP: the sender's
+    body.extend_from_slice(&rand::random::<u32>().to_be_bytes()); // Key
+    body.extend_from_slice(&(-1i32).to_be_bytes()); // Peers wanted: the default
+    body.extend_from_slice(&request.port.to_be_bytes());
+
+    let response = udp_request(&socket, connection_id, UDP_ANNOUNCE, &body).await?;
+    if response.len() < 12 {
+        bail!("Short tracker announce response");
+    }
+    let field = |i: usize| u32::from_be_bytes(response[i * 4..i * 4 + 4].try_into().unwrap());
+    Ok(Response {
+        interval: Duration::from_secs(field(0) as u64),
+        leechers: Some(field(1) as u64),
+        seeders: Some(field(2) as u64),
+        completed: None, // Only in scrape responses
+        peers: Some(compact_peers(&response[12..], address.is_ipv6())),
+    })
+}
+
+/// What is sent to a tracker
+#[derive(Debug, Clone, Copy)]
+enum Update {
+    Scrape,
+    /// An announce, and whether the torrent was complete when it was chosen
+    Announce(Event, bool),
+}
+
+/// Mark a tracker as being updated and choose the request to send it
+fn begin_update(state: &mut TrackerState, scraped: bool, complete: bool) -> Update {
+    if scraped && !state.scraped {
+        // librqbit took over announcing, with the same peer id: a stopped
+        // event now would unregister its announces
+        state.started = false;
+    }
+    state.scraped = scraped;
+    state.updating = true;
+    state.next_update = None;
+    if scraped {
+        Update::Scrape
+    } else {
+        Update::Announce(next_event(state, complete), complete)
+    }
+}
+
+async fn send(session: &Session, handle: &ManagedTorrent, tracker: &Url, update: Update) -> Result<Response> {
+    match update {
+        Update::Scrape => scrape(tracker, handle.info_hash()).await,
+        Update::Announce(event, _) => announce(session, handle, tracker, event).await,
+    }
+}
+
+/// Record the outcome of a scrape or announce
+fn record(id: usize, tracker: &Url, update: Update, result: &Result<Response>) {
+    let now = SystemTime::now();
+    with_states(id, |states| {
+        let state = states.entry(tracker.clone()).or_default();
+        state.last_update = Some(now);
+        state.updating = false;
+        match result {
+            Ok(response) => {
+                match update {
+                    Update::Announce(Event::Stopped, _) => state.started = false,
+                    Update::Announce(Event::Started, complete) => {
+                        state.started = true;
+                        state.completed_sent = complete;
+                    }
+                    Update::Announce(Event::Completed, _) => state.completed_sent = true,
+                    _ => {}
+                }
+                state.next_update = match update {
+                    Update::Announce(Event::Stopped, _) => None,
+                    _ => Some(now + response.interval.max(MIN_INTERVAL)),
+                };
+                state.seeders = response.seeders;
+                state.leechers = response.leechers;
+                state.completed = response.completed;
+                state.peers = response.peers.as_ref().map(|p| p.len());
+                state.last_error = None;
+            }
+            Err(e) => {
+                state.next_update = Some(now + RETRY_INTERVAL);
+                state.last_error = Some(format!("{:#}", e));
+            }
+        }
+    });
+}
+
+/// Scrape or announce to one tracker of a torrent right away, whether or not
+/// it is due, and record the outcome like the monitor does. Returns the
+/// peers the tracker sent, None if it was scraped.
+pub async fn update_now(session: &Session, handle: &ManagedTorrent, tracker: &Url) -> Result<Option<Vec<SocketAddr>>> {
+    let scraped = librqbit_trackers(handle).contains(tracker);
+    let complete = is_complete(handle);
+    let update = with_states(handle.id(), |states| {
+        begin_update(states.entry(tracker.clone()).or_default(), scraped, complete)
+    });
+    let result = send(session, handle, tracker, update).await;
+    record(handle.id(), tracker, update, &result);
+    result.map(|response| response.peers)
+}
+
+/// Peers of a torrent found by a DHT lookup within `duration`
+pub async fn dht_lookup(session: &Session, handle: &ManagedTorrent, duration: Duration) -> Result<HashSet<SocketAddr>> {
+    let dht = session.get_dht().context("DHT is disabled")?;
+    let mut stream = dht.get_peers(handle.info_hash(), port_mapping::announce_port(session.tcp_listen_port()));
+    let deadline = tokio::time::Instant::now() + duration;
+    let mut peers = HashSet::new();
+    while let Ok(Some(peer)) = tokio::time::timeout_at(deadline, stream.next()).await {
+        peers.insert(peer);
+    }
+    Ok(peers)
+}
+
+/// Scrape or announce to the trackers of a registered torrent whenever they
+/// are due while it is live, until it is removed from the session
+pub fn spawn_monitor(id: usize) {
+    tokio::spawn(async move {
+        let mut last = None;
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            let Ok(session) = get_session().await else {
+                continue;
+            };
+            match session.get(TorrentIdOrHash::Id(id)) {
+                Some(handle) => {
+                    update_due(&session, &handle);
+                    last = Some((session, handle));
+                }
+                None if torrents::is_readding(id) => continue,
+                None => {
+                    // Removed from the session
+                    if let Some((session, handle)) = last {
+                        stop_all(&session, &handle).await;
+                    }
+                    break;
+                }
+            }
+        }
+        STATES.lock().unwrap_or_else(|e| e.into_inner()).remove(&id);
+    });
+}
+
+/// Send the stopped event to every tracker we sent started to
+async fn stop_all(session: &Session, handle: &ManagedTorrent) {
+    let started: Vec<Url> = with_states(handle.id(), |states| {
+        states.iter().filter(|(_, s)| s.started).map(|(url, _)| url.clone()).collect()
+    });
+    let stops = started.iter().map(|tracker| async move {
+        let update = Update::Announce(Event::Stopped, false);
+        let result = send(session, handle, tracker, update).await;
+        record(handle.id(), tracker, update, &result);
+    });
+    futures::future::join_all(stops).await;
+}
+
+/// Whether the stopped event can be sent now: right away, or once the retry
+/// interval after a failed request is over
+fn may_stop(state: &TrackerState, now: SystemTime) -> bool {
+    state.last_error.is_none() || state.next_update.is_none_or(|next| next <= now)
+}
+
+fn update_due(session: &Arc<Session>, handle: &Arc<ManagedTorrent>) {
+    let id = handle.id();
+    let trackers: Vec<Url> = torrents::tracker_tiers(handle).into_iter().flatten().collect();
+    let librqbit = librqbit_trackers(handle);
+    let is_live = matches!(handle.stats().state, TorrentStatsState::Live) && !handle.is_paused();
+    let complete = is_complete(handle);
+    let now = SystemTime::now();
+
+    let due: Vec<(Url, Update)> = with_states(id, |states| {
+        // Trackers that were removed and that we announced to are told we
+        // stopped before they are forgotten
+        let mut due: Vec<(Url, Update)> = states.iter_mut()
+            .filter(|(url, state)| {
+                !trackers.contains(url) && state.started && !state.updating && may_stop(state, now)
+            })
+            .map(|(url, state)| {
+                state.updating = true;
+                (url.clone(), Update::Announce(Event::Stopped, complete))
+            })
+            .collect();
+        states.retain(|url, state| trackers.contains(url) || state.updating);
+
+        for url in trackers {
+            let scraped = librqbit.contains(&url);
+            let state = states.entry(url.clone()).or_default();
+            if state.updating {
+                continue;
+            }
+            if !is_live {
+                if state.started && !scraped {
+                    if may_stop(state, now) {
+                        state.updating = true;
+                        due.push((url, Update::Announce(Event::Stopped, complete)));
+                    }
+                } else {
+                    state.next_update = None;
+                }
+                continue;
+            }
+            // Completion is announced as it happens, not at the next interval
+            let completed_now = !scraped && next_event(state, complete) == Event::Completed;
+            if state.next_update.is_some_and(|next| next > now) && !completed_now {
+                continue;
+            }
+            due.push((url, begin_update(state, scraped, complete)));
+        }
+        due
+    });
+
+    for (tracker, update) in due {
+        let session = session.clone();
+        let handle = handle.clone();
+        tokio::spawn(async move {
+            let result = send(&session, &handle, &tracker, update).await;
+            record(handle.id(), &tracker, update, &result);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{HttpResponse, HttpServer};
+
+    const INFO_HASH: [u8; 20] = [0xab; 20];
+
+    fn request(event: Event) -> Request {
+        Request { info_hash: Id20::new(INFO_HASH), port: 6881, uploaded: 1, downloaded: 2, left: 3, event }
+    }
+
+    fn query_param(target: &str, name: &str) -> Option<String> {
+        let url = Url::parse(&format!("http://localhost{}", target)).unwrap();
+        url.query_pairs().find(|(n, _)| n == name).map(|(_, v)| v.into_owned())
+    }
+
+    #[test]
+    fn scrape_urls_follow_bep48() {
+        // (announce URL, scrape URL)
+        let cases = [
+            ("http://t.example/announce", Some("http://t.example/scrape")),
+            ("http://t.example/x/announce", Some("http://t.example/x/scrape")),
+            ("http://t.example/announce.php", Some("http://t.example/scrape.php")),
+            ("http://t.example/announce?passkey=k", Some("http://t.example/scrape?passkey=k")),
+            ("http://t.example/a", None),
+            ("http://t.example/x/announce/y", None),
+            ("http://t.example/", None),
+        ];
+        for (tracker, scrape) in cases {
+            let url = scrape_url(&Url::parse(tracker).unwrap());
+            assert_eq!(url.as_ref().map(Url::as_str), scrape, "{}", tracker);
+        }
+    }
+
+    #[test]
+    fn events_follow_the_torrent() {
+        let state = |started, completed_sent| TrackerState { started, completed_sent, ..Default::default() };
+        // (started, completed sent, complete, event)
+        let cases = [
+            (false, false, false, Event::Started),
+            (false, false, true, Event::Started),
+            (true, false, false, Event::None),
+            (true, false, true, Event::Completed),
+            (true, true, true, Event::None),
+        ];
+        for (started, completed_sent, complete, event) in cases {
+            assert_eq!(next_event(&state(started, completed_sent), complete), event);
+        }
+    }
+
+    #[test]
+    fn records_event_outcomes() {
+        let url = Url::parse("http://t.example/announce").unwrap();
+        let ok = || Ok(Response { interval: Duration::from_secs(1800), seeders: Some(1), leechers: None, completed: None, peers: Some(Vec::new()) });
+        let id = usize::MAX;
+        let state = || tracker_states(id)[&url].clone();
+
+        record(id, &url, Update::Announce(Event::Started, false), &ok());
+        assert!(state().started && !state().completed_sent);
+        assert!(state().next_update.is_some());
+        record(id, &url, Update::Announce(Event::Completed, true), &Err(anyhow::anyhow!("down")));
+        assert!(!state().completed_sent);
+        assert_eq!(state().last_error.as_deref(), Some("down"));
+        record(id, &url, Update::Announce(Event::Completed, true), &ok());
+        assert!(state().completed_sent && state().last_error.is_none());
+        record(id, &url, Update::Announce(Event::Stopped, true), &ok());
+        assert!(!state().started && state().next_update.is_none());
+
+        // librqbit taking the tracker over doesn't send stopped
+        let mut taken = TrackerState { started: true, ..Default::default() };
+        assert!(matches!(begin_update(&mut taken, true, false), Update::Scrape));
+        assert!(!taken.started && taken.scraped);
+    }
+
+    #[tokio::test]
+    async fn http_announces_carry_events() {
+        let server = HttpServer::start(|_| {
+            HttpResponse::ok(b"d8:completei5e10:incompletei7e8:intervali1800e5:peers6:\x7f\x00\x00\x01\x1a\xe1e".to_vec())
+                .header("Content-Type", "text/plain")
+        }).await;
+        let tracker = Url::parse(&server.url("/announce?passkey=k")).unwrap();
+
+        let response = announce_http(&tracker, &request(Event::Started)).await.unwrap();
+        assert_eq!(response.seeders, Some(5));
+        assert_eq!(response.leechers, Some(7));
+        assert_eq!(response.interval, Duration::from_secs(1800));
+        assert_eq!(response.peers, Some(vec!["127.0.0.1:6881".parse().unwrap()]));
+        announce_http(&tracker, &request(Event::None)).await.unwrap();
+
+        let requests = server.requests();
+        let first = &requests[0].target;
+        assert!(first.starts_with("/announce?passkey=k&info_hash=%AB%AB"), "{}", first);
+        assert_eq!(query_param(first, "event").as_deref(), Some("started"));
+        assert_eq!(query_param(first, "left").as_deref(), Some("3"));
+        assert_eq!(query_param(&requests[1].target, "event"), None);
+    }
+
+    #[tokio::test]
+    async fn http_scrapes_read_the_torrents_counts() {
+        let mut body = b"d5:filesd20:".to_vec();
+        body.extend_from_slice(&INFO_HASH);
+        body.extend_from_slice(b"d8:completei3e10:downloadedi9e10:incompletei4eeee");
+        let server = HttpServer::start(move |request| {
+            if request.target.starts_with("/scrape") {
+                HttpResponse::ok(body.clone())
+            } else {
+                HttpResponse::status(404)
+            }
+        }).await;
+
+        let tracker = Url::parse(&server.url("/announce")).unwrap();
+        let response = scrape(&tracker, Id20::new(INFO_HASH)).await.unwrap();
+        assert_eq!((response.seeders, response.leechers, response.completed), (Some(3), Some(4), Some(9)));
+        assert!(response.peers.is_none());
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert!(requests[0].target.ends_with(&format!("info_hash={}", "%AB".repeat(20))), "{}", requests[0].target);
+
+        let err = scrape(&tracker, Id20::new([1; 20])).await.err().unwrap();
+        assert!(err.to_string().contains("doesn't know the torrent"), "{}", err);
+        let unsupported = Url::parse(&server.url("/tracker")).unwrap();
+        assert!(scrape(&unsupported, Id20::new(INFO_HASH)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn http_failures_are_errors() {
+        let server = HttpServer::start(|request| match request.target.as_str() {
+            t if t.starts_with("/fail") => HttpResponse::ok(b"d14:failure reason9:not founde".to_vec()),
+            t if t.starts_with("/junk") => HttpResponse::ok(b"<html>".to_vec()),
+            _ => HttpResponse::status(500),
+        }).await;
+        // (path, error)
+        let cases = [
+            ("/fail/announce", "Tracker error: not found"),
+            ("/junk/announce", "Invalid tracker response"),
+            ("/announce", "500"),
+        ];
+        for (path, error) in cases {
+            let tracker = Url::parse(&server.url(path)).unwrap();
+            let err = announce_http(&tracker, &request(Event::None)).await.err().unwrap();
+            assert!(format!("{:#}", err).contains(error), "{}: {:#}", path, err);
+        }
+    }
+
+    /// A UDP tracker answering connects, scrapes and announces, returning the
+    /// event of every announce
+    async fn udp_tracker() -> (Url, Arc<Mutex<Vec<u32>>>) {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("udp://{}", socket.local_addr().unwrap())).unwrap();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+                let packet = &buf[..len];
+                let action = u32::from_be_bytes(packet[8..12].try_into().unwrap());
+                let mut response = action.to_be_bytes().to_vec();
+                response.extend_from_slice(&packet[12..16]);
+                match action {
+                    UDP_CONNECT => response.extend_from_slice(&[7; 8]),
+                    UDP_SCRAPE => {
+                        assert_eq!(&packet[..8], &[7; 8]);
+                        for field in [3u32, 9, 4] {
+                            response.extend_from_slice(&field.to_be_bytes());
+                        }
+                    }
+                    UDP_ANNOUNCE => {
+                        recorded.lock().unwrap().push(u32::from_be_bytes(packet[80..84].try_into().unwrap()));
+                        for field in [1800u32, 4, 3] {
+                            response.extend_from_slice(&field.to_be_bytes());
+                        }
+                        response.extend_from_slice(&[127, 0, 0, 1, 0x1a, 0xe1]);
+                    }
+                    _ => continue,
+                }
+                socket.send_to(&response, from).await.unwrap();
+            }
+        });
+        (url, events)
+    }
+
+    #[tokio::test]
+    async fn udp_scrapes_and_announces() {
+        let (tracker, events) = udp_tracker().await;
+
+        let scraped = scrape(&tracker, Id20::new(INFO_HASH)).await.unwrap();
+        assert_eq!((scraped.seeders, scraped.leechers, scraped.completed), (Some(3), Some(4), Some(9)));
+
+        for event in [Event::Started, Event::None, Event::Completed, Event::Stopped] {
+            let announced = announce_udp(&tracker, &request(event)).await.unwrap();
+            assert_eq!((announced.seeders, announced.leechers), (Some(3), Some(4)));
+            assert_eq!(announced.peers, Some(vec!["127.0.0.1:6881".parse().unwrap()]));
+        }
+        assert_eq!(*events.lock().unwrap(), vec![2, 0, 1, 3]);
+    }
+}
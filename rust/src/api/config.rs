@@ -0,0 +1,179 @@
+use crate::api::trackers::parse_tracker;
+use crate::{app_data_dir, proxy};
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, RwLock};
+use url::Url;
+
+/// Saved in the app data directory, next to the ban list
+const CONFIG_FILE: &str = "session_config.json";
+
+/// Session-wide settings, applied to torrents added after they are set.
+/// They are saved and loaded again on the next launch.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct SessionConfig {
+    pub incomplete_dir: Option<String>, // Where unfinished downloads are written
+    pub complete_dir: Option<String>,   // Where finished downloads are moved, overriding output_dir
+    pub part_suffix: bool,              // Append ".part" to files until they are complete
+    pub include_file_progress: bool,    // Fill AppTorrentStatus.files in status events
+    pub default_trackers: Vec<String>,  // Added to every new torrent unless the add opts out
+    pub default_trackers_file: Option<String>, // Text file with more of them, one URL per line
+    pub dht_port: Option<u16>,          // UDP port of the DHT, random if None
+    pub dht_bootstrap_nodes: Vec<String>, // "host:port" nodes to join the DHT through, besides librqbit's
+    pub listen_port: Option<u16>,       // TCP port for incoming peers, None to accept none
+    pub listen_port_max: Option<u16>,   // With listen_port, pick a random free port up to this one
+    pub upnp: bool,                     // Map the listen port on the router with UPnP IGD
+    pub natpmp: bool,                   // Map the listen port on the router with NAT-PMP
+    pub outgoing_interface: Option<String>, // Torrents pause while it (e.g. "tun0") is gone; peer connections aren't bound to it
+    pub bind_address: Option<String>,   // Local IP for the DHT and tracker requests; torrents pause while it is gone
+    pub proxy: Option<ProxyConfig>,
+    pub encryption: EncryptionPolicy,   // Protocol encryption (MSE/PE) for peer connections
+    pub max_active_downloads: Option<u32>, // Batch-added torrents beyond this many downloads are queued
+}
+
+/// librqbit has no protocol encryption, so every connection is plain:
+/// Prefer falls back to that, and Require is refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EncryptionPolicy {
+    Disabled, // Plain connections only
+    #[default]
+    Prefer,   // Encrypt when the peer supports it, else connect plainly
+    Require,  // Encrypted connections only
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ProxyType {
+    #[default]
+    Socks5,
+    Http, // HTTP CONNECT, for tracker announces only
+}
+
+/// A proxy for peer connections and tracker announces. librqbit's own
+/// announces follow the peer setting, and UDP trackers and the DHT are
+/// never proxied.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct ProxyConfig {
+    pub proxy_type: ProxyType,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub proxy_peers: bool,    // Connect to peers through the proxy (SOCKS5 only)
+    pub proxy_trackers: bool, // Announce through the proxy, only with proxy_peers; UDP trackers are skipped
+}
+
+static CONFIG: LazyLock<RwLock<SessionConfig>> = LazyLock::new(|| RwLock::new(load_config()));
+
+fn load_config() -> SessionConfig {
+    std::fs::read_to_string(app_data_dir().join(CONFIG_FILE))
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+/// Replace the session config, creating the configured directories
+pub fn set_session_config(config: SessionConfig) -> anyhow::Result<()> {
+    for dir in [&config.incomplete_dir, &config.complete_dir].into_iter().flatten() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| anyhow::anyhow!("Cannot create {}: {}", dir, e))?;
+    }
+    for tracker in &config.default_trackers {
+        parse_tracker(tracker)?;
+    }
+    for node in &config.dht_bootstrap_nodes {
+        if node.rsplit_once(':').is_none_or(|(host, port)| host.is_empty() || port.parse::<u16>().is_err()) {
+            return Err(anyhow::anyhow!("Invalid DHT bootstrap node, expected host:port: {}", node));
+        }
+    }
+    match (config.listen_port, config.listen_port_max) {
+        (Some(0), _) => return Err(anyhow::anyhow!("Invalid listen port: 0")),
+        (None, Some(_)) => return Err(anyhow::anyhow!("listen_port_max needs a listen_port")),
+        (Some(min), Some(max)) if max < min => {
+            return Err(anyhow::anyhow!("Invalid listen port range: {}-{}", min, max));
+        }
+        _ => {}
+    }
+    if config.max_active_downloads == Some(0) {
+        return Err(anyhow::anyhow!("max_active_downloads must be at least 1"));
+    }
+    check_encryption(config.encryption)?;
+    if let Some(proxy) = &config.proxy {
+        proxy::validate(proxy)?;
+    }
+    if let Some(address) = &config.bind_address {
+        address.parse::<std::net::IpAddr>()
+            .map_err(|_| anyhow::anyhow!("Invalid bind address: {}", address))?;
+    }
+    let text = serde_json::to_string_pretty(&config)?;
+    std::fs::write(app_data_dir().join(CONFIG_FILE), text)
+        .map_err(|e| anyhow::anyhow!("Failed to save the session config: {}", e))?;
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = config;
+    Ok(())
+}
+
+/// Refuse encryption settings librqbit can't honour, as it only makes plain
+/// connections
+pub(crate) fn check_encryption(policy: EncryptionPolicy) -> anyhow::Result<()> {
+    if policy == EncryptionPolicy::Require {
+        return Err(anyhow::anyhow!("Required encryption isn't supported: librqbit has no protocol encryption (MSE/PE)"));
+    }
+    Ok(())
+}
+
+pub fn get_session_config() -> SessionConfig {
+    session_config()
+}
+
+pub(crate) fn session_config() -> SessionConfig {
+    CONFIG.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Run `future` with `config` in place of the current settings, which are
+/// put back afterwards. Nothing is saved.
+pub(crate) async fn with_config<F: std::future::Future>(config: SessionConfig, future: F) -> F::Output {
+    let current = std::mem::replace(&mut *CONFIG.write().unwrap_or_else(|e| e.into_inner()), config);
+    let output = future.await;
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = current;
+    output
+}
+
+/// The default trackers: those of the config, then those of its trackers
+/// file, without duplicates. The file is read on every call, '#' starts a
+/// comment line and invalid URLs in it are skipped.
+pub(crate) fn default_trackers() -> Vec<Url> {
+    let config = session_config();
+    let mut lines = config.default_trackers;
+    if let Some(path) = &config.default_trackers_file {
+        match std::fs::read_to_string(path) {
+            Ok(text) => lines.extend(text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(String::from)),
+            Err(e) => println!("CONFIG: default trackers file {} not read: {}", path, e),
+        }
+    }
+
+    let mut trackers: Vec<Url> = Vec::new();
+    for tracker in lines.iter().filter_map(|line| parse_tracker(line).ok()) {
+        if !trackers.contains(&tracker) {
+            trackers.push(tracker);
+        }
+    }
+    trackers
+}
+
+/// Restart the torrent session so settings librqbit only reads at startup
+/// (the ban list, the IP filter, the DHT, listen port, bind address and
+/// proxy settings) take effect.
+///
+/// Every torrent is added back under the same id, keeping its pause state
+/// and file selection, and its data is re-verified like on a recheck.
+/// Should the new settings keep the session from starting (a port in use,
+/// say), it is started with those it ran with before and the error is
+/// returned; the new settings stay saved for the next restart.
+pub async fn restart_session() -> anyhow::Result<()> {
+    crate::replace_session().await
+}
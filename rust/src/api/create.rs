@@ -0,0 +1,94 @@
+use crate::create::{Plan, Progress};
+use crate::frb_generated::StreamSink;
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often hashing progress is reported
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Default)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct CreateTorrentOptions {
+    pub name: Option<String>,      // Defaults to the file or folder name
+    pub piece_length: Option<u32>, // Power of two from 16 KiB to 16 MiB; picked from the size if None
+    pub trackers: Vec<String>,     // Each in a tier of its own, in order
+    pub web_seeds: Vec<String>,    // BEP 19 url-list
+    pub comment: Option<String>,
+    pub private: bool,             // Peers only come from the trackers: no DHT or peer exchange
+    pub source: Option<String>,    // Tag in the info dictionary, which changes the info hash
+}
+
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct CreatedTorrent {
+    pub torrent_bytes: Vec<u8>, // The .torrent file
+    pub magnet: String,
+    pub info_hash: String,
+    pub name: String,
+    pub total_size: u64,
+    pub piece_length: u32,
+    pub piece_count: usize,
+}
+
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct CreateTorrentProgress {
+    pub hashed_bytes: u64,
+    pub total_bytes: u64,
+    pub torrent: Option<CreatedTorrent>, // Set in the last update, once the torrent is created
+}
+
+/// Create a .torrent for a local file or folder.
+///
+/// A file makes a single-file torrent, a folder a multi-file torrent named
+/// after it, holding every file below it. Hashing progress is streamed, and
+/// the last update carries the torrent. Closing the stream cancels it.
+pub async fn create_torrent(
+    path: String,
+    options: CreateTorrentOptions,
+    progress_sink: StreamSink<CreateTorrentProgress>,
+) -> anyhow::Result<()> {
+    let path = PathBuf::from(path);
+    let plan = tokio::task::spawn_blocking(move || Plan::new(&path, options)).await??;
+    let total_bytes = plan.total_bytes();
+
+    let progress = Arc::new(Progress::default());
+    let mut build = tokio::task::spawn_blocking({
+        let progress = progress.clone();
+        move || plan.build(&progress)
+    });
+    loop {
+        tokio::select! {
+            result = &mut build => {
+                let created = result??;
+                let _ = progress_sink.add(CreateTorrentProgress {
+                    hashed_bytes: total_bytes,
+                    total_bytes,
+                    torrent: Some(CreatedTorrent {
+                        torrent_bytes: created.torrent_bytes,
+                        magnet: created.magnet,
+                        info_hash: created.info_hash,
+                        name: created.name,
+                        total_size: created.total_bytes,
+                        piece_length: created.piece_length,
+                        piece_count: created.piece_count,
+                    }),
+                });
+                return Ok(());
+            }
+            _ = tokio::time::sleep(PROGRESS_INTERVAL) => {
+                let update = CreateTorrentProgress {
+                    hashed_bytes: progress.hashed_bytes.load(Ordering::Relaxed),
+                    total_bytes,
+                    torrent: None,
+                };
+                if progress_sink.add(update).is_err() {
+                    // Stream closed by UI
+                    progress.cancelled.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
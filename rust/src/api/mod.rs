@@ -1 +1,6 @@
 pub mod simple;
+pub mod config;
+pub mod peers;
+pub mod trackers;
+pub mod network;
+pub mod create;
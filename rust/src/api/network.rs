@@ -0,0 +1,117 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::api::config::ProxyConfig;
+use crate::{get_session, interface, port_mapping, proxy};
+
+/// Set once a peer has connected to us, reset when the session restarts
+static INCOMING_SEEN: AtomicBool = AtomicBool::new(false);
+
+/// Whether peers can reach us
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct NetworkStatus {
+    pub listen_address: Option<String>,   // Where incoming peers are accepted, None if nowhere
+    pub port_mapping: Option<String>,     // "UPnP" or "NAT-PMP" if the listen port is mapped on the router
+    pub external_address: Option<String>, // Public IP, as reported by the router
+    pub external_port: Option<u16>,       // Port the router forwards to the listen port
+    pub mapping_error: Option<String>,    // Why mapping failed; it is retried every minute
+    pub incoming_seen: bool,              // A peer has connected to us since the session started
+    pub interface_up: bool,               // The configured interface is there; torrents are paused if not
+    pub bind_address: Option<String>,     // Address the DHT and tracker announces are sent from
+}
+
+/// State of the session's DHT
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct DhtStats {
+    pub enabled: bool,
+    pub node_id: String,           // Hex
+    pub listen_port: u16,
+    pub nodes: u32,                // Nodes in the routing table
+    pub buckets: Vec<DhtBucket>,
+    pub outstanding_queries: u32,  // Requests awaiting a response
+}
+
+/// A bucket of the routing table: nodes whose id shares the first
+/// `prefix_bits` bits with the bucket's range
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct DhtBucket {
+    pub prefix_bits: u8,
+    pub nodes: u32,
+}
+
+pub async fn dht_stats() -> anyhow::Result<DhtStats> {
+    let session = get_session().await?;
+    let Some(dht) = session.get_dht() else {
+        return Ok(DhtStats {
+            enabled: false,
+            node_id: String::new(),
+            listen_port: 0,
+            nodes: 0,
+            buckets: Vec::new(),
+            outstanding_queries: 0,
+        });
+    };
+
+    let stats = dht.stats();
+    let buckets = dht.with_routing_table(|table| {
+        table.iter_buckets()
+            .map(|bucket| DhtBucket {
+                prefix_bits: 160 - bucket.bits,
+                nodes: bucket.leaf.nodes.len() as u32,
+            })
+            .collect()
+    });
+    Ok(DhtStats {
+        enabled: true,
+        node_id: stats.id.as_string(),
+        listen_port: dht.listen_addr().port(),
+        nodes: stats.routing_table_size as u32,
+        buckets,
+        outstanding_queries: stats.outstanding_requests as u32,
+    })
+}
+
+pub async fn network_status() -> anyhow::Result<NetworkStatus> {
+    let session = get_session().await?;
+
+    if !INCOMING_SEEN.load(Ordering::Relaxed) {
+        // Every peer librqbit has seen, not only connected ones. The filter
+        // type isn't exported, but it deserializes.
+        let incoming = session.with_torrents(|torrents| {
+            torrents.filter_map(|(_, handle)| handle.live()).any(|live| {
+                let filter = serde_json::from_str(r#"{"state": "All"}"#).unwrap_or_default();
+                live.per_peer_stats_snapshot(filter).peers.values()
+                    .any(|stats| stats.counters.incoming_connections > 0)
+            })
+        });
+        if incoming {
+            INCOMING_SEEN.store(true, Ordering::Relaxed);
+        }
+    }
+
+    let (mapping, mapping_error) = port_mapping::status();
+    Ok(NetworkStatus {
+        listen_address: session.tcp_listen_port().map(|port| format!("0.0.0.0:{}", port)),
+        port_mapping: mapping.as_ref().map(|m| m.protocol().to_string()),
+        external_address: mapping.as_ref().and_then(|m| m.external_ip).map(|ip| ip.to_string()),
+        external_port: mapping.as_ref().map(|m| m.external_port),
+        mapping_error,
+        incoming_seen: INCOMING_SEEN.load(Ordering::Relaxed),
+        interface_up: interface::is_up(),
+        bind_address: interface::bind_ip().map(|ip| ip.to_string()),
+    })
+}
+
+pub(crate) fn reset_incoming_seen() {
+    INCOMING_SEEN.store(false, Ordering::Relaxed);
+}
+
+/// Check that `proxy` works: connect to it, authenticate and, if `target`
+/// ("host:port") is given, have it connect there. HTTP proxies can only be
+/// checked with a target. Returns the milliseconds taken; errors say which
+/// step failed.
+pub async fn test_proxy(proxy: ProxyConfig, target: Option<String>) -> anyhow::Result<u32> {
+    let elapsed = proxy::test(&proxy, target.as_deref()).await?;
+    Ok(elapsed.as_millis() as u32)
+}
@@ -0,0 +1,388 @@
+use crate::ip_filter::{self, FilterFormat, IpFilter};
+use crate::{app_data_dir, get_session, torrents};
+use librqbit::api::TorrentIdOrHash;
+use librqbit::ManagedTorrent;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::io::{BufWriter, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::Instant;
+
+/// Banned IPs, one per line, next to the session config
+const BANS_FILE: &str = "banned_peers.txt";
+
+/// The loaded IP filter, merged and in P2P format, with its stats
+const IP_FILTER_FILE: &str = "ip_filter.txt";
+const IP_FILTER_STATS_FILE: &str = "ip_filter.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerDirection {
+    Incoming, // The peer connected to us
+    Outgoing, // We connected to the peer
+}
+
+/// One connected peer of a torrent.
+///
+/// librqbit 8 only exposes per-peer download counters, so there is nothing to
+/// report about the peer's client, choke state, upload or its own progress.
+/// It has no protocol encryption either, so no connection is encrypted.
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct PeerInfo {
+    pub address: String,             // "ip:port"
+    pub direction: PeerDirection,
+    pub encrypted: bool,             // Connection uses protocol encryption (MSE/PE)
+    pub download_bps: u64,           // Bytes/s from the peer since the previous get_peers call
+    pub downloaded_bytes: u64,       // Total fetched from the peer
+    pub pieces_downloaded: u32,      // Verified pieces fetched from the peer
+    pub errors: u32,                 // Connection errors with the peer so far
+}
+
+/// (time, fetched bytes) of a peer at the previous call, to turn librqbit's
+/// byte counters into rates
+type Sample = (Instant, u64);
+
+/// Keyed by (torrent id, address)
+static SAMPLES: LazyLock<Mutex<HashMap<(usize, String), Sample>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// List the peers a torrent is currently connected to.
+/// Rates are averaged since the previous call for the same torrent, so the
+/// first call reports 0.
+pub async fn get_peers(id: usize) -> anyhow::Result<Vec<PeerInfo>> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    let Some(live) = handle.live() else {
+        // Paused, checking or fetching metadata: no connections
+        return Ok(Vec::new());
+    };
+
+    let snapshot = live.per_peer_stats_snapshot(Default::default());
+    let now = Instant::now();
+    let mut samples = SAMPLES.lock().unwrap_or_else(|e| e.into_inner());
+    samples.retain(|(torrent, address), _| *torrent != id || snapshot.peers.contains_key(address));
+
+    let mut peers: Vec<PeerInfo> = snapshot.peers.into_iter()
+        .map(|(address, stats)| {
+            let counters = stats.counters;
+            let fetched = counters.fetched_bytes;
+            let previous = samples.insert((id, address.clone()), (now, fetched));
+            let download_bps = match previous {
+                Some((at, bytes)) => {
+                    let elapsed = now.duration_since(at).as_secs_f64();
+                    if elapsed > 0.0 {
+                        (fetched.saturating_sub(bytes) as f64 / elapsed) as u64
+                    } else {
+                        0
+                    }
+                }
+                None => 0,
+            };
+
+            PeerInfo {
+                address,
+                direction: if counters.incoming_connections > 0 && counters.connections == 0 {
+                    PeerDirection::Incoming
+                } else {
+                    PeerDirection::Outgoing
+                },
+                encrypted: false,
+                download_bps,
+                downloaded_bytes: fetched,
+                pieces_downloaded: counters.downloaded_and_checked_pieces,
+                errors: counters.errors,
+            }
+        })
+        .collect();
+
+    // Fastest first, the order a "why is this slow" view wants
+    peers.sort_by(|a, b| b.download_bps.cmp(&a.download_bps).then(b.downloaded_bytes.cmp(&a.downloaded_bytes)));
+    Ok(peers)
+}
+
+/// Connect a torrent to a specific peer, e.g. a private seedbox.
+///
+/// `address` is "ip:port" or "host:port". librqbit only takes extra peers
+/// when a torrent is added, so the torrent is re-added with the peer, which
+/// re-verifies its data like recheck_torrent. The peer is connected to
+/// again at every later add of the torrent.
+pub async fn add_peer(id: usize, address: String) -> anyhow::Result<()> {
+    let peer = tokio::net::lookup_host(&address).await
+        .map_err(|e| anyhow::anyhow!("Invalid peer address {}: {}", address, e))?
+        .next()
+        .ok_or(anyhow::anyhow!("Peer address did not resolve: {}", address))?;
+    if torrents::get(id).is_none() {
+        return Err(anyhow::anyhow!("Torrent was not added for download: {}", id));
+    }
+    
+    if is_blocked(peer.ip()) {
+        return Err(anyhow::anyhow!("Peer is banned or blocked by the IP filter: {}", peer));
+    }
+    
+    if torrents::add_peer(id, peer) {
+        let session = get_session().await?;
+        torrents::readd(&session, id).await?;
+    }
+    Ok(())
+}
+
+static BANS: LazyLock<Mutex<BTreeSet<IpAddr>>> = LazyLock::new(|| Mutex::new(load_bans()));
+
+fn load_bans() -> BTreeSet<IpAddr> {
+    std::fs::read_to_string(app_data_dir().join(BANS_FILE))
+        .map(|text| text.lines().filter_map(|line| line.trim().parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+fn save_bans(bans: &BTreeSet<IpAddr>) -> anyhow::Result<()> {
+    let text: String = bans.iter().map(|ip| format!("{}\n", ip)).collect();
+    std::fs::write(app_data_dir().join(BANS_FILE), text)
+        .map_err(|e| anyhow::anyhow!("Failed to save the ban list: {}", e))
+}
+
+/// Accepts "ip" as well as "ip:port" as shown by get_peers
+fn parse_ip(address: &str) -> anyhow::Result<IpAddr> {
+    address.parse::<IpAddr>()
+        .or_else(|_| address.parse::<SocketAddr>().map(|a| a.ip()))
+        .map_err(|_| anyhow::anyhow!("Invalid IP address: {}", address))
+}
+
+fn update_bans(address: &str, f: impl FnOnce(&mut BTreeSet<IpAddr>, IpAddr)) -> anyhow::Result<()> {
+    let ip = parse_ip(address)?;
+    let mut bans = BANS.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut bans, ip);
+    save_bans(&bans)
+}
+
+/// What banning an IP did to the running session
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct PeerBan {
+    pub disconnected: u32, // Torrents whose connections to the IP were dropped
+    pub active: bool,      // Refused by the running session; if not, the IP can connect again until restart_session
+}
+
+/// Ban an IP from every torrent, for incoming and outgoing connections.
+///
+/// Bans are saved with the session config. Connections to the IP are dropped
+/// right away, but librqbit reads its blocklist when the session starts, so
+/// the peer is only refused from the next `restart_session` or app launch and
+/// may be connected to again until then, which the result tells.
+pub async fn ban_peer(address: String) -> anyhow::Result<PeerBan> {
+    let ip = parse_ip(&address)?;
+    update_bans(&address, |bans, ip| {
+        bans.insert(ip);
+    })?;
+    let disconnected = disconnect_blocked().await?;
+    let session = get_session().await?;
+    Ok(PeerBan {
+        disconnected,
+        active: session.blocklist.is_blocked(ip),
+    })
+}
+
+/// Lift a ban, from the next session start like ban_peer
+pub fn unban_peer(address: String) -> anyhow::Result<()> {
+    update_bans(&address, |bans, ip| {
+        bans.remove(&ip);
+    })
+}
+
+pub fn list_bans() -> Vec<String> {
+    banned_ips().iter().map(|ip| ip.to_string()).collect()
+}
+
+pub(crate) fn banned_ips() -> Vec<IpAddr> {
+    BANS.lock().unwrap_or_else(|e| e.into_inner()).iter().copied().collect()
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct IpFilterStats {
+    pub source: Option<String>,  // File the filter was loaded from, None if no filter
+    pub format: Option<String>,  // "eMule" or "P2P"
+    pub entries: usize,          // Blocking entries read, before merging overlaps
+    pub allowed_entries: usize,  // eMule entries with an allowing access level
+    pub invalid_lines: usize,    // Lines that could not be parsed
+    pub ipv4_ranges: usize,      // Ranges after merging
+    pub ipv6_ranges: usize,
+    pub ipv4_addresses: u64,     // IPv4 addresses blocked
+    #[serde(skip)]
+    pub active: bool,            // In effect for the running session
+}
+
+struct LoadedFilter {
+    filter: IpFilter,
+    stats: IpFilterStats,
+}
+
+static IP_FILTER: LazyLock<RwLock<Option<LoadedFilter>>> =
+    LazyLock::new(|| RwLock::new(load_saved_filter()));
+
+/// Set once the current filter has been written to a session's blocklist
+static IP_FILTER_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+fn load_saved_filter() -> Option<LoadedFilter> {
+    let stats_text = std::fs::read_to_string(app_data_dir().join(IP_FILTER_STATS_FILE)).ok()?;
+    let stats = serde_json::from_str(&stats_text).ok()?;
+    let parsed = ip_filter::load(&app_data_dir().join(IP_FILTER_FILE)).ok()?;
+    Some(LoadedFilter { filter: parsed.filter, stats })
+}
+
+fn save_filter(loaded: &LoadedFilter) -> anyhow::Result<()> {
+    let file = std::fs::File::create(app_data_dir().join(IP_FILTER_FILE))?;
+    let mut out = BufWriter::new(file);
+    for (first, last) in loaded.filter.ranges() {
+        writeln!(out, "filter:{}-{}", first, last)?;
+    }
+    out.flush()?;
+    std::fs::write(app_data_dir().join(IP_FILTER_STATS_FILE), serde_json::to_string_pretty(&loaded.stats)?)?;
+    Ok(())
+}
+
+/// Load an IP filter in the eMule (ipfilter.dat) or P2P format, plain or
+/// gzip-compressed, replacing the current one.
+///
+/// The filter is saved and, like bans, handed to librqbit when the session
+/// starts. Connections to blocked peers are dropped right away, but new ones
+/// are only refused from the next `restart_session` or app launch.
+pub async fn load_ip_filter(path: String) -> anyhow::Result<IpFilterStats> {
+    let parsed = ip_filter::load(Path::new(&path))?;
+    if parsed.filter.is_empty() {
+        return Err(anyhow::anyhow!("No IP ranges found in {}", path));
+    }
+    
+    let loaded = LoadedFilter {
+        stats: IpFilterStats {
+            source: Some(path),
+            format: parsed.format.map(|f| match f {
+                FilterFormat::Emule => "eMule".to_string(),
+                FilterFormat::P2p => "P2P".to_string(),
+            }),
+            entries: parsed.entries,
+            allowed_entries: parsed.allowed_entries,
+            invalid_lines: parsed.invalid_lines,
+            ipv4_ranges: parsed.filter.ipv4_range_count(),
+            ipv6_ranges: parsed.filter.ipv6_range_count(),
+            ipv4_addresses: parsed.filter.ipv4_address_count(),
+            active: false,
+        },
+        filter: parsed.filter,
+    };
+    save_filter(&loaded).map_err(|e| anyhow::anyhow!("Failed to save the IP filter: {}", e))?;
+    
+    let stats = loaded.stats.clone();
+    *IP_FILTER.write().unwrap_or_else(|e| e.into_inner()) = Some(loaded);
+    IP_FILTER_ACTIVE.store(false, Ordering::Relaxed);
+    disconnect_blocked().await?;
+    Ok(stats)
+}
+
+/// Remove the IP filter, from the next session start like load_ip_filter
+pub fn clear_ip_filter() -> anyhow::Result<()> {
+    for file in [IP_FILTER_FILE, IP_FILTER_STATS_FILE] {
+        match std::fs::remove_file(app_data_dir().join(file)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(anyhow::anyhow!("Failed to remove the IP filter: {}", e));
+            }
+            _ => {}
+        }
+    }
+    *IP_FILTER.write().unwrap_or_else(|e| e.into_inner()) = None;
+    IP_FILTER_ACTIVE.store(false, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn ip_filter_stats() -> IpFilterStats {
+    match IP_FILTER.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        Some(loaded) => IpFilterStats {
+            active: IP_FILTER_ACTIVE.load(Ordering::Relaxed),
+            ..loaded.stats.clone()
+        },
+        None => IpFilterStats::default(),
+    }
+}
+
+/// Ranges of the IP filter, for the session blocklist, which marks the
+/// filter as active
+pub(crate) fn take_ip_filter_ranges() -> Vec<(IpAddr, IpAddr)> {
+    let filter = IP_FILTER.read().unwrap_or_else(|e| e.into_inner());
+    IP_FILTER_ACTIVE.store(filter.is_some(), Ordering::Relaxed);
+    filter.as_ref().map(|f| f.filter.ranges().collect()).unwrap_or_default()
+}
+
+/// Drop the connections of every torrent to peers that are now blocked.
+/// librqbit can't close a single connection, so torrents connected to one
+/// are paused and resumed, which closes all of theirs.
+/// Drop the connections of every torrent to blocked IPs by pausing and
+/// resuming it. Returns how many torrents had any.
+async fn disconnect_blocked() -> anyhow::Result<u32> {
+    let session = get_session().await?;
+    let handles: Vec<Arc<ManagedTorrent>> = session.with_torrents(|torrents| {
+        torrents.map(|(_, handle)| handle.clone()).collect()
+    });
+    let mut disconnected = 0;
+    for handle in handles {
+        let connected = handle.live().is_some_and(|live| {
+            live.per_peer_stats_snapshot(Default::default()).peers.keys()
+                .filter_map(|address| address.parse::<SocketAddr>().ok())
+                .any(|address| is_blocked(address.ip()))
+        });
+        if connected {
+            session.pause(&handle).await?;
+            session.unpause(&handle).await?;
+            disconnected += 1;
+        }
+    }
+    Ok(disconnected)
+}
+
+/// Whether connections to `ip` are refused, by a ban or the IP filter
+pub(crate) fn is_blocked(ip: IpAddr) -> bool {
+    BANS.lock().unwrap_or_else(|e| e.into_inner()).contains(&ip)
+        || IP_FILTER.read().unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .is_some_and(|f| f.filter.is_blocked(ip))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librqbit::{AddTorrent, AddTorrentOptions};
+    use crate::storage::StorageLocation;
+    use crate::test_util::{content, create_torrent, wait_for, with_session, Seeder};
+
+    #[test]
+    fn added_peers_are_connected_to_right_away() {
+        with_session(async {
+            let shared = tempfile::tempdir().unwrap();
+            let data = content(100_000);
+            std::fs::write(shared.path().join("movie.bin"), &data).unwrap();
+            let created = create_torrent(&shared.path().join("movie.bin"), Some(16 * 1024));
+            let seeder = Seeder::start(&created.torrent_bytes, shared.path()).await;
+
+            let downloads = tempfile::tempdir().unwrap();
+            let location = StorageLocation::new(downloads.path(), false);
+            let id = torrents::allocate_id();
+            torrents::register(id, location.clone(), None);
+            let session = get_session().await.unwrap();
+            session
+                .add_torrent(AddTorrent::from_bytes(created.torrent_bytes), Some(AddTorrentOptions {
+                    preferred_id: Some(id),
+                    ..torrents::download_options(&location)
+                }))
+                .await.unwrap();
+
+            add_peer(id, seeder.addr.to_string()).await.unwrap();
+            wait_for("the download to finish", || {
+                session.get(TorrentIdOrHash::Id(id)).is_some_and(|h| h.stats().finished)
+            }).await;
+            assert_eq!(std::fs::read(downloads.path().join("movie.bin")).unwrap(), data);
+            session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+}
@@ -1,6 +1,24 @@
 use crate::frb_generated::StreamSink;
+use crate::announce;
+use crate::api::config;
+use crate::completion;
 use crate::get_session;
-use librqbit::{AddTorrent, AddTorrentOptions};
+use crate::priorities;
+use crate::queue;
+use crate::streaming::{self, ReadThrough};
+use crate::meta_v2::{self, V2Metadata};
+use crate::storage::sanitize::sanitize_path;
+use crate::storage::{dedupe, file_layout, StorageLocation};
+use crate::torrent_url;
+use crate::torrents;
+use crate::web_seeds;
+use librqbit::api::TorrentIdOrHash;
+use librqbit::{torrent_from_bytes, AddTorrent, AddTorrentOptions, AddTorrentResponse, ByteBufOwned, ManagedTorrent, TorrentMetaV1Info, TorrentStatsState};
+use librqbit::dht::Id20;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Initialize the Rust library (called once at app startup)
@@ -12,16 +30,35 @@ pub fn init_app() {
 #[derive(Debug, Clone)]
 #[flutter_rust_bridge::frb(serialize)]
 pub struct AppTorrentStatus {
+    pub id: usize,             // Session torrent id, used by the per-torrent calls
     pub total_pieces: u32,
     pub completed_pieces: u32,
     pub peers: u32,
-    pub speed_mbps: f64,
+    pub speed_mbps: f64,          // From peers in MiB/s
+    pub web_seed_speed_mbps: f64, // From web seeds in MiB/s, not included in speed_mbps
     pub downloading: bool,
     pub is_fetching_metadata: bool,
+    pub is_checking: bool,     // Verifying existing data; downloaded_bytes counts checked bytes
+    pub is_moving: bool,       // Data is being moved by move_storage
+    pub is_sequential: bool,   // Pieces are downloaded in order (set_sequential_download)
+    pub is_network_paused: bool, // Paused until the configured network interface is back
+    pub is_queued: bool,       // Added paused by add_torrents_batch, starts when a download slot is free
     pub status_message: String,
     pub error: Option<String>,
     pub total_bytes: u64,      // Total size in bytes
     pub downloaded_bytes: u64, // Downloaded so far in bytes
+    pub web_seed_downloaded_bytes: u64, // Fetched from web seeds, counted in downloaded_bytes once verified
+    pub files: Option<Vec<FileProgress>>, // Per-file progress, if enabled in the session config
+}
+
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct FileProgress {
+    pub index: usize, // Index in the torrent, as in FileInfo
+    pub path: String,
+    pub size: u64,
+    pub downloaded: u64, // Bytes of verified pieces
+    pub complete: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -32,24 +69,60 @@ pub struct TorrentInfo {
     pub piece_count: usize,
     pub piece_length: usize,
     pub files: Vec<FileInfo>,
-    pub info_hash: String,
+    pub info_hash: String,              // v1 info hash; for v2-only torrents the truncated v2 one peers use
+    pub info_hash_v2: Option<String>,   // SHA-256 info hash of v2 and hybrid torrents
+    pub meta_version: MetaVersion,
     pub announce: String,
+    pub web_seeds: Vec<String>, // BEP 19 url-list
+    pub file_tree: Vec<V2FileInfo>, // The v2 file tree, flattened in path order; empty for v1 torrents
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MetaVersion {
+    #[default]
+    V1,
+    V2,     // v2 only; can be inspected but not downloaded yet
+    Hybrid, // Both; downloaded using the v1 metadata
+}
+
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct V2FileInfo {
+    pub path: String, // As in the torrent, relative to its folder, '/'-separated
+    pub size: u64,
+    pub merkle_root: Option<String>, // Hex SHA-256 "pieces root", None for empty files
 }
 
 #[derive(Debug, Clone)]
 #[flutter_rust_bridge::frb(serialize)]
 pub struct FileInfo {
-    pub path: String,
+    pub index: usize, // Index in the torrent, used by the per-file calls
+    pub path: String, // Sanitised path the file is written to, relative to the output dir
     pub size: usize,
+    pub original_path: Option<String>, // Name from the torrent, set if it had to be changed
+    pub priority: FilePriority,
+    pub downloaded_bytes: u64, // Always 0 before the torrent is added
+    pub merkle_root: Option<String>, // v2 "pieces root" of the file, for v2 and hybrid torrents
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilePriority {
+    Skip,   // Not downloaded
+    Low,    // Downloaded after every Normal and High file
+    #[default]
+    Normal,
+    High,   // Downloaded first
 }
 
 #[derive(Debug, Clone)]
 #[flutter_rust_bridge::frb(serialize)]
 pub struct MagnetInfo {
     pub url: String,
-    pub info_hash: String,
+    pub info_hash: String,            // v1 info hash, or for v2-only links the truncated v2 one
+    pub info_hash_v2: Option<String>, // From an "xt=urn:btmh:" topic, if it is SHA-256
     pub name: Option<String>,
     pub trackers: Vec<String>,
+    pub web_seeds: Vec<String>, // ws= parameters
 }
 
 pub fn parse_magnet(uri: String) -> anyhow::Result<MagnetInfo> {
@@ -60,102 +133,449 @@ pub fn parse_magnet(uri: String) -> anyhow::Result<MagnetInfo> {
     }
     
     let mut info_hash = String::new();
+    let mut info_hash_v2 = None;
+    let mut btmh_error = None;
     let mut name = None;
     let mut trackers = Vec::new();
+    let mut web_seeds = Vec::new();
     
     for (key, value) in url.query_pairs() {
         match key.as_ref() {
-            "xt" => {
-                if value.starts_with("urn:btih:") {
-                   info_hash = value.strip_prefix("urn:btih:").unwrap().to_string();
+            "xt" if value.starts_with("urn:btih:") => {
+                info_hash = value.strip_prefix("urn:btih:").unwrap().to_string();
+            },
+            "xt" if value.starts_with("urn:btmh:") => {
+                match meta_v2::parse_btmh(value.strip_prefix("urn:btmh:").unwrap()) {
+                    Ok(hash) => info_hash_v2 = Some(hash),
+                    // Only an error if there is no v1 info hash to use instead
+                    Err(e) => btmh_error = Some(e),
                 }
             },
             "dn" => name = Some(value.to_string()),
             "tr" => trackers.push(value.to_string()),
+            "ws" => web_seeds.push(value.to_string()),
             _ => {}
         }
     }
     
     if info_hash.is_empty() {
-        return Err(anyhow::anyhow!("No info hash found"));
+        match (&info_hash_v2, btmh_error) {
+            (Some(hash), _) => info_hash = hex::encode(&hash[..20]),
+            (None, Some(e)) => return Err(e),
+            (None, None) => return Err(anyhow::anyhow!("No info hash found")),
+        }
     }
     
     Ok(MagnetInfo {
         url: uri,
         info_hash,
+        info_hash_v2: info_hash_v2.map(hex::encode),
         name,
-        trackers
+        trackers,
+        web_seeds,
+    })
+}
+
+
+/// The non-padding files of a torrent. Priorities, progress and merkle roots
+/// are indexed like the torrent's files; missing entries mean Normal, nothing
+/// downloaded and no root.
+fn file_infos(
+    info: &TorrentMetaV1Info<ByteBufOwned>,
+    priorities: &[FilePriority],
+    progress: &[u64],
+    merkle_roots: &[Option<[u8; 32]>],
+) -> anyhow::Result<Vec<FileInfo>> {
+    Ok(file_layout(info)?
+        .into_iter()
+        .enumerate()
+        .filter(|(_, entry)| !entry.padding)
+        .map(|(index, entry)| FileInfo {
+            index,
+            path: entry.path.path.to_string_lossy().to_string(),
+            size: entry.length as usize,
+            original_path: entry.path.renamed.then_some(entry.path.original),
+            priority: priorities.get(index).copied().unwrap_or_default(),
+            downloaded_bytes: progress.get(index).copied().unwrap_or(0),
+            merkle_root: merkle_roots.get(index).copied().flatten().map(hex::encode),
+        })
+        .collect())
+}
+
+/// Merkle roots of a torrent's files, by index; empty for v1 torrents
+fn merkle_roots(info: &TorrentMetaV1Info<ByteBufOwned>, torrent_bytes: &[u8]) -> Vec<Option<[u8; 32]>> {
+    match meta_v2::parse(torrent_bytes) {
+        Ok(Some(v2)) => v2.merkle_roots(info).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+#[derive(Deserialize)]
+struct TorrentTrackers {
+    announce: Option<ByteBufOwned>,
+    #[serde(rename = "announce-list", default)]
+    announce_list: Vec<Vec<ByteBufOwned>>,
+}
+
+/// The trackers of a .torrent file, tier by tier. Read on their own, as
+/// librqbit can't parse v2-only torrents.
+fn torrent_trackers(torrent_bytes: &[u8]) -> Vec<String> {
+    let Ok(torrent) = librqbit_bencode::from_bytes::<TorrentTrackers>(torrent_bytes) else {
+        return Vec::new();
+    };
+    let urls = if torrent.announce_list.iter().flatten().next().is_some() {
+        torrent.announce_list.into_iter().flatten().collect()
+    } else {
+        torrent.announce.into_iter().collect::<Vec<_>>()
+    };
+    urls.iter()
+        .map(|url| String::from_utf8_lossy(url.as_ref()).to_string())
+        .collect()
+}
+
+/// The session's default trackers a torrent with trackers `existing`
+/// doesn't have yet, to pass as `AddTorrentOptions::trackers`
+fn default_trackers_for(existing: &[String], use_default_trackers: bool) -> Option<Vec<String>> {
+    if !use_default_trackers {
+        return None;
+    }
+    let existing: Vec<url::Url> = existing.iter().filter_map(|t| url::Url::parse(t).ok()).collect();
+    let trackers: Vec<String> = config::default_trackers().into_iter()
+        .filter(|t| !existing.contains(t))
+        .map(String::from)
+        .collect();
+    (!trackers.is_empty()).then_some(trackers)
+}
+
+/// Build a TorrentInfo from parsed metadata.
+/// File paths are the sanitised paths the data will actually be written to.
+fn torrent_info_from_metadata(
+    info: &TorrentMetaV1Info<ByteBufOwned>,
+    info_hash: Id20,
+    torrent_bytes: &[u8],
+) -> anyhow::Result<TorrentInfo> {
+    let v2 = meta_v2::parse(torrent_bytes).ok().flatten();
+    let roots = match &v2 {
+        Some(v2) => v2.merkle_roots(info)?,
+        None => Vec::new(),
+    };
+    let files = file_infos(info, &[], &[], &roots)?;
+
+    Ok(TorrentInfo {
+        name: info.name.as_ref()
+            .map(|n| String::from_utf8_lossy(n.as_ref()).to_string())
+            .unwrap_or_default(),
+        total_size: files.iter().map(|f| f.size).sum(),
+        piece_count: info.pieces.as_ref().len() / 20,
+        piece_length: info.piece_length as usize,
+        files,
+        info_hash: info_hash.as_string(),
+        info_hash_v2: v2.as_ref().map(|v2| v2.info_hash_hex()),
+        meta_version: if v2.is_some() { MetaVersion::Hybrid } else { MetaVersion::V1 },
+        announce: torrent_trackers(torrent_bytes).into_iter().next().unwrap_or_default(),
+        web_seeds: web_seeds::torrent_web_seeds(torrent_bytes).into_iter().map(String::from).collect(),
+        file_tree: v2.as_ref().map(file_tree).unwrap_or_default(),
     })
 }
 
+fn file_tree(v2: &V2Metadata) -> Vec<V2FileInfo> {
+    v2.files.iter()
+        .map(|file| V2FileInfo {
+            path: file.path.join("/"),
+            size: file.length,
+            merkle_root: file.pieces_root.map(hex::encode),
+        })
+        .collect()
+}
+
+/// Build a TorrentInfo for a v2-only torrent, which librqbit can't read.
+/// File paths are sanitised and deduplicated as they would be for v1 torrents.
+fn torrent_info_from_v2(v2: &V2Metadata, torrent_bytes: &[u8]) -> anyhow::Result<TorrentInfo> {
+    let single_file = v2.files.len() == 1 && v2.files[0].path.len() == 1;
+    let mut seen = HashSet::new();
+    let files = v2.files.iter()
+        .enumerate()
+        .map(|(index, file)| {
+            let components: Vec<&[u8]> = if single_file {
+                vec![v2.name.as_bytes()]
+            } else {
+                std::iter::once(v2.name.as_bytes()).chain(file.path.iter().map(|p| p.as_bytes())).collect()
+            };
+            let mut path = sanitize_path(components)?;
+            dedupe(&mut path, &mut seen);
+            Ok(FileInfo {
+                index,
+                path: path.path.to_string_lossy().to_string(),
+                size: file.length as usize,
+                original_path: path.renamed.then_some(path.original),
+                priority: FilePriority::Normal,
+                downloaded_bytes: 0,
+                merkle_root: file.pieces_root.map(hex::encode),
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(TorrentInfo {
+        name: v2.name.clone(),
+        total_size: files.iter().map(|f| f.size).sum(),
+        // v2 pieces never span files
+        piece_count: v2.files.iter().map(|f| f.length.div_ceil(v2.piece_length) as usize).sum(),
+        piece_length: v2.piece_length as usize,
+        files,
+        info_hash: v2.truncated_info_hash(),
+        info_hash_v2: Some(v2.info_hash_hex()),
+        meta_version: MetaVersion::V2,
+        announce: torrent_trackers(torrent_bytes).into_iter().next().unwrap_or_default(),
+        web_seeds: web_seeds::torrent_web_seeds(torrent_bytes).into_iter().map(String::from).collect(),
+        file_tree: file_tree(v2),
+    })
+}
+
+/// Refuse v2-only torrents, which librqbit can't download
+fn check_downloadable(torrent_bytes: &[u8]) -> anyhow::Result<()> {
+    match meta_v2::parse(torrent_bytes) {
+        Ok(Some(v2)) if !v2.hybrid => Err(anyhow::anyhow!("v2-only torrents can't be downloaded yet, only hybrid ones")),
+        _ => Ok(()),
+    }
+}
+
+/// Refuse magnet links with only a v2 info hash; librqbit needs the v1 one
+fn check_magnet_downloadable(uri: &str) -> anyhow::Result<()> {
+    let has_v1 = url::Url::parse(uri)
+        .is_ok_and(|url| url.query_pairs().any(|(key, value)| key == "xt" && value.starts_with("urn:btih:")));
+    if !has_v1 && parse_magnet(uri.to_string())?.info_hash_v2.is_some() {
+        return Err(anyhow::anyhow!("v2-only magnet links can't be downloaded yet, only hybrid ones"));
+    }
+    Ok(())
+}
+
+/// The magnet link for librqbit, which refuses the whole link over a
+/// "urn:btmh:" topic that isn't a SHA-256 hash: such topics are dropped
+fn librqbit_magnet(uri: &str) -> String {
+    let Ok(mut url) = url::Url::parse(uri) else {
+        return uri.to_string();
+    };
+    let is_supported = |key: &str, value: &str| {
+        key != "xt" || value.strip_prefix("urn:btmh:").is_none_or(|topic| meta_v2::parse_btmh(topic).is_ok())
+    };
+    if url.query_pairs().all(|(key, value)| is_supported(&key, &value)) {
+        return uri.to_string();
+    }
+    let pairs: Vec<(String, String)> = url.query_pairs()
+        .filter(|(key, value)| is_supported(key, value))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url.to_string()
+}
 
 pub async fn get_torrent_info_file(path: String) -> anyhow::Result<TorrentInfo> {
     let session = get_session().await?;
     let bytes = std::fs::read(&path)?;
+    if let Ok(Some(v2)) = meta_v2::parse(&bytes) {
+        if !v2.hybrid {
+            return torrent_info_from_v2(&v2, &bytes);
+        }
+    }
     
-    // Add torrent paused to inspect metadata
+    // list_only parses the metadata without creating any files
     let add_result = session.add_torrent(
         AddTorrent::from_bytes(bytes),
         Some(AddTorrentOptions {
-            paused: true,
-            overwrite: true,
+            list_only: true,
             ..Default::default()
         })
     ).await?;
     
-    let handle = add_result.into_handle()
-        .ok_or(anyhow::anyhow!("Failed to create torrent handle"))?;
-        
-    // For a local .torrent file, metadata should be available immediately
+    match add_result {
+        AddTorrentResponse::ListOnly(listed) => torrent_info_from_metadata(&listed.info, listed.info_hash, &listed.torrent_bytes),
+        _ => Err(anyhow::anyhow!("Failed to read torrent metadata")),
+    }
+}
+
+/// Build the status reported to the UI for one torrent
+fn torrent_status(handle: &ManagedTorrent) -> AppTorrentStatus {
     let stats = handle.stats();
     
-    // Build info structure (simplified for v8)
-    let result = TorrentInfo {
-        name: "Local Torrent".to_string(), // TODO: extract name from bytes if needed, or use filename
-        total_size: stats.total_bytes as usize,
-        piece_count: 0,
-        piece_length: 0,
-        files: vec![FileInfo {
-            path: "content".to_string(),
-            size: stats.total_bytes as usize,
-        }],
-        info_hash: format!("{:?}", handle.id()),
-        announce: String::new(),
+    let has_metadata = stats.total_bytes > 0;
+    let is_fetching_metadata = !has_metadata;
+    
+    // While initializing, librqbit reports hashed bytes as progress
+    let is_checking = has_metadata && matches!(stats.state, TorrentStatsState::Initializing);
+    
+    // Calculate total pieces from total bytes / piece length (estimate)
+    let total_pieces = if stats.total_bytes > 0 {
+        ((stats.total_bytes as f64 / 16384.0).ceil()) as u32 // Assume 16KB pieces
+    } else {
+        0
+    };
+    
+    // v8: Calculate completed pieces from progress_bytes
+    let completed = if stats.total_bytes > 0 && !is_checking {
+        ((stats.progress_bytes as f64 / 16384.0).floor()) as u32
+    } else {
+        0
     };
     
-    // Clean up
-    let _ = session.delete(librqbit::api::TorrentIdOrHash::Id(handle.id()), false).await;
+    // v8 API: peer count from snapshot if live stats available
+    let peer_count = if let Some(live) = &stats.live {
+        live.snapshot.peer_stats.live as u32
+    } else {
+        0
+    };
     
-    Ok(result)
+    let entry = torrents::get(handle.id());
+    let move_progress = entry.as_ref().and_then(|e| e.move_progress);
+    
+    // Check if finished
+    let is_finished = !is_checking && stats.progress_bytes >= stats.total_bytes && stats.total_bytes > 0;
+    let is_paused = handle.is_paused();
+    let is_network_paused = is_paused && entry.as_ref().is_some_and(|e| e.network_paused);
+    let is_queued = is_paused && queue::is_queued(handle.id());
+    let (web_seed_rate, web_seed_downloaded_bytes) = web_seeds::download_rate(handle.id());
+
+    let status_message = if is_fetching_metadata {
+        format!("Fetching Metadata... ({} peers)", peer_count)
+    } else if is_checking {
+        format!("Checking files... ({}%)", stats.progress_bytes * 100 / stats.total_bytes)
+    } else if let Some((moved, total)) = move_progress {
+        format!("Moving files... ({}%)", (moved * 100).checked_div(total).unwrap_or(0))
+    } else if is_finished {
+        "Complete".to_string()
+    } else if is_network_paused {
+        "Paused: network interface down".to_string()
+    } else if is_queued {
+        "Queued".to_string()
+    } else if is_paused {
+        "Paused".to_string()
+    } else if peer_count == 0 && web_seed_rate > 0.0 {
+        "Downloading from web seeds".to_string()
+    } else if peer_count == 0 {
+        "Searching for peers...".to_string()
+    } else {
+        format!("Downloading ({} peers)", peer_count)
+    };
+    
+    // v8: download_speed.mbps is already MiB/s, despite its name
+    let speed_mbps = if let Some(live) = &stats.live {
+        live.download_speed.mbps
+    } else {
+        0.0
+    };
+    let web_seed_speed_mbps = web_seed_rate / (1024.0 * 1024.0);
+    
+    AppTorrentStatus {
+        id: handle.id(),
+        total_pieces,
+        completed_pieces: completed,
+        peers: peer_count,
+        speed_mbps,
+        web_seed_speed_mbps,
+        downloading: !is_fetching_metadata && !is_checking && !is_finished && !is_paused,
+        is_fetching_metadata,
+        is_checking,
+        is_moving: move_progress.is_some(),
+        is_sequential: streaming::read_through_files(handle.id(), ReadThrough::Sequential).is_some(),
+        is_network_paused,
+        is_queued,
+        status_message,
+        error: stats.error.or(entry.and_then(|e| e.error)),
+        total_bytes: stats.total_bytes,
+        downloaded_bytes: stats.progress_bytes,
+        web_seed_downloaded_bytes,
+        files: config::session_config().include_file_progress
+            .then(|| file_progress(handle, &stats.file_progress).ok())
+            .flatten(),
+    }
+}
+
+/// Progress of every non-padding file, from librqbit's per-file count of
+/// bytes in verified pieces
+fn file_progress(handle: &ManagedTorrent, progress: &[u64]) -> anyhow::Result<Vec<FileProgress>> {
+    let layout = handle.with_metadata(|m| file_layout(&m.info))??;
+    Ok(layout.into_iter()
+        .enumerate()
+        .filter(|(_, entry)| !entry.padding)
+        .map(|(index, entry)| {
+            let downloaded = progress.get(index).copied().unwrap_or(0).min(entry.length);
+            FileProgress {
+                index,
+                path: entry.path.path.to_string_lossy().to_string(),
+                size: entry.length,
+                downloaded,
+                complete: downloaded == entry.length,
+            }
+        })
+        .collect())
 }
 
+/// Add a torrent and stream its status until it completes.
+///
+/// `source` is a magnet link, a .torrent file path, or the http(s) URL of a
+/// .torrent file, which is downloaded first.
+///
+/// `use_existing_data_path` is the save folder another client used for the
+/// same torrent: it contains the torrent's file (single-file torrents) or its
+/// top-level folder (multi-file torrents). The data there is verified and the
+/// download continues in place instead of in `output_dir`, so only missing or
+/// corrupt pieces are fetched.
+///
+/// Otherwise the session config decides where data goes: it is written to
+/// `incomplete_dir` (or `output_dir`), optionally with a ".part" suffix per
+/// file, and moved to `complete_dir` (or `output_dir`) once finished.
+///
+/// The session's default trackers are added to the torrent's own unless
+/// `use_default_trackers` is false. Web seeds, from the torrent's url-list
+/// or the magnet link's ws= parameters, are downloaded from alongside peers.
 pub async fn start_download(
     source: String,
     output_dir: String,
     stream_sink: StreamSink<AppTorrentStatus>,
-    _selected_file_indices: Option<Vec<usize>>, // Ignored for now
+    selected_file_indices: Option<Vec<usize>>, // Files to download, all if None
+    use_existing_data_path: Option<String>,
+    use_default_trackers: bool,
 ) -> anyhow::Result<()> {
     let session = get_session().await?;
     
-    let add_torrent = if source.starts_with("magnet:") {
-        AddTorrent::from_url(&source)
+    // Data found in an existing folder stays there, untouched by the config
+    let (location, complete_dir) = match use_existing_data_path {
+        Some(path) => {
+            if !Path::new(&path).is_dir() {
+                return Err(anyhow::anyhow!("Existing data folder not found: {}", path));
+            }
+            (StorageLocation::new(&path, false), None)
+        }
+        None => download_location(output_dir)?,
+    };
+    
+    let (add_torrent, trackers, seeds) = if source.starts_with("magnet:") {
+        check_magnet_downloadable(&source)?;
+        let (trackers, seeds) = parse_magnet(source.clone())
+            .map(|m| (m.trackers, web_seeds::parse_urls(m.web_seeds.iter().map(|s| s.as_str()))))
+            .unwrap_or_default();
+        (AddTorrent::from_url(librqbit_magnet(&source)), trackers, seeds)
     } else {
-        // Assume file path
-        let bytes = std::fs::read(&source)?;
-        AddTorrent::from_bytes(bytes)
+        // A .torrent file on the web, else assume file path
+        let bytes = if torrent_url::is_url(&source) {
+            torrent_url::fetch(&source).await?
+        } else {
+            std::fs::read(&source)?
+        };
+        check_downloadable(&bytes)?;
+        let trackers = torrent_trackers(&bytes);
+        let seeds = web_seeds::torrent_web_seeds(&bytes);
+        (AddTorrent::from_bytes(bytes), trackers, seeds)
     };
     
     // Starting download for source
     
     // Try to add torrent - librqbit v8 handles duplicates with overwrite option
-    let add_result = session.add_torrent(
-        add_torrent,
-        Some(AddTorrentOptions {
-            output_folder: Some(output_dir.into()),
-            overwrite: true, // This should handle existing torrents
-            ..Default::default()
-        })
-    ).await;
+    let add_result = session.add_torrent(add_torrent, Some(AddTorrentOptions {
+        only_files: selected_file_indices,
+        preferred_id: Some(torrents::allocate_id()),
+        trackers: default_trackers_for(&trackers, use_default_trackers),
+        ..torrents::download_options(&location)
+    })).await;
     
     // Get the handle - either from new add or handle error for existing
     let handle = match add_result {
@@ -167,88 +587,44 @@ pub async fn start_download(
             // Add failed, looking for existing torrent
             
             // Find existing torrent by iterating through session
-            use std::cell::RefCell;
-            let found_handle: RefCell<Option<_>> = RefCell::new(None);
-            session.with_torrents(|torrents| {
-                for (_, handle) in torrents {
-                    // Just get the first active one for now
-                    // In production, match by info_hash
-                    *found_handle.borrow_mut() = Some(handle.clone());
-                    break;
-                }
+            // Just get the first active one for now
+            // In production, match by info_hash
+            let found_handle = session.with_torrents(|torrents| {
+                torrents.next().map(|(_, handle)| handle.clone())
             });
             
-            found_handle.into_inner().ok_or(anyhow::anyhow!("Torrent not found in session: {}", e))?
+            found_handle.ok_or(anyhow::anyhow!("Torrent not found in session: {}", e))?
         }
     };
     
+    let id = handle.id();
+    register_download(id, location, complete_dir, seeds, false);
+    
     // Status Loop
     loop {
-        let stats = handle.stats();
-        
-        // v8 API: check if we have metadata
-        let has_metadata = stats.total_bytes > 0;
-        let is_fetching_metadata = !has_metadata;
-        
-        // Calculate total pieces from total bytes / piece length (estimate)
-        let total_pieces = if stats.total_bytes > 0 {
-            ((stats.total_bytes as f64 / 16384.0).ceil()) as u32 // Assume 16KB pieces
-        } else {
-            0
-        };
-        
-        // v8: Calculate completed pieces from progress_bytes
-        let completed = if stats.total_bytes > 0 {
-            ((stats.progress_bytes as f64 / 16384.0).floor()) as u32
-        } else {
-            0
+        // Look the torrent up every tick: re-adding it (recheck, session
+        // restart) replaces the handle and maybe the session
+        let handle = match get_session().await?.get(TorrentIdOrHash::Id(id)) {
+            Some(handle) => handle,
+            None if torrents::is_readding(id) => {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+            None => break, // Removed from the session
         };
         
-        // v8 API: peer count from snapshot if live stats available
-        let peer_count = if let Some(live) = &stats.live {
-            live.snapshot.peer_stats.live as u32
-        } else {
-            0
-        };
-        
-        let status_message = if is_fetching_metadata {
-            format!("Fetching Metadata... ({} peers)", peer_count)
-        } else if stats.progress_bytes >= stats.total_bytes && stats.total_bytes > 0 {
-            "Complete".to_string()
-        } else if peer_count == 0 {
-            "Searching for peers...".to_string()
-        } else {
-            format!("Downloading ({} peers)", peer_count)
-        };
-        
-        // v8: download_speed.mbps is already in megabits/sec, convert to MB/s
-        // 1 Mbps = 0.125 MB/s (divide by 8)
-        let speed_mbps = if let Some(live) = &stats.live {
-            live.download_speed.mbps as f64 / 8.0
-        } else {
-            0.0
-        };
-        
-        let status = AppTorrentStatus {
-            total_pieces,
-            completed_pieces: completed,
-            peers: peer_count,
-            speed_mbps,
-            downloading: !is_fetching_metadata && stats.progress_bytes < stats.total_bytes,
-            is_fetching_metadata,
-            status_message,
-            error: None,
-            total_bytes: stats.total_bytes,
-            downloaded_bytes: stats.progress_bytes,
-        };
+        let status = torrent_status(&handle);
+        let is_complete = !status.is_checking
+            && status.total_bytes > 0
+            && status.downloaded_bytes >= status.total_bytes;
         
         if stream_sink.add(status).is_err() {
             // Stream closed by UI
             break;
         }
         
-        // v8: Check if download is complete
-        if stats.progress_bytes >= stats.total_bytes && stats.total_bytes > 0 {
+        // v8: Check if download is complete, and files are renamed/moved
+        if is_complete && torrents::get(id).is_none_or(|e| e.finished) {
             break;
         }
         
@@ -258,70 +634,275 @@ pub async fn start_download(
     Ok(())
 }
 
+#[derive(Debug, Clone, Default)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct AddRequest {
+    pub source: Option<String>,         // Magnet link, .torrent file path or http(s) URL of one
+    pub torrent_bytes: Option<Vec<u8>>, // Contents of a .torrent file, instead of a source
+    pub output_dir: String,
+    pub selected_file_indices: Option<Vec<usize>>, // Files to download, all if None
+    pub use_default_trackers: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct AddResult {
+    pub id: Option<usize>,         // Torrent id, None if the request failed
+    pub info_hash: Option<String>,
+    pub name: Option<String>,      // From the metadata, or the magnet link's dn= if any
+    pub already_added: bool,       // In the session or earlier in the batch; id is that torrent's
+    pub queued: bool,              // Added paused, to start when a download slot is free
+    pub error: Option<String>,
+}
+
+/// A batch request, parsed and checked
+struct PreparedAdd {
+    torrent: AddTorrent<'static>,
+    info_hash: Id20,
+    name: Option<String>,
+    trackers: Vec<String>,
+    seeds: Vec<url::Url>,
+}
+
+async fn prepare_add(request: &AddRequest) -> anyhow::Result<PreparedAdd> {
+    let bytes = match (&request.source, &request.torrent_bytes) {
+        (Some(source), None) if source.starts_with("magnet:") => {
+            check_magnet_downloadable(source)?;
+            let magnet = parse_magnet(source.clone())?;
+            let uri = librqbit_magnet(source);
+            let info_hash = librqbit::Magnet::parse(&uri)?.as_id20()
+                .ok_or(anyhow::anyhow!("Magnet link has no v1 info hash"))?;
+            return Ok(PreparedAdd {
+                torrent: AddTorrent::from_url(uri),
+                info_hash,
+                name: magnet.name,
+                trackers: magnet.trackers,
+                seeds: web_seeds::parse_urls(magnet.web_seeds.iter().map(|s| s.as_str())),
+            });
+        }
+        (Some(url), None) if torrent_url::is_url(url) => torrent_url::fetch(url).await?,
+        (Some(path), None) => std::fs::read(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read {}: {}", path, e))?,
+        (None, Some(bytes)) => bytes.clone(),
+        _ => return Err(anyhow::anyhow!("Expected either a source or torrent bytes")),
+    };
+    check_downloadable(&bytes)?;
+    let torrent = torrent_from_bytes::<ByteBufOwned>(&bytes)?;
+    Ok(PreparedAdd {
+        info_hash: torrent.info_hash,
+        name: torrent.info.name.as_ref().map(|n| String::from_utf8_lossy(n.as_ref()).to_string()),
+        trackers: torrent_trackers(&bytes),
+        seeds: web_seeds::torrent_web_seeds(&bytes),
+        torrent: AddTorrent::from_bytes(bytes),
+    })
+}
+
+/// Add several torrents at once, from magnet links, .torrent files (local
+/// or http(s) URLs) or their contents, with a result per request in the
+/// same order.
+///
+/// Every request is checked before anything is added, and one that fails
+/// doesn't stop the others. Torrents already in the session, or earlier in
+/// the batch, aren't added again; their result has the existing id.
+///
+/// With `max_active_downloads` set in the session config, torrents beyond
+/// the free download slots are added paused and started in order as other
+/// downloads finish. Magnet links are added once their metadata is resolved,
+/// in the background: their id is set right away, and get_torrents lists
+/// them from then on. A torrent that then fails to be added is dropped, and
+/// its id refers to nothing. Data goes where start_download would put it.
+pub async fn add_torrents_batch(requests: Vec<AddRequest>) -> anyhow::Result<Vec<AddResult>> {
+    let session = get_session().await?;
+    let prepared = futures::future::join_all(requests.iter().map(prepare_add)).await;
+
+    let mut results = Vec::with_capacity(requests.len());
+    for (request, prepared) in requests.into_iter().zip(prepared) {
+        let prepared = match prepared {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                results.push(AddResult { error: Some(format!("{:#}", e)), ..Default::default() });
+                continue;
+            }
+        };
+        let mut result = AddResult {
+            info_hash: Some(prepared.info_hash.as_string()),
+            name: prepared.name.clone(),
+            ..Default::default()
+        };
+
+        // Torrents of this batch are being added by now, so this finds them too
+        let existing = session.get(TorrentIdOrHash::Hash(prepared.info_hash))
+            .map(|handle| handle.id())
+            .or_else(|| queue::adding(prepared.info_hash));
+        if let Some(id) = existing {
+            result.id = Some(id);
+            result.already_added = true;
+            results.push(result);
+            continue;
+        }
+
+        let (location, complete_dir) = match download_location(request.output_dir) {
+            Ok(location) => location,
+            Err(e) => {
+                result.error = Some(format!("{:#}", e));
+                results.push(result);
+                continue;
+            }
+        };
+        let id = torrents::allocate_id();
+        let info_hash = prepared.info_hash;
+        let options = AddTorrentOptions {
+            paused: !queue::admit(&session, id, info_hash),
+            only_files: request.selected_file_indices,
+            preferred_id: Some(id),
+            trackers: default_trackers_for(&prepared.trackers, request.use_default_trackers),
+            ..torrents::download_options(&location)
+        };
+        result.id = Some(id);
+        result.queued = options.paused;
+        results.push(result);
+
+        register_download(id, location, complete_dir, prepared.seeds, true);
+        let session = session.clone();
+        tokio::spawn(async move {
+            let added = session.add_torrent(prepared.torrent, Some(options)).await;
+            // The entry only stays for a torrent added under this id
+            match added {
+                Ok(AddTorrentResponse::AlreadyManaged(existing, _)) => {
+                    torrents::unregister(id);
+                    println!("BATCH: torrent {} was added meanwhile as {}", id, existing);
+                }
+                Ok(_) => torrents::set_readding(id, false),
+                Err(e) => {
+                    torrents::unregister(id);
+                    println!("BATCH: failed to add torrent {}: {:#}", id, e);
+                }
+            }
+            queue::added(info_hash);
+        });
+    }
+    Ok(results)
+}
+
+/// Seed a torrent from data already on disk, such as the content a torrent
+/// was just created from, without downloading or copying anything.
+///
+/// `content_path` is the torrent's file (single-file torrents) or its
+/// top-level folder (multi-file torrents), named as in the torrent. Every
+/// piece is verified before the torrent is kept; if any fails, it is
+/// removed again, leaving the data untouched. Returns the torrent id.
+pub async fn seed_local(torrent_bytes: Vec<u8>, content_path: String) -> anyhow::Result<usize> {
+    let session = get_session().await?;
+    check_downloadable(&torrent_bytes)?;
+    let torrent = torrent_from_bytes::<ByteBufOwned>(&torrent_bytes)?;
+    let content_path = PathBuf::from(content_path);
+    let root = content_path.parent()
+        .ok_or(anyhow::anyhow!("Invalid content path: {:?}", content_path))?;
+
+    // The data must be where the torrent's layout puts it under `root`
+    let layout = file_layout(&torrent.info)?;
+    let top = layout.first().and_then(|entry| entry.path.path.components().next());
+    if top.is_none_or(|top| Some(top.as_os_str()) != content_path.file_name()) {
+        return Err(anyhow::anyhow!("Content path must be named as in the torrent: {}",
+            top.map(|t| t.as_os_str().to_string_lossy().to_string()).unwrap_or_default()));
+    }
+    for entry in layout.iter().filter(|entry| !entry.padding) {
+        let path = root.join(&entry.path.path);
+        let length = std::fs::metadata(&path).map(|m| m.len())
+            .map_err(|_| anyhow::anyhow!("Missing file: {:?}", path))?;
+        if length != entry.length {
+            return Err(anyhow::anyhow!("File size doesn't match the torrent: {:?}", path));
+        }
+    }
+
+    let location = StorageLocation::new(root, false);
+    let seeds = web_seeds::torrent_web_seeds(&torrent_bytes);
+    let handle = match session.add_torrent(AddTorrent::from_bytes(torrent_bytes), Some(AddTorrentOptions {
+        preferred_id: Some(torrents::allocate_id()),
+        ..torrents::download_options(&location)
+    })).await? {
+        AddTorrentResponse::Added(_, handle) => handle,
+        AddTorrentResponse::AlreadyManaged(id, _) => {
+            return Err(anyhow::anyhow!("Torrent is already in the session: {}", id));
+        }
+        AddTorrentResponse::ListOnly(_) => return Err(anyhow::anyhow!("Failed to add torrent")),
+    };
+
+    // librqbit checks every piece on disk as the torrent is added
+    let id = handle.id();
+    let stats = loop {
+        let stats = handle.stats();
+        if !matches!(stats.state, TorrentStatsState::Initializing) {
+            break stats;
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    };
+    if stats.error.is_some() || stats.progress_bytes < stats.total_bytes {
+        session.delete(TorrentIdOrHash::Id(id), false).await?;
+        return Err(match stats.error {
+            Some(e) => anyhow::anyhow!("Failed to check the data: {}", e),
+            None => anyhow::anyhow!("Data doesn't match the torrent: {} of {} bytes verified",
+                stats.progress_bytes, stats.total_bytes),
+        });
+    }
+
+    register_download(id, location, None, seeds, false);
+    Ok(id)
+}
+
+/// Where a new download's data goes by the session config: the folder it
+/// is written to, and where it is moved once finished if that is elsewhere
+fn download_location(output_dir: String) -> anyhow::Result<(Arc<StorageLocation>, Option<PathBuf>)> {
+    let config = config::session_config();
+    let final_dir = PathBuf::from(config.complete_dir.unwrap_or(output_dir));
+    let data_dir = config.incomplete_dir.map(PathBuf::from).unwrap_or(final_dir.clone());
+    std::fs::create_dir_all(&data_dir)?;
+    let complete_dir = (data_dir != final_dir).then_some(final_dir);
+    Ok((StorageLocation::new(&data_dir, config.part_suffix), complete_dir))
+}
+
+/// Remember a newly added torrent and start its background tasks, unless
+/// it was already registered. Torrents that aren't in the session yet are
+/// flagged as being added, so the tasks wait for them.
+fn register_download(id: usize, location: Arc<StorageLocation>, complete_dir: Option<PathBuf>, seeds: Vec<url::Url>, adding: bool) {
+    if torrents::register(id, location, complete_dir) {
+        torrents::set_readding(id, adding);
+        torrents::set_web_seeds(id, seeds);
+        completion::spawn_watcher(id);
+        announce::spawn_monitor(id);
+        web_seeds::spawn_worker(id);
+    }
+}
+
+/// Resolve a magnet link's metadata from peers, found with the link's
+/// trackers, the DHT and, unless `use_default_trackers` is false, the
+/// session's default trackers.
 pub async fn fetch_magnet_metadata(
     magnet_uri: String,
     timeout_secs: u32,
+    use_default_trackers: bool,
 ) -> anyhow::Result<TorrentInfo> {
     let session = get_session().await?;
+    check_magnet_downloadable(&magnet_uri)?;
+    let trackers = parse_magnet(magnet_uri.clone()).map(|m| m.trackers).unwrap_or_default();
     
-    // Add torrent in paused state to just fetch metadata
-    let add_result = session.add_torrent(
-        AddTorrent::from_url(&magnet_uri),
-        Some(AddTorrentOptions {
-            paused: true, // Don't start downloading
-            overwrite: true,
-            ..Default::default()
-        })
-    ).await?;
-    
-    let handle = add_result.into_handle()
-        .ok_or(anyhow::anyhow!("Failed to create torrent handle"))?;
-    
-    // Wait for metadata with timeout
-    let deadline = tokio::time::Instant::now() + Duration::from_secs(timeout_secs as u64);
+    // list_only resolves the metadata from peers without adding the torrent,
+    // so there is nothing to clean up afterwards
+    let add_result = tokio::time::timeout(
+        Duration::from_secs(timeout_secs as u64),
+        session.add_torrent(
+            AddTorrent::from_url(librqbit_magnet(&magnet_uri)),
+            Some(AddTorrentOptions {
+                list_only: true,
+                trackers: default_trackers_for(&trackers, use_default_trackers),
+                ..Default::default()
+            })
+        ),
+    ).await.map_err(|_| anyhow::anyhow!("Timeout waiting for metadata"))??;
     
-    loop {
-        if tokio::time::Instant::now() > deadline {
-            // Timeout - remove the torrent and fail
-            let _ = session.delete(librqbit::api::TorrentIdOrHash::Id(handle.id()), false).await;
-            return Err(anyhow::anyhow!("Timeout waiting for metadata"));
-        }
-        
-        // v8: Check if we have metadata (stats.total_bytes > 0 means metadata loaded)
-        let stats = handle.stats();
-        if stats.total_bytes > 0 {
-            // We have metadata! Build TorrentInfo
-            // Note: In v8, we can't easily access the full torrent info structure
-            // So we'll use a simplified approach with stats
-            
-            let result = TorrentInfo {
-                name: "Downloaded Content".to_string(), // v8 limitation: can't easily get name from stats
-                total_size: stats.total_bytes as usize,
-                piece_count: 0, // v8: Can't easily get piece count
-                piece_length: 0, // v8: Can't easily get piece length  
-                files: vec![FileInfo {
-                    path: "content".to_string(),
-                    size: stats.total_bytes as usize,
-                }],
-                info_hash: format!("{:?}", handle.id()), // v8: Use torrent ID as hash
-                announce: String::new(),
-            };
-            
-            // Remove the torrent (we'll re-add it when user confirms)
-            let _ = session.delete(librqbit::api::TorrentIdOrHash::Id(handle.id()), false).await;
-            
-            return Ok(result);
-        }
-        
-        // v8: Log peer count for debugging
-        let peer_count = if let Some(live) = &stats.live {
-            live.snapshot.peer_stats.live
-        } else {
-            0
-        };
-        println!("PREVIEW: Waiting for metadata... {} peers connected, {} bytes", peer_count, stats.total_bytes);
-        
-        tokio::time::sleep(Duration::from_millis(500)).await;
+    match add_result {
+        AddTorrentResponse::ListOnly(listed) => torrent_info_from_metadata(&listed.info, listed.info_hash, &listed.torrent_bytes),
+        _ => Err(anyhow::anyhow!("Failed to fetch magnet metadata")),
     }
 }
 
@@ -330,71 +911,296 @@ pub async fn get_torrents() -> anyhow::Result<Vec<AppTorrentStatus>> {
     let handles = session.with_torrents(|iter| {
         iter.map(|(_, handle)| handle.clone()).collect::<Vec<_>>()
     });
-    let mut results = Vec::new();
     
-    for handle in handles {
-        let stats = handle.stats();
-        
-        let has_metadata = stats.total_bytes > 0;
-        let is_fetching_metadata = !has_metadata;
-        
-        // Calculate total pieces from total bytes / piece length (estimate)
-        let total_pieces = if stats.total_bytes > 0 {
-            ((stats.total_bytes as f64 / 16384.0).ceil()) as u32 // Assume 16KB pieces
-        } else {
-            0
-        };
-        
-        // v8: Calculate completed pieces from progress_bytes
-        let completed = if stats.total_bytes > 0 {
-            ((stats.progress_bytes as f64 / 16384.0).floor()) as u32
-        } else {
-            0
-        };
-        
-        // v8 API: peer count from snapshot if live stats available
-        let peer_count = if let Some(live) = &stats.live {
-            live.snapshot.peer_stats.live as u32
-        } else {
-            0
-        };
-        
-        // Check if finished
-        let is_finished = stats.progress_bytes >= stats.total_bytes && stats.total_bytes > 0;
-        let is_paused = handle.is_paused();
-
-        let status_message = if is_fetching_metadata {
-            format!("Fetching Metadata... ({} peers)", peer_count)
-        } else if is_finished {
-            "Complete".to_string()
-        } else if is_paused {
-            "Paused".to_string()
-        } else if peer_count == 0 {
-            "Searching for peers...".to_string()
-        } else {
-            format!("Downloading ({} peers)", peer_count)
+    Ok(handles.iter().map(|handle| torrent_status(handle)).collect())
+}
+
+/// Force a recheck of a torrent's data on disk.
+///
+/// The torrent is removed from the session and re-added under the same id,
+/// which makes librqbit re-hash every piece through our storage. Pieces that
+/// fail verification are downloaded again. Progress shows up in the status
+/// stream with `is_checking` set. If it can't be added back (its folder is
+/// gone, say), the error is returned and the torrent is kept out of the
+/// session until the next recheck or session restart adds it.
+pub async fn recheck_torrent(id: usize) -> anyhow::Result<()> {
+    let session = get_session().await?;
+    if torrents::get(id).is_none() {
+        return Err(anyhow::anyhow!("Torrent was not added for download: {}", id));
+    }
+    torrents::readd(&session, id).await
+}
+
+/// Move a torrent's data to `new_dir`.
+///
+/// The torrent is paused while its files are moved (copied and deleted when
+/// `new_dir` is on another filesystem) and resumed afterwards if it was
+/// running. Progress shows up in the status stream with `is_moving` set. If
+/// any file can't be moved, everything is put back and the error returned.
+/// Should putting a file back fail too, the torrent stays wherever most of its
+/// data ended up and the error lists the files left at the other directory.
+pub async fn move_storage(id: usize, new_dir: String) -> anyhow::Result<()> {
+    let session = get_session().await?;
+    // An explicit move overrides the session's complete directory
+    torrents::set_complete_dir(id, None);
+    completion::relocate(&session, id, Path::new(&new_dir)).await
+}
+
+/// Download a torrent's pieces in order so playback can start early.
+///
+/// With `file_index` only that file is downloaded front to back, otherwise
+/// every selected file is, in torrent order. The mode ends by itself once
+/// those files are complete; `enabled: false` ends it early.
+pub async fn set_sequential_download(
+    id: usize,
+    enabled: bool,
+    file_index: Option<usize>,
+) -> anyhow::Result<()> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    
+    if !enabled {
+        streaming::stop_read_through(id, ReadThrough::Sequential);
+        return Ok(());
+    }
+    
+    let file_count = handle.with_metadata(|m| m.file_infos.len())?;
+    let file_ids = match file_index {
+        Some(index) if index < file_count => vec![index],
+        Some(index) => return Err(anyhow::anyhow!("No such file: {}", index)),
+        None => {
+            let only_files = handle.only_files();
+            (0..file_count)
+                .filter(|i| only_files.as_ref().is_none_or(|only| only.contains(i)))
+                .collect()
+        }
+    };
+    streaming::start_read_through(id, ReadThrough::Sequential, file_ids);
+    Ok(())
+}
+
+/// Get a local HTTP URL serving one file of a torrent, for media players.
+///
+/// The file can be played while it downloads: Range requests are supported,
+/// the pieces a player reads are downloaded first, and reads of data that is
+/// not there yet wait for it. The URL only works while the app is running.
+pub async fn stream_file(id: usize, file_index: usize) -> anyhow::Result<String> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    let file_name = handle.with_metadata(|m| {
+        m.file_infos.get(file_index).map(|f| {
+            f.relative_filename.file_name().unwrap_or_default().to_string_lossy().to_string()
+        })
+    })?.ok_or(anyhow::anyhow!("No such file: {}", file_index))?;
+    
+    streaming::file_url(id, file_index, &file_name).await
+}
+
+/// List a torrent's files with their priority and downloaded bytes
+pub async fn get_torrent_files(id: usize) -> anyhow::Result<Vec<FileInfo>> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    let priorities = priorities::file_priorities(&handle)?;
+    let progress = handle.stats().file_progress;
+    handle.with_metadata(|m| file_infos(&m.info, &priorities, &progress, &merkle_roots(&m.info, &m.torrent_bytes)))?
+}
+
+/// Change the priority of some of a torrent's files, by index.
+///
+/// librqbit only selects or deselects files, so priorities are approximated:
+/// Skip deselects a file, Low files are selected once every Normal and High
+/// file is complete, and High files have their pieces requested first.
+pub async fn set_file_priorities(
+    id: usize,
+    priorities: Vec<(usize, FilePriority)>,
+) -> anyhow::Result<()> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    if torrents::get(id).is_none() {
+        return Err(anyhow::anyhow!("Torrent was not added for download: {}", id));
+    }
+    
+    let mut file_priorities = priorities::file_priorities(&handle)?;
+    for (index, priority) in priorities {
+        let slot = file_priorities.get_mut(index)
+            .ok_or(anyhow::anyhow!("No such file: {}", index))?;
+        *slot = priority;
+    }
+    torrents::set_file_priorities(id, file_priorities);
+    priorities::apply(&session, &handle).await?;
+    
+    // Newly selected files need renaming and moving once they complete too
+    if torrents::reopen(id) {
+        completion::spawn_watcher(id);
+    }
+    Ok(())
+}
+
+/// Progress of each file of a torrent
+pub async fn get_file_progress(id: usize) -> anyhow::Result<Vec<FileProgress>> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    file_progress(&handle, &handle.stats().file_progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use crate::test_util::{content, create_torrent, wait_for, with_session};
+
+    const BTIH: &str = "urn:btih:a621779b5e3d486e127c3efbca9b6f8d135f52e5";
+    const BTMH: &str = "urn:btmh:1220caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e";
+    // A SHA-512 multihash, which isn't used for info hashes
+    const BTMH_SHA512: &str = "urn:btmh:1340aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    #[test]
+    fn parses_magnet_info_hashes() {
+        let hybrid = parse_magnet(format!("magnet:?xt={}&xt={}&dn=x", BTIH, BTMH)).unwrap();
+        assert_eq!(hybrid.info_hash, "a621779b5e3d486e127c3efbca9b6f8d135f52e5");
+        assert_eq!(hybrid.info_hash_v2.as_deref(), Some(&BTMH[13..]));
+        assert_eq!(hybrid.name.as_deref(), Some("x"));
+
+        let v2 = parse_magnet(format!("magnet:?xt={}", BTMH)).unwrap();
+        assert_eq!(v2.info_hash, &BTMH[13..53]);
+
+        // Unsupported v2 hashes are ignored next to a v1 one
+        let v1 = parse_magnet(format!("magnet:?xt={}&xt={}", BTMH_SHA512, BTIH)).unwrap();
+        assert_eq!(v1.info_hash, "a621779b5e3d486e127c3efbca9b6f8d135f52e5");
+        assert_eq!(v1.info_hash_v2, None);
+
+        let err = parse_magnet(format!("magnet:?xt={}", BTMH_SHA512)).err().unwrap();
+        assert!(err.to_string().contains("Unsupported multihash"), "{}", err);
+        let err = parse_magnet("magnet:?dn=x".to_string()).err().unwrap();
+        assert!(err.to_string().contains("No info hash"), "{}", err);
+    }
+
+    #[test]
+    fn librqbit_gets_magnets_without_unsupported_topics() {
+        let uri = format!("magnet:?xt={}&xt={}&dn=a+b", BTIH, BTMH);
+        assert_eq!(librqbit_magnet(&uri), uri);
+
+        let uri = format!("magnet:?xt={}&xt={}&dn=a+b", BTMH_SHA512, BTIH);
+        let magnet = librqbit::Magnet::parse(&librqbit_magnet(&uri)).unwrap();
+        assert_eq!(magnet.as_id20().unwrap().as_string(), &BTIH[9..]);
+        assert!(librqbit::Magnet::parse(&uri).is_err());
+    }
+
+    #[test]
+    fn v2_file_paths_are_deduplicated_like_v1() {
+        let file = |path: &[&str], length| meta_v2::V2File {
+            path: path.iter().map(|p| p.to_string()).collect(),
+            length,
+            pieces_root: None,
         };
-        
-        // v8: download_speed.mbps is megabits/sec, convert to MB/s
-        let speed_mbps = if let Some(live) = &stats.live {
-            live.download_speed.mbps as f64 / 8.0
-        } else {
-            0.0
+        let v2 = V2Metadata {
+            info_hash: [0x11; 32],
+            hybrid: false,
+            name: "album".to_string(),
+            piece_length: 16 * 1024,
+            files: vec![file(&["A.txt"], 10), file(&["a.txt"], 20), file(&["DIR", "x"], 30), file(&["dir", "x"], 0)],
         };
-        
-        results.push(AppTorrentStatus {
-            total_pieces,
-            completed_pieces: completed,
-            peers: peer_count,
-            speed_mbps,
-            downloading: !is_fetching_metadata && !is_finished && !is_paused,
-            is_fetching_metadata,
-            status_message,
-            error: None,
-            total_bytes: stats.total_bytes,
-            downloaded_bytes: stats.progress_bytes,
-        });
+        let info = torrent_info_from_v2(&v2, b"de").unwrap();
+        let paths: Vec<(&str, Option<&str>)> = info.files.iter()
+            .map(|f| (f.path.as_str(), f.original_path.as_deref()))
+            .collect();
+        assert_eq!(paths, vec![
+            ("album/A.txt", None),
+            ("album/a (1).txt", Some("album/a.txt")),
+            ("album/DIR/x", None),
+            ("album/dir/x (1)", Some("album/dir/x")),
+        ]);
+        assert_eq!((info.total_size, info.piece_count), (60, 3));
+    }
+
+    fn add_request(torrent_bytes: &[u8], output_dir: &Path) -> AddRequest {
+        AddRequest {
+            source: None,
+            torrent_bytes: Some(torrent_bytes.to_vec()),
+            output_dir: output_dir.to_string_lossy().to_string(),
+            selected_file_indices: None,
+            use_default_trackers: false,
+        }
+    }
+
+    /// The current settings with data going straight to the output folder
+    /// and no download limit
+    fn plain_config() -> config::SessionConfig {
+        config::SessionConfig {
+            complete_dir: None,
+            incomplete_dir: None,
+            max_active_downloads: None,
+            ..config::session_config()
+        }
+    }
+
+    #[test]
+    fn batches_add_each_torrent_once() {
+        with_session(config::with_config(plain_config(), async {
+            let dir = tempfile::tempdir().unwrap();
+            let sources = dir.path().join("sources");
+            std::fs::create_dir(&sources).unwrap();
+            let mut torrents = Vec::new();
+            for name in ["a.bin", "b.bin"] {
+                std::fs::write(sources.join(name), content(20_000)).unwrap();
+                torrents.push(create_torrent(&sources.join(name), None));
+            }
+            let downloads = dir.path().join("downloads");
+
+            let first = add_torrents_batch(vec![add_request(&torrents[0].torrent_bytes, &downloads)]).await.unwrap();
+            let a = first[0].id.unwrap();
+            assert!(!first[0].already_added && first[0].error.is_none());
+            let session = get_session().await.unwrap();
+            wait_for("the first torrent to be added", || session.get(TorrentIdOrHash::Id(a)).is_some()).await;
+
+            let results = add_torrents_batch(vec![
+                add_request(&torrents[1].torrent_bytes, &downloads),
+                add_request(&torrents[0].torrent_bytes, &downloads),
+                add_request(b"not a torrent", &downloads),
+                add_request(&torrents[1].torrent_bytes, &downloads),
+            ]).await.unwrap();
+            let b = results[0].id.unwrap();
+            assert_ne!(a, b);
+            assert!(!results[0].already_added);
+            // In the session, then earlier in the batch
+            assert_eq!((results[1].id, results[1].already_added), (Some(a), true));
+            assert_eq!((results[3].id, results[3].already_added), (Some(b), true));
+            assert!(results[2].id.is_none() && results[2].error.is_some());
+            assert_eq!(results[3].info_hash.as_deref(), Some(torrents[1].info_hash.as_str()));
+
+            wait_for("the second torrent to be added", || session.get(TorrentIdOrHash::Id(b)).is_some()).await;
+            assert_eq!(session.with_torrents(|t| t.filter(|(_, h)| h.id() == a || h.id() == b).count()), 2);
+            for id in [a, b] {
+                session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+            }
+        }));
+    }
+
+    #[test]
+    fn failed_batch_adds_leave_nothing_behind() {
+        with_session(config::with_config(plain_config(), async {
+            let dir = tempfile::tempdir().unwrap();
+            let album = dir.path().join("album");
+            std::fs::create_dir(&album).unwrap();
+            std::fs::write(album.join("a.bin"), content(20_000)).unwrap();
+            let created = create_torrent(&album, None);
+
+            // A file where the torrent's folder goes: the storage can't open
+            let downloads = dir.path().join("downloads");
+            std::fs::create_dir(&downloads).unwrap();
+            std::fs::write(downloads.join("album"), b"").unwrap();
+
+            let results = add_torrents_batch(vec![add_request(&created.torrent_bytes, &downloads)]).await.unwrap();
+            let id = results[0].id.unwrap();
+            wait_for("the failed torrent to be dropped", || torrents::get(id).is_none()).await;
+            let info_hash = Id20::from_str(&created.info_hash).unwrap();
+            assert!(get_session().await.unwrap().get(TorrentIdOrHash::Hash(info_hash)).is_none());
+            assert_eq!(queue::adding(info_hash), None);
+        }));
     }
-    
-    Ok(results)
 }
@@ -0,0 +1,390 @@
+use crate::{announce, get_session, torrents, web_seeds};
+use librqbit::api::TorrentIdOrHash;
+use librqbit::TorrentStatsState;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinSet;
+use url::Url;
+
+/// How long force_reannounce collects peers from the DHT
+const DHT_LOOKUP_TIME: Duration = Duration::from_secs(15);
+
+/// One tracker of a torrent and the outcome of the last request to it.
+///
+/// librqbit announces to the trackers it was given with the torrent but
+/// doesn't report the responses, so those are scraped for their counts.
+/// Trackers added since are announced to directly until the torrent's next
+/// re-add hands them to librqbit. Times are Unix timestamps in seconds. The
+/// counts are those of the last successful request, None if the tracker
+/// didn't send them.
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct TrackerInfo {
+    pub url: String,
+    pub tier: usize,                 // Trackers of lower tiers are tried first
+    pub last_update: Option<u64>,
+    pub next_update: Option<u64>,    // None while updating or the torrent is not live
+    pub is_scraped: bool,            // librqbit announces to it, the counts come from scrapes
+    pub seeders: Option<u64>,
+    pub leechers: Option<u64>,
+    pub completed: Option<u64>,      // Finished downloads, from scrapes and some HTTP announces
+    pub peers: Option<u32>,          // Peers in the last announce, None when scraped
+    pub last_error: Option<String>,  // Set if the last request failed
+    pub is_updating: bool,           // A request is under way
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Parse a tracker URL librqbit can announce to
+pub(crate) fn parse_tracker(url: &str) -> anyhow::Result<Url> {
+    let parsed = Url::parse(url.trim())
+        .map_err(|e| anyhow::anyhow!("Invalid tracker URL {}: {}", url, e))?;
+    match parsed.scheme() {
+        "http" | "https" => Ok(parsed),
+        "udp" if parsed.port().is_some() => Ok(parsed),
+        "udp" => Err(anyhow::anyhow!("UDP tracker URL has no port: {}", url)),
+        scheme => Err(anyhow::anyhow!("Unsupported tracker scheme {}: {}", scheme, url)),
+    }
+}
+
+/// The trackers of a torrent by tier, with their status
+pub async fn get_trackers(id: usize) -> anyhow::Result<Vec<TrackerInfo>> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    let states = announce::tracker_states(id);
+
+    Ok(torrents::tracker_tiers(&handle)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(tier, urls)| urls.into_iter().map(move |url| (tier, url)))
+        .map(|(tier, url)| {
+            let state = states.get(&url).cloned().unwrap_or_default();
+            TrackerInfo {
+                url: url.to_string(),
+                tier,
+                last_update: state.last_update.map(unix_time),
+                next_update: state.next_update.map(unix_time),
+                is_scraped: state.scraped,
+                seeders: state.seeders,
+                leechers: state.leechers,
+                completed: state.completed,
+                peers: state.peers.map(|p| p as u32),
+                last_error: state.last_error,
+                is_updating: state.updating,
+            }
+        })
+        .collect())
+}
+
+/// Add trackers to a torrent, each in a tier of its own after the existing
+/// ones. Trackers it already has are skipped.
+///
+/// librqbit only reads trackers when a torrent is added, so the torrent is
+/// re-added with the new trackers in its torrent file, which re-verifies its
+/// data like recheck_torrent. librqbit then announces to them itself.
+pub async fn add_trackers(id: usize, urls: Vec<String>) -> anyhow::Result<()> {
+    let urls = urls.iter().map(|url| parse_tracker(url)).collect::<anyhow::Result<Vec<_>>>()?;
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    if torrents::get(id).is_none() {
+        return Err(anyhow::anyhow!("Torrent was not added for download: {}", id));
+    }
+
+    let mut tiers = torrents::tracker_tiers(&handle);
+    drop(handle);
+    let mut added = false;
+    for url in urls {
+        if !tiers.iter().flatten().any(|t| *t == url) {
+            tiers.push(vec![url]);
+            added = true;
+        }
+    }
+    if added {
+        torrents::set_trackers(id, tiers);
+        torrents::readd(&session, id).await?;
+    }
+    Ok(())
+}
+
+/// Remove a tracker from a torrent. Like add_trackers, the torrent is
+/// re-added without it for librqbit to stop announcing to it.
+pub async fn remove_tracker(id: usize, url: String) -> anyhow::Result<()> {
+    let url = parse_tracker(&url)?;
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    if torrents::get(id).is_none() {
+        return Err(anyhow::anyhow!("Torrent was not added for download: {}", id));
+    }
+
+    let mut tiers = torrents::tracker_tiers(&handle);
+    drop(handle);
+    if !tiers.iter().flatten().any(|t| *t == url) {
+        return Err(anyhow::anyhow!("Torrent has no tracker {}", url));
+    }
+    for tier in &mut tiers {
+        tier.retain(|t| *t != url);
+    }
+    tiers.retain(|tier| !tier.is_empty());
+    torrents::set_trackers(id, tiers);
+    torrents::readd(&session, id).await
+}
+
+/// One web seed of a torrent and what was downloaded from it
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct WebSeedInfo {
+    pub url: String,
+    pub downloaded_bytes: u64,
+    pub last_error: Option<String>, // Set if the last request failed
+}
+
+/// The web seeds of a torrent, from its url-list or the magnet's ws=
+pub async fn get_web_seeds(id: usize) -> anyhow::Result<Vec<WebSeedInfo>> {
+    let entry = torrents::get(id)
+        .ok_or(anyhow::anyhow!("Torrent was not added for download: {}", id))?;
+    let states = web_seeds::seed_states(id);
+    Ok(entry.web_seeds
+        .into_iter()
+        .map(|url| {
+            let state = states.get(&url).cloned().unwrap_or_default();
+            WebSeedInfo {
+                url: url.to_string(),
+                downloaded_bytes: state.downloaded,
+                last_error: state.last_error,
+            }
+        })
+        .collect())
+}
+
+/// Peers one source found for force_reannounce
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct PeerSourceResult {
+    pub source: String,         // Tracker URL, or "DHT"
+    pub peers: Option<u32>,     // Peers returned, None for trackers librqbit announces to
+    pub new_peers: Option<u32>, // Of those, peers the torrent didn't know yet
+    pub error: Option<String>,  // Set if the source couldn't be queried
+}
+
+#[derive(Debug, Clone)]
+#[flutter_rust_bridge::frb(serialize)]
+pub struct ReannounceSummary {
+    pub sources: Vec<PeerSourceResult>,
+    pub new_peers: u32,         // New peers over all sources, each counted once
+}
+
+/// Announce a running torrent to all its trackers now and look it up in
+/// the DHT, and report how many new peers each source turned up.
+///
+/// librqbit can neither be told to announce nor be handed peers while a
+/// torrent runs. The new peers are added to the torrent like add_peer does
+/// and it is re-added with them, which re-verifies its data like
+/// recheck_torrent; librqbit then announces to its trackers again too.
+/// Those trackers are only scraped from here, so their peers aren't
+/// counted. The torrent's current connections are re-established.
+pub async fn force_reannounce(id: usize) -> anyhow::Result<ReannounceSummary> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    let Some(live) = handle.live().filter(|_| matches!(handle.stats().state, TorrentStatsState::Live)) else {
+        return Err(anyhow::anyhow!("Torrent is not running: {}", id));
+    };
+    if torrents::get(id).is_none() {
+        return Err(anyhow::anyhow!("Torrent was not added for download: {}", id));
+    }
+
+    // Every peer librqbit has seen, not only connected ones. The filter type
+    // isn't exported, but it deserializes.
+    let filter = serde_json::from_str(r#"{"state": "All"}"#)?;
+    let known: HashSet<SocketAddr> = live.per_peer_stats_snapshot(filter).peers.keys()
+        .filter_map(|address| address.parse().ok())
+        .collect();
+    drop(live);
+
+    let mut announces = JoinSet::new();
+    for (index, tracker) in torrents::tracker_tiers(&handle).into_iter().flatten().enumerate() {
+        let session = session.clone();
+        let handle = handle.clone();
+        announces.spawn(async move {
+            let result = announce::update_now(&session, &handle, &tracker).await
+                .map(|peers| peers.map(|peers| peers.into_iter().collect::<HashSet<_>>()));
+            (index, tracker.to_string(), result)
+        });
+    }
+    let is_private = handle.with_metadata(|m| m.info.private).unwrap_or(false);
+    let dht = async {
+        if is_private {
+            Err(anyhow::anyhow!("Private torrents don't use the DHT"))
+        } else {
+            announce::dht_lookup(&session, &handle, DHT_LOOKUP_TIME).await
+        }
+    };
+    let (mut announced, dht) = tokio::join!(announces.join_all(), dht);
+    announced.sort_by_key(|(index, _, _)| *index);
+    let results = announced.into_iter()
+        .map(|(_, source, result)| (source, result))
+        .chain([("DHT".to_string(), dht.map(Some))]);
+
+    let mut new_peers = HashSet::new();
+    let sources = results
+        .map(|(source, result)| match result {
+            Ok(Some(peers)) => {
+                let new: Vec<SocketAddr> = peers.iter().filter(|p| !known.contains(p)).copied().collect();
+                let result = PeerSourceResult {
+                    source,
+                    peers: Some(peers.len() as u32),
+                    new_peers: Some(new.len() as u32),
+                    error: None,
+                };
+                new_peers.extend(new);
+                result
+            }
+            Ok(None) => PeerSourceResult {
+                source,
+                peers: None,
+                new_peers: None,
+                error: None,
+            },
+            Err(e) => PeerSourceResult {
+                source,
+                peers: None,
+                new_peers: None,
+                error: Some(format!("{:#}", e)),
+            },
+        })
+        .collect();
+
+    let reannounces = !announce::librqbit_trackers(&handle).is_empty();
+    drop(handle);
+    for peer in &new_peers {
+        torrents::add_peer(id, *peer);
+    }
+    if reannounces || !new_peers.is_empty() {
+        torrents::readd(&session, id).await?;
+    }
+    Ok(ReannounceSummary {
+        sources,
+        new_peers: new_peers.len() as u32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use librqbit::{AddTorrent, AddTorrentOptions};
+    use crate::api::create::CreateTorrentOptions;
+    use crate::create::{Plan, Progress};
+    use crate::storage::StorageLocation;
+    use crate::test_util::{content, create_torrent, wait_for, with_session, HttpResponse, HttpServer, Seeder};
+
+    #[test]
+    fn tracker_edits_reach_librqbit() {
+        with_session(async {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join("movie.bin"), content(50_000)).unwrap();
+            let created = create_torrent(&dir.path().join("movie.bin"), Some(16 * 1024));
+            let location = StorageLocation::new(dir.path(), false);
+            let id = torrents::allocate_id();
+            torrents::register(id, location.clone(), None);
+            let session = get_session().await.unwrap();
+            session
+                .add_torrent(AddTorrent::from_bytes(created.torrent_bytes), Some(AddTorrentOptions {
+                    paused: true,
+                    preferred_id: Some(id),
+                    ..torrents::download_options(&location)
+                }))
+                .await.unwrap();
+            let librqbit_trackers = || {
+                session.get(TorrentIdOrHash::Id(id)).unwrap().shared().trackers.iter()
+                    .map(|url| url.to_string())
+                    .collect::<Vec<_>>()
+            };
+
+            let tracker = "http://127.0.0.1:9/announce".to_string();
+            add_trackers(id, vec![tracker.clone()]).await.unwrap();
+            assert_eq!(librqbit_trackers(), vec![tracker.clone()]);
+            assert!(session.get(TorrentIdOrHash::Id(id)).unwrap().is_paused());
+
+            remove_tracker(id, tracker).await.unwrap();
+            assert!(librqbit_trackers().is_empty());
+            session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+
+    #[test]
+    fn reannounced_peers_reach_librqbit() {
+        with_session(async {
+            // Two trackers of a private torrent: librqbit only announces to
+            // one, the other is announced to from here
+            let announcing = Arc::new(Mutex::new(None::<String>));
+            let seeder_peer = Arc::new(Mutex::new(Vec::new()));
+            let server = HttpServer::start({
+                let announcing = announcing.clone();
+                let seeder_peer = seeder_peer.clone();
+                move |request| {
+                    let mut body = b"d8:intervali1800e5:peers".to_vec();
+                    let served = announcing.lock().unwrap().as_ref().is_some_and(|path| request.target.starts_with(path));
+                    let peers = if served { seeder_peer.lock().unwrap().clone() } else { Vec::new() };
+                    body.extend_from_slice(format!("{}:", peers.len()).as_bytes());
+                    body.extend_from_slice(&peers);
+                    body.push(b'e');
+                    HttpResponse::ok(body)
+                }
+            }).await;
+            let trackers = vec![server.url("/a/announce"), server.url("/b/announce")];
+
+            let shared = tempfile::tempdir().unwrap();
+            let data = content(100_000);
+            std::fs::write(shared.path().join("movie.bin"), &data).unwrap();
+            let options = CreateTorrentOptions {
+                piece_length: Some(16 * 1024),
+                trackers: trackers.clone(),
+                private: true,
+                ..Default::default()
+            };
+            let created = Plan::new(&shared.path().join("movie.bin"), options).unwrap().build(&Progress::default()).unwrap();
+            let seeder = Seeder::start(&created.torrent_bytes, shared.path()).await;
+            let mut peer = vec![127, 0, 0, 1];
+            peer.extend_from_slice(&seeder.addr.port().to_be_bytes());
+            *seeder_peer.lock().unwrap() = peer;
+
+            let downloads = tempfile::tempdir().unwrap();
+            let location = StorageLocation::new(downloads.path(), false);
+            let id = torrents::allocate_id();
+            torrents::register(id, location.clone(), None);
+            let session = get_session().await.unwrap();
+            let handle = session
+                .add_torrent(AddTorrent::from_bytes(created.torrent_bytes), Some(AddTorrentOptions {
+                    preferred_id: Some(id),
+                    ..torrents::download_options(&location)
+                }))
+                .await.unwrap().into_handle().unwrap();
+            wait_for("the torrent to start", || matches!(handle.stats().state, TorrentStatsState::Live)).await;
+            let ours = trackers.iter()
+                .find(|url| !announce::librqbit_trackers(&handle).contains(&Url::parse(url).unwrap()))
+                .unwrap();
+            *announcing.lock().unwrap() = Some(Url::parse(ours).unwrap().path().trim_end_matches("announce").to_string());
+            drop(handle);
+
+            let summary = force_reannounce(id).await.unwrap();
+            assert_eq!(summary.new_peers, 1);
+            let source = summary.sources.iter().find(|s| s.source == *ours).unwrap();
+            assert_eq!((source.peers, source.new_peers), (Some(1), Some(1)));
+            assert_eq!(torrents::get(id).unwrap().peers, vec![seeder.addr]);
+
+            // Only the peer found here has the data
+            wait_for("the download to finish", || {
+                session.get(TorrentIdOrHash::Id(id)).is_some_and(|h| h.stats().finished)
+            }).await;
+            assert_eq!(std::fs::read(downloads.path().join("movie.bin")).unwrap(), data);
+            session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+}
@@ -37,7 +37,7 @@ async fn main() -> anyhow::Result<()> {
             let peers = live.snapshot.peer_stats.live;
             let progress_mb = stats.progress_bytes / 1_000_000;
             let total_mb = stats.total_bytes / 1_000_000;
-            let speed = (live.download_speed.mbps as f64) / 1_000_000.0;
+            let speed = live.download_speed.mbps / 1_000_000.0;
             
             println!("[{:03}s] Peers: {:3} | Progress: {:4} MB / {:4} MB | Speed: {:.2} MB/s", 
                 i * 2, peers, progress_mb, total_mb, speed);
@@ -0,0 +1,35 @@
+// The blocklist handed to librqbit when a session starts.
+//
+// librqbit reads its blocklist once, from a URL, when the session is created,
+// and checks it for both incoming and outgoing connections. Banned peers and
+// the IP filter are written to a file in its format ("name:first-last", one
+// range per line).
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use anyhow::{Context, Result};
+use crate::api::peers::{banned_ips, take_ip_filter_ranges};
+
+const BLOCKLIST_FILE: &str = "session_blocklist.txt";
+
+/// Write the blocklist for a new session and return its path, or None if
+/// nothing is blocked
+pub fn write_blocklist() -> Result<Option<PathBuf>> {
+    let bans = banned_ips();
+    let filter = take_ip_filter_ranges();
+    if bans.is_empty() && filter.is_empty() {
+        return Ok(None);
+    }
+
+    let path = crate::app_data_dir().join(BLOCKLIST_FILE);
+    let file = File::create(&path).context(format!("Failed to create {:?}", path))?;
+    let mut out = BufWriter::new(file);
+    for ip in bans {
+        writeln!(out, "ban:{}-{}", ip, ip)?;
+    }
+    for (first, last) in filter {
+        writeln!(out, "filter:{}-{}", first, last)?;
+    }
+    out.flush()?;
+    Ok(Some(path))
+}
@@ -0,0 +1,144 @@
+// Finishing downloads: dropping the ".part" suffix of files as they complete
+// and moving torrents to the complete directory once they are done.
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use librqbit::api::TorrentIdOrHash;
+use librqbit::{Session, TorrentStatsState};
+use crate::{get_session, torrents};
+
+/// Watch a registered torrent until it finishes downloading, then finalize it
+pub fn spawn_watcher(id: usize) {
+    tokio::spawn(async move {
+        let error = watch(id).await.err().map(|e| format!("{:#}", e));
+        if let Some(e) = &error {
+            println!("COMPLETION: failed to finish torrent {}: {}", id, e);
+        }
+        torrents::set_finished(id, error);
+    });
+}
+
+async fn watch(id: usize) -> anyhow::Result<()> {
+    loop {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        // Looked up every tick: restarting the session replaces it
+        let session = get_session().await?;
+        let handle = match session.get(TorrentIdOrHash::Id(id)) {
+            Some(handle) => handle,
+            None if torrents::is_readding(id) => continue,
+            None => return Ok(()), // Removed from the session
+        };
+        let Some(entry) = torrents::get(id) else {
+            return Ok(());
+        };
+        if entry.move_progress.is_some() {
+            continue;
+        }
+
+        if let Err(e) = crate::priorities::apply(&session, &handle).await {
+            println!("COMPLETION: failed to apply file priorities of torrent {}: {:#}", id, e);
+            continue;
+        }
+
+        let stats = handle.stats();
+        // Progress is only meaningful once the initial check is done
+        if !matches!(stats.state, TorrentStatsState::Live | TorrentStatsState::Paused) {
+            continue;
+        }
+
+        let lengths = handle.with_metadata(|m| {
+            m.file_infos.iter().map(|f| f.len).collect::<Vec<_>>()
+        })?;
+        for (file_id, (have, length)) in stats.file_progress.iter().zip(lengths).enumerate() {
+            if *have >= length {
+                entry.location.finish_file(file_id)?;
+            }
+        }
+
+        if stats.finished {
+            if let Some(complete_dir) = &entry.complete_dir {
+                relocate(&session, id, complete_dir).await?;
+            }
+            return Ok(());
+        }
+    }
+}
+
+/// Move a torrent's data to `new_dir`, pausing it meanwhile.
+/// Progress is published through the registry for the status stream.
+pub async fn relocate(session: &Arc<Session>, id: usize, new_dir: &Path) -> anyhow::Result<()> {
+    let handle = session.get(TorrentIdOrHash::Id(id))
+        .ok_or(anyhow::anyhow!("Torrent not found: {}", id))?;
+    let entry = torrents::get(id)
+        .ok_or(anyhow::anyhow!("Torrent was not added for download: {}", id))?;
+
+    std::fs::create_dir_all(new_dir)?;
+
+    let was_paused = handle.is_paused();
+    if !was_paused {
+        session.pause(&handle).await?;
+    }
+
+    torrents::set_move_progress(id, Some((0, 0)));
+    let location = entry.location.clone();
+    let new_dir = new_dir.to_path_buf();
+    let result = tokio::task::spawn_blocking(move || {
+        location.relocate(&new_dir, |moved, total| {
+            torrents::set_move_progress(id, Some((moved, total)));
+        })
+    }).await;
+    torrents::set_move_progress(id, None);
+
+    if !was_paused {
+        session.unpause(&handle).await?;
+    }
+
+    result?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librqbit::{AddTorrent, AddTorrentOptions};
+    use crate::storage::StorageLocation;
+    use crate::test_util::{content, create_torrent, wait_for, with_session, Seeder};
+
+    #[test]
+    fn finishes_downloads_across_session_restarts() {
+        with_session(async {
+            let shared = tempfile::tempdir().unwrap();
+            let data = content(100_000);
+            std::fs::write(shared.path().join("movie.bin"), &data).unwrap();
+            let created = create_torrent(&shared.path().join("movie.bin"), Some(16 * 1024));
+            let seeder = Seeder::start(&created.torrent_bytes, shared.path()).await;
+
+            let downloads = tempfile::tempdir().unwrap();
+            let incomplete = downloads.path().join("incomplete");
+            let complete = downloads.path().join("complete");
+            let location = StorageLocation::new(&incomplete, true);
+            let id = torrents::allocate_id();
+            torrents::register(id, location.clone(), Some(complete.clone()));
+            // Without peers, nothing is downloaded before the restart
+            get_session().await.unwrap()
+                .add_torrent(AddTorrent::from_bytes(created.torrent_bytes.clone()), Some(AddTorrentOptions {
+                    preferred_id: Some(id),
+                    ..torrents::download_options(&location)
+                }))
+                .await.unwrap();
+            spawn_watcher(id);
+            // Let the watcher look at the torrent in the first session
+            tokio::time::sleep(Duration::from_millis(1500)).await;
+            assert!(incomplete.join("movie.bin.part").exists());
+
+            torrents::add_peer(id, seeder.addr);
+            crate::replace_session().await.unwrap();
+
+            wait_for("the download to finish", || torrents::get(id).is_some_and(|e| e.finished)).await;
+            assert_eq!(torrents::get(id).unwrap().error, None);
+            assert_eq!(std::fs::read(complete.join("movie.bin")).unwrap(), data);
+            assert!(!incomplete.join("movie.bin.part").exists());
+            get_session().await.unwrap().delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+}
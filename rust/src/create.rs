@@ -0,0 +1,504 @@
+// Creating .torrent files from local files and folders.
+//
+// The content is laid out as librqbit and other clients expect it: a file
+// is a single-file torrent, a folder a multi-file torrent named after it,
+// with its files in path order. Pieces are hashed on a pool of threads, each
+// taking a contiguous run of pieces so files are read front to back.
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{bail, Context, Result};
+use librqbit::ByteBufOwned;
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+use url::Url;
+use crate::api::create::CreateTorrentOptions;
+use crate::api::trackers::parse_tracker;
+
+const MIN_PIECE_LENGTH: u32 = 16 * 1024;
+const MAX_PIECE_LENGTH: u32 = 16 * 1024 * 1024;
+/// Piece count aimed for when picking the piece length
+const TARGET_PIECES: u64 = 1500;
+const CREATED_BY: &str = concat!("torrent-core/", env!("CARGO_PKG_VERSION"));
+
+/// Hashing progress, shared with the threads doing it
+#[derive(Default)]
+pub struct Progress {
+    pub hashed_bytes: AtomicU64,
+    /// Set to stop hashing early
+    pub cancelled: AtomicBool,
+}
+
+struct ContentFile {
+    path: PathBuf,
+    // Path within the torrent's folder, empty for single-file torrents
+    components: Vec<String>,
+    length: u64,
+}
+
+/// A torrent to be created: its files found and its options checked
+pub struct Plan {
+    name: String,
+    files: Vec<ContentFile>,
+    total_bytes: u64,
+    piece_length: u32,
+    trackers: Vec<Url>,
+    web_seeds: Vec<Url>,
+    comment: Option<String>,
+    private: bool,
+    source: Option<String>,
+}
+
+/// A created torrent
+pub struct Created {
+    pub torrent_bytes: Vec<u8>,
+    pub info_hash: String,
+    pub magnet: String,
+    pub name: String,
+    pub total_bytes: u64,
+    pub piece_length: u32,
+    pub piece_count: usize,
+}
+
+#[derive(Serialize)]
+struct MetaInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    announce: Option<String>,
+    #[serde(rename = "announce-list", skip_serializing_if = "Option::is_none")]
+    announce_list: Option<Vec<Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(rename = "created by")]
+    created_by: &'static str,
+    #[serde(rename = "creation date")]
+    creation_date: u64,
+    info: Info,
+    #[serde(rename = "url-list", skip_serializing_if = "Option::is_none")]
+    url_list: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct Info {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<InfoFile>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    length: Option<u64>,
+    name: String,
+    #[serde(rename = "piece length")]
+    piece_length: u32,
+    pieces: ByteBufOwned,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+}
+
+#[derive(Serialize)]
+struct InfoFile {
+    length: u64,
+    path: Vec<String>,
+}
+
+/// Piece length giving about TARGET_PIECES pieces for `total_bytes`
+pub fn auto_piece_length(total_bytes: u64) -> u32 {
+    (total_bytes / TARGET_PIECES)
+        .next_power_of_two()
+        .clamp(MIN_PIECE_LENGTH as u64, MAX_PIECE_LENGTH as u64) as u32
+}
+
+fn utf8_name(path: &Path) -> Result<String> {
+    let name = path.file_name().context(format!("Path has no name: {:?}", path))?;
+    name.to_str()
+        .map(String::from)
+        .context(format!("File name is not valid UTF-8: {:?}", path))
+}
+
+/// Every file under `dir`, sorted by path. Symlinks to files are followed,
+/// symlinks to folders are skipped so loops can't be walked forever.
+fn walk(dir: &Path, components: &mut Vec<String>, files: &mut Vec<ContentFile>) -> Result<()> {
+    let mut entries = std::fs::read_dir(dir)
+        .context(format!("Failed to read folder: {:?}", dir))?
+        .collect::<std::io::Result<Vec<_>>>()
+        .context(format!("Failed to read folder: {:?}", dir))?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        let metadata = if file_type.is_symlink() {
+            match std::fs::metadata(&path) {
+                Ok(metadata) if metadata.is_file() => metadata,
+                _ => continue,
+            }
+        } else {
+            entry.metadata()?
+        };
+
+        components.push(utf8_name(&path)?);
+        if metadata.is_dir() {
+            walk(&path, components, files)?;
+        } else if metadata.is_file() {
+            files.push(ContentFile {
+                path,
+                components: components.clone(),
+                length: metadata.len(),
+            });
+        }
+        components.pop();
+    }
+    Ok(())
+}
+
+impl Plan {
+    pub fn new(path: &Path, options: CreateTorrentOptions) -> Result<Self> {
+        let metadata = std::fs::metadata(path).context(format!("Not found: {:?}", path))?;
+        let files = if metadata.is_dir() {
+            let mut files = Vec::new();
+            walk(path, &mut Vec::new(), &mut files)?;
+            files
+        } else {
+            vec![ContentFile {
+                path: path.to_path_buf(),
+                components: Vec::new(),
+                length: metadata.len(),
+            }]
+        };
+        let total_bytes: u64 = files.iter().map(|f| f.length).sum();
+        if total_bytes == 0 {
+            bail!("Nothing to share: {:?} has no data", path);
+        }
+
+        let name = match options.name {
+            Some(name) => name,
+            None => utf8_name(path)?,
+        };
+        if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+            bail!("Invalid torrent name: {:?}", name);
+        }
+
+        let piece_length = match options.piece_length {
+            Some(length) if length.is_power_of_two() && (MIN_PIECE_LENGTH..=MAX_PIECE_LENGTH).contains(&length) => length,
+            Some(length) => bail!("Piece length must be a power of two from 16 KiB to 16 MiB: {}", length),
+            None => auto_piece_length(total_bytes),
+        };
+
+        let trackers = options.trackers.iter()
+            .map(|url| parse_tracker(url))
+            .collect::<Result<Vec<_>>>()?;
+        let web_seeds = options.web_seeds.iter()
+            .map(|url| match Url::parse(url.trim()) {
+                Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(parsed),
+                _ => bail!("Invalid web seed URL, expected http(s): {}", url),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Plan {
+            name,
+            files,
+            total_bytes,
+            piece_length,
+            trackers,
+            web_seeds,
+            comment: options.comment.filter(|c| !c.is_empty()),
+            private: options.private,
+            source: options.source.filter(|s| !s.is_empty()),
+        })
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// Hash the content and build the torrent
+    pub fn build(self, progress: &Progress) -> Result<Created> {
+        let pieces = self.hash_pieces(progress)?;
+        let piece_count = pieces.len() / 20;
+
+        let single_file = self.files.len() == 1 && self.files[0].components.is_empty();
+        let info = Info {
+            files: (!single_file).then(|| self.files.iter()
+                .map(|f| InfoFile { length: f.length, path: f.components.clone() })
+                .collect()),
+            length: single_file.then_some(self.total_bytes),
+            name: self.name.clone(),
+            piece_length: self.piece_length,
+            pieces: pieces.into(),
+            private: self.private.then_some(1),
+            source: self.source,
+        };
+        let mut info_bytes = Vec::new();
+        librqbit_bencode::bencode_serialize_to_writer(&info, &mut info_bytes)?;
+        let info_hash = hex::encode(Sha1::digest(&info_bytes));
+
+        let trackers: Vec<String> = self.trackers.iter().map(|t| t.to_string()).collect();
+        let web_seeds: Vec<String> = self.web_seeds.iter().map(|w| w.to_string()).collect();
+        let meta = MetaInfo {
+            announce: trackers.first().cloned(),
+            announce_list: (trackers.len() > 1).then(|| trackers.iter().map(|t| vec![t.clone()]).collect()),
+            comment: self.comment,
+            created_by: CREATED_BY,
+            creation_date: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+            info,
+            url_list: (!web_seeds.is_empty()).then(|| web_seeds.clone()),
+        };
+        let mut torrent_bytes = Vec::new();
+        librqbit_bencode::bencode_serialize_to_writer(&meta, &mut torrent_bytes)?;
+
+        let mut magnet = format!("magnet:?xt=urn:btih:{}&dn=", info_hash);
+        magnet.extend(url::form_urlencoded::byte_serialize(self.name.as_bytes()));
+        for (key, url) in trackers.iter().map(|t| ("tr", t)).chain(web_seeds.iter().map(|w| ("ws", w))) {
+            magnet.push_str(&format!("&{}=", key));
+            magnet.extend(url::form_urlencoded::byte_serialize(url.as_bytes()));
+        }
+
+        Ok(Created {
+            torrent_bytes,
+            info_hash,
+            magnet,
+            name: self.name,
+            total_bytes: self.total_bytes,
+            piece_length: self.piece_length,
+            piece_count,
+        })
+    }
+
+    /// SHA-1 of every piece, concatenated
+    fn hash_pieces(&self, progress: &Progress) -> Result<Vec<u8>> {
+        let piece_length = self.piece_length as u64;
+        let piece_count = self.total_bytes.div_ceil(piece_length);
+        let workers = std::thread::available_parallelism().map(|n| n.get() as u64).unwrap_or(1).min(piece_count);
+        let per_worker = piece_count.div_ceil(workers);
+
+        let results: Vec<Result<Vec<u8>>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    let pieces = worker * per_worker..((worker + 1) * per_worker).min(piece_count);
+                    scope.spawn(move || {
+                        let mut reader = ContentReader::new(&self.files);
+                        let mut hashes = Vec::with_capacity(pieces.clone().count() * 20);
+                        let mut buf = vec![0u8; self.piece_length as usize];
+                        for piece in pieces {
+                            if progress.cancelled.load(Ordering::Relaxed) {
+                                bail!("Torrent creation cancelled");
+                            }
+                            let offset = piece * piece_length;
+                            let length = piece_length.min(self.total_bytes - offset) as usize;
+                            reader.read_at(offset, &mut buf[..length])?;
+                            hashes.extend_from_slice(&Sha1::digest(&buf[..length]));
+                            progress.hashed_bytes.fetch_add(length as u64, Ordering::Relaxed);
+                        }
+                        Ok(hashes)
+                    })
+                })
+                .collect();
+            handles.into_iter()
+                .map(|h| h.join().unwrap_or_else(|_| Err(anyhow::anyhow!("Hashing thread panicked"))))
+                .collect()
+        });
+
+        let mut pieces = Vec::with_capacity(piece_count as usize * 20);
+        for result in results {
+            pieces.extend(result?);
+        }
+        Ok(pieces)
+    }
+}
+
+/// Reads the content as one stream of bytes, keeping the last file open
+struct ContentReader<'a> {
+    files: &'a [ContentFile],
+    open: Option<(usize, File)>,
+}
+
+impl<'a> ContentReader<'a> {
+    fn new(files: &'a [ContentFile]) -> Self {
+        ContentReader { files, open: None }
+    }
+
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+        let mut file_start = 0;
+        let mut done = 0;
+        for (index, content_file) in self.files.iter().enumerate() {
+            let file_end = file_start + content_file.length;
+            let pos = offset + done as u64;
+            if done < buf.len() && pos < file_end {
+                let len = ((file_end - pos) as usize).min(buf.len() - done);
+                if self.open.as_ref().is_none_or(|(open, _)| *open != index) {
+                    let file = File::open(&content_file.path)
+                        .context(format!("Failed to open file: {:?}", content_file.path))?;
+                    self.open = Some((index, file));
+                }
+                let (_, file) = self.open.as_mut().unwrap();
+                file.seek(SeekFrom::Start(pos - file_start))?;
+                // A file that shrank since it was listed fails here
+                file.read_exact(&mut buf[done..done + len])
+                    .context(format!("Failed to read file: {:?}", content_file.path))?;
+                done += len;
+            }
+            file_start = file_end;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librqbit::torrent_from_bytes;
+    use crate::test_util::content;
+
+    /// SHA-1 of every `piece_length` chunk of `data`, concatenated
+    fn piece_hashes(data: &[u8], piece_length: usize) -> Vec<u8> {
+        data.chunks(piece_length).flat_map(|chunk| Sha1::digest(chunk).to_vec()).collect()
+    }
+
+    fn create(path: &Path, options: CreateTorrentOptions) -> Created {
+        Plan::new(path, options).unwrap().build(&Progress::default()).unwrap()
+    }
+
+    fn text(buf: &ByteBufOwned) -> String {
+        String::from_utf8(buf.as_ref().to_vec()).unwrap()
+    }
+
+    #[test]
+    fn files_make_single_file_torrents() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = content(5 * 16 * 1024 + 123);
+        std::fs::write(dir.path().join("movie.bin"), &data).unwrap();
+
+        let created = create(&dir.path().join("movie.bin"), CreateTorrentOptions {
+            piece_length: Some(16 * 1024),
+            ..Default::default()
+        });
+        let torrent = torrent_from_bytes::<ByteBufOwned>(&created.torrent_bytes).unwrap();
+        let info = &torrent.info;
+        assert_eq!(info.name.as_ref().map(text).as_deref(), Some("movie.bin"));
+        assert_eq!(info.length, Some(data.len() as u64));
+        assert!(info.files.is_none());
+        assert_eq!(info.piece_length, 16 * 1024);
+        assert_eq!(info.pieces.as_ref(), piece_hashes(&data, 16 * 1024));
+        assert!(!info.private);
+        assert_eq!(created.info_hash, torrent.info_hash.as_string());
+        assert_eq!((created.piece_count, created.total_bytes), (6, data.len() as u64));
+    }
+
+    #[test]
+    fn folders_make_multi_file_torrents_in_path_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("album");
+        std::fs::create_dir_all(root.join("b")).unwrap();
+        // (path, length), in the order they are expected in the torrent
+        let files = [("a.txt", 20_000), ("b/c.bin", 0), ("b/d.bin", 30_001), ("e.txt", 5)];
+        let mut data = Vec::new();
+        for (index, (path, length)) in files.iter().enumerate() {
+            let file = content(*length).into_iter().map(|b| b.wrapping_add(index as u8)).collect::<Vec<_>>();
+            std::fs::write(root.join(path), &file).unwrap();
+            data.extend(file);
+        }
+
+        let created = create(&root, CreateTorrentOptions {
+            piece_length: Some(16 * 1024),
+            ..Default::default()
+        });
+        let torrent = torrent_from_bytes::<ByteBufOwned>(&created.torrent_bytes).unwrap();
+        let info = &torrent.info;
+        assert_eq!(info.name.as_ref().map(text).as_deref(), Some("album"));
+        assert_eq!(info.length, None);
+        let listed: Vec<(String, u64)> = info.files.as_ref().unwrap().iter()
+            .map(|f| (f.path.iter().map(text).collect::<Vec<_>>().join("/"), f.length))
+            .collect();
+        let expected: Vec<(String, u64)> = files.iter().map(|(p, l)| (p.to_string(), *l as u64)).collect();
+        assert_eq!(listed, expected);
+        // Pieces run across file boundaries
+        assert_eq!(info.pieces.as_ref(), piece_hashes(&data, 16 * 1024));
+    }
+
+    #[test]
+    fn pieces_are_hashed_in_parallel_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = content(300 * 16 * 1024 + 1);
+        std::fs::write(dir.path().join("big.bin"), &data).unwrap();
+
+        let progress = Progress::default();
+        let plan = Plan::new(&dir.path().join("big.bin"), CreateTorrentOptions {
+            piece_length: Some(16 * 1024),
+            ..Default::default()
+        }).unwrap();
+        let created = plan.build(&progress).unwrap();
+        let torrent = torrent_from_bytes::<ByteBufOwned>(&created.torrent_bytes).unwrap();
+        assert_eq!(torrent.info.pieces.as_ref(), piece_hashes(&data, 16 * 1024));
+        assert_eq!(created.piece_count, 301);
+        assert_eq!(progress.hashed_bytes.load(Ordering::Relaxed), data.len() as u64);
+
+        let cancelled = Progress::default();
+        cancelled.cancelled.store(true, Ordering::Relaxed);
+        let plan = Plan::new(&dir.path().join("big.bin"), Default::default()).unwrap();
+        assert!(plan.build(&cancelled).is_err());
+    }
+
+    #[test]
+    fn piece_lengths_are_clamped_powers_of_two() {
+        const KIB: u64 = 1024;
+        // (total bytes, piece length)
+        let cases = [
+            (0, 16 * KIB),
+            (1, 16 * KIB),
+            (1500 * 16 * KIB, 16 * KIB),
+            (1500 * 32 * KIB, 32 * KIB),
+            (1500 * 32 * KIB + 1500, 64 * KIB),
+            (1500 * 16 * 1024 * KIB, 16 * 1024 * KIB),
+            (u64::MAX / 2, 16 * 1024 * KIB),
+        ];
+        for (total, length) in cases {
+            assert_eq!(auto_piece_length(total) as u64, length, "{}", total);
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("small.bin");
+        std::fs::write(&path, content(10)).unwrap();
+        assert_eq!(create(&path, Default::default()).piece_length, 16 * 1024);
+        for length in [8 * 1024, 48 * 1024, 32 * 1024 * 1024] {
+            let options = CreateTorrentOptions { piece_length: Some(length), ..Default::default() };
+            assert!(Plan::new(&path, options).is_err(), "{}", length);
+        }
+    }
+
+    #[test]
+    fn trackers_and_web_seeds_reach_the_file_and_magnet() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("movie.bin");
+        std::fs::write(&path, content(1000)).unwrap();
+
+        let created = create(&path, CreateTorrentOptions {
+            name: Some("My movie".to_string()),
+            trackers: vec!["http://t1.example/announce".to_string(), "udp://t2.example:80".to_string()],
+            web_seeds: vec!["http://seed.example/files/".to_string()],
+            private: true,
+            ..Default::default()
+        });
+        assert_eq!(created.magnet, format!(
+            "magnet:?xt=urn:btih:{}&dn=My+movie&tr=http%3A%2F%2Ft1.example%2Fannounce&tr=udp%3A%2F%2Ft2.example%3A80&ws=http%3A%2F%2Fseed.example%2Ffiles%2F",
+            created.info_hash,
+        ));
+        let torrent = torrent_from_bytes::<ByteBufOwned>(&created.torrent_bytes).unwrap();
+        assert_eq!(torrent.info.name.as_ref().map(text).as_deref(), Some("My movie"));
+        assert!(torrent.info.private);
+        assert_eq!(torrent.announce.as_ref().map(text).as_deref(), Some("http://t1.example/announce"));
+        let tiers: Vec<Vec<String>> = torrent.announce_list.iter().map(|tier| tier.iter().map(text).collect()).collect();
+        assert_eq!(tiers, vec![vec!["http://t1.example/announce".to_string()], vec!["udp://t2.example:80".to_string()]]);
+
+        // A single tracker goes in announce alone
+        let created = create(&path, CreateTorrentOptions {
+            trackers: vec!["http://t1.example/announce".to_string()],
+            ..Default::default()
+        });
+        let torrent = torrent_from_bytes::<ByteBufOwned>(&created.torrent_bytes).unwrap();
+        assert!(torrent.announce_list.is_empty());
+        assert_eq!(created.magnet, format!(
+            "magnet:?xt=urn:btih:{}&dn=movie.bin&tr=http%3A%2F%2Ft1.example%2Fannounce",
+            created.info_hash,
+        ));
+    }
+}
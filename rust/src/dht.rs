@@ -0,0 +1,110 @@
+// DHT settings librqbit has no options for.
+//
+// librqbit bootstraps its DHT from built-in hostnames on a random port of
+// every interface, unless it restores a persisted routing table, which also
+// holds the listen address. With a DHT port, bootstrap nodes or a bind
+// address configured, a short-lived DHT is bootstrapped from those nodes
+// first, and its routing table is persisted with the configured address for
+// the session's DHT to start from.
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::path::Path;
+use std::time::Duration;
+use anyhow::{Context, Result};
+use librqbit::dht::{DhtBuilder, DhtConfig, DhtState, PersistentDhtConfig};
+use serde::{Deserialize, Serialize};
+use crate::api::config::session_config;
+use crate::{app_data_dir, interface};
+
+/// librqbit keeps it up to date while the session runs
+const DHT_FILE: &str = "dht.json";
+/// How long bootstrapping may take before the session starts anyway
+const BOOTSTRAP_TIME: Duration = Duration::from_secs(5);
+/// How long a stopped session may take to release the DHT port
+const PORT_RELEASE_TIME: Duration = Duration::from_secs(2);
+
+/// librqbit's persisted DHT. Its routing table type isn't exported, so it
+/// is left generic and inferred.
+#[derive(Serialize, Deserialize)]
+struct DhtFile<Table> {
+    addr: SocketAddr,
+    table: Table,
+    peer_store: Option<serde_json::Value>,
+}
+
+/// DHT persistence for a new session, or None to keep librqbit's defaults
+pub async fn persistence_config() -> Result<Option<PersistentDhtConfig>> {
+    let config = session_config();
+    let ip = interface::bind_ip().unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    if config.dht_port.is_none() && config.dht_bootstrap_nodes.is_empty() && ip.is_unspecified() {
+        return Ok(None);
+    }
+    let path = app_data_dir().join(DHT_FILE);
+
+    // Nodes learned by the previous session are kept
+    let previous = std::fs::read_to_string(&path).ok()
+        .and_then(|text| serde_json::from_str::<DhtFile<_>>(&text).ok());
+    let (routing_table, peer_store) = match previous {
+        Some(file) => (Some(file.table), file.peer_store),
+        None => (None, None),
+    };
+
+    let dht = DhtBuilder::with_config(DhtConfig {
+        routing_table,
+        bootstrap_addrs: Some(config.dht_bootstrap_nodes.clone()),
+        listen_addr: Some(SocketAddr::new(ip, 0)),
+        ..Default::default()
+    }).await.context("Failed to bootstrap the DHT")?;
+    if !config.dht_bootstrap_nodes.is_empty() {
+        wait_for_bootstrap(&dht).await;
+    }
+    let table = dht.clone_routing_table();
+    dht.cancellation_token().cancel();
+
+    let port = config.dht_port.unwrap_or(0);
+    wait_for_port(ip, port).await;
+    let file = DhtFile {
+        addr: SocketAddr::new(ip, port),
+        table,
+        peer_store,
+    };
+    write_file(&path, &serde_json::to_string(&file)?)?;
+
+    Ok(Some(PersistentDhtConfig {
+        config_filename: Some(path),
+        dump_interval: None,
+    }))
+}
+
+fn write_file(path: &Path, text: &str) -> Result<()> {
+    std::fs::write(path, text).context(format!("Failed to write {:?}", path))
+}
+
+/// Wait until the routing table stops growing, up to BOOTSTRAP_TIME
+async fn wait_for_bootstrap(dht: &DhtState) {
+    let interval = Duration::from_millis(250);
+    let mut nodes = 0;
+    let mut stable = 0;
+    for _ in 0..BOOTSTRAP_TIME.as_millis() / interval.as_millis() {
+        tokio::time::sleep(interval).await;
+        let now = dht.stats().routing_table_size;
+        stable = if now > 0 && now == nodes { stable + 1 } else { 0 };
+        nodes = now;
+        if stable >= 4 {
+            break;
+        }
+    }
+}
+
+/// Wait for the previous session's DHT to close its socket on `port`
+async fn wait_for_port(ip: IpAddr, port: u16) {
+    if port == 0 {
+        return;
+    }
+    let interval = Duration::from_millis(100);
+    for _ in 0..PORT_RELEASE_TIME.as_millis() / interval.as_millis() {
+        if UdpSocket::bind((ip, port)).is_ok() {
+            return;
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
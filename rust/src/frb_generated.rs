@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -137581715;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -989607099;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__simple__fetch_magnet_metadata_impl(
+fn wire__crate__api__peers__add_peer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__simple__fetch_magnet_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_magnet_metadata",
+            debug_name: "add_peer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,17 +67,13 @@ fn wire__crate__api__simple__fetch_magnet_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_magnet_uri = <String>::sse_decode(&mut deserializer);
-            let api_timeout_secs = <u32>::sse_decode(&mut deserializer);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::simple::fetch_magnet_metadata(
-                            api_magnet_uri,
-                            api_timeout_secs,
-                        )
-                        .await?;
+                        let output_ok = crate::api::peers::add_peer(api_id, api_address).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -86,7 +82,71 @@ fn wire__crate__api__simple__fetch_magnet_metadata_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_torrent_info_file_impl(
+fn wire__crate__api__simple__add_request_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_request_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::AddRequest::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__add_result_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_result_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::AddResult::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__add_torrents_batch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -94,7 +154,7 @@ fn wire__crate__api__simple__get_torrent_info_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_torrent_info_file",
+            debug_name: "add_torrents_batch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -108,12 +168,13 @@ fn wire__crate__api__simple__get_torrent_info_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_requests = <Vec<crate::api::simple::AddRequest>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::simple::get_torrent_info_file(api_path).await?;
+                        let output_ok =
+                            crate::api::simple::add_torrents_batch(api_requests).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -122,7 +183,7 @@ fn wire__crate__api__simple__get_torrent_info_file_impl(
         },
     )
 }
-fn wire__crate__api__simple__get_torrents_impl(
+fn wire__crate__api__trackers__add_trackers_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -130,7 +191,7 @@ fn wire__crate__api__simple__get_torrents_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_torrents",
+            debug_name: "add_trackers",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -144,11 +205,14 @@ fn wire__crate__api__simple__get_torrents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::simple::get_torrents().await?;
+                        let output_ok =
+                            crate::api::trackers::add_trackers(api_id, api_urls).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -157,15 +221,15 @@ fn wire__crate__api__simple__get_torrents_impl(
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__peers__ban_peer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "ban_peer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -179,19 +243,21 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::peers::ban_peer(api_address).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__parse_magnet_impl(
+fn wire__crate__api__peers__clear_ip_filter_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -199,7 +265,7 @@ fn wire__crate__api__simple__parse_magnet_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_magnet",
+            debug_name: "clear_ip_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -213,12 +279,11 @@ fn wire__crate__api__simple__parse_magnet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_uri = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::simple::parse_magnet(api_uri)?;
+                        let output_ok = crate::api::peers::clear_ip_filter()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -226,7 +291,7 @@ fn wire__crate__api__simple__parse_magnet_impl(
         },
     )
 }
-fn wire__crate__api__simple__start_download_impl(
+fn wire__crate__api__create__create_torrent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -234,7 +299,7 @@ fn wire__crate__api__simple__start_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_download",
+            debug_name: "create_torrent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -248,22 +313,21 @@ fn wire__crate__api__simple__start_download_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_source = <String>::sse_decode(&mut deserializer);
-            let api_output_dir = <String>::sse_decode(&mut deserializer);
-            let api_stream_sink = <StreamSink<
-                crate::api::simple::AppTorrentStatus,
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::create::CreateTorrentOptions>::sse_decode(&mut deserializer);
+            let api_progress_sink = <StreamSink<
+                crate::api::create::CreateTorrentProgress,
                 flutter_rust_bridge::for_generated::SseCodec,
             >>::sse_decode(&mut deserializer);
-            let api__selected_file_indices = <Option<Vec<usize>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::simple::start_download(
-                            api_source,
-                            api_output_dir,
-                            api_stream_sink,
-                            api__selected_file_indices,
+                        let output_ok = crate::api::create::create_torrent(
+                            api_path,
+                            api_options,
+                            api_progress_sink,
                         )
                         .await?;
                         Ok(output_ok)
@@ -274,358 +338,2997 @@ fn wire__crate__api__simple__start_download_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::simple::AppTorrentStatus,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__create__create_torrent_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_torrent_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::create::CreateTorrentOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__network__dht_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dht_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::network::dht_stats().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::api::simple::AppTorrentStatus {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalPieces = <u32>::sse_decode(deserializer);
-        let mut var_completedPieces = <u32>::sse_decode(deserializer);
-        let mut var_peers = <u32>::sse_decode(deserializer);
-        let mut var_speedMbps = <f64>::sse_decode(deserializer);
-        let mut var_downloading = <bool>::sse_decode(deserializer);
-        let mut var_isFetchingMetadata = <bool>::sse_decode(deserializer);
-        let mut var_statusMessage = <String>::sse_decode(deserializer);
-        let mut var_error = <Option<String>>::sse_decode(deserializer);
-        let mut var_totalBytes = <u64>::sse_decode(deserializer);
-        let mut var_downloadedBytes = <u64>::sse_decode(deserializer);
-        return crate::api::simple::AppTorrentStatus {
-            total_pieces: var_totalPieces,
-            completed_pieces: var_completedPieces,
-            peers: var_peers,
-            speed_mbps: var_speedMbps,
-            downloading: var_downloading,
-            is_fetching_metadata: var_isFetchingMetadata,
-            status_message: var_statusMessage,
-            error: var_error,
-            total_bytes: var_totalBytes,
-            downloaded_bytes: var_downloadedBytes,
-        };
-    }
+fn wire__crate__api__config__encryption_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encryption_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::config::EncryptionPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__simple__fetch_magnet_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fetch_magnet_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_magnet_uri = <String>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <u32>::sse_decode(&mut deserializer);
+            let api_use_default_trackers = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::fetch_magnet_metadata(
+                            api_magnet_uri,
+                            api_timeout_secs,
+                            api_use_default_trackers,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__file_priority_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "file_priority_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::FilePriority::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__trackers__force_reannounce_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "force_reannounce",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::trackers::force_reannounce(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_file_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_file_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::get_file_progress(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__peers__get_peers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_peers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::peers::get_peers(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__config__get_session_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_session_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::config::get_session_config())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_torrent_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_torrent_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::get_torrent_files(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_torrent_info_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_torrent_info_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::get_torrent_info_file(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_torrents_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_torrents",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::get_torrents().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trackers__get_trackers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_trackers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::trackers::get_trackers(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trackers__get_web_seeds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_web_seeds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::trackers::get_web_seeds(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__peers__ip_filter_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ip_filter_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::peers::ip_filter_stats())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__peers__ip_filter_stats_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ip_filter_stats_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::peers::IpFilterStats::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__peers__list_bans_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_bans",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::peers::list_bans())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__peers__load_ip_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_ip_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::peers::load_ip_filter(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__meta_version_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "meta_version_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::MetaVersion::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__move_storage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_storage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_new_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::move_storage(api_id, api_new_dir).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network__network_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "network_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::network::network_status().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__parse_magnet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_magnet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_uri = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::parse_magnet(api_uri)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__config__proxy_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "proxy_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::config::ProxyConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__config__proxy_type_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "proxy_type_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::config::ProxyType::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__recheck_torrent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recheck_torrent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::recheck_torrent(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trackers__remove_tracker_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_tracker",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::trackers::remove_tracker(api_id, api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__config__restart_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restart_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::config::restart_session().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__seed_local_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "seed_local",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_torrent_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_content_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::seed_local(api_torrent_bytes, api_content_path)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__config__session_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "session_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::config::SessionConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_file_priorities_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_file_priorities",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_priorities =
+                <Vec<(usize, crate::api::simple::FilePriority)>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::set_file_priorities(api_id, api_priorities).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_sequential_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_sequential_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_file_index = <Option<usize>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::set_sequential_download(
+                            api_id,
+                            api_enabled,
+                            api_file_index,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__config__set_session_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_session_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::config::SessionConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::config::set_session_config(api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__start_download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_stream_sink = <StreamSink<
+                crate::api::simple::AppTorrentStatus,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_selected_file_indices = <Option<Vec<usize>>>::sse_decode(&mut deserializer);
+            let api_use_existing_data_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_use_default_trackers = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::start_download(
+                            api_source,
+                            api_output_dir,
+                            api_stream_sink,
+                            api_selected_file_indices,
+                            api_use_existing_data_path,
+                            api_use_default_trackers,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__stream_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <usize>::sse_decode(&mut deserializer);
+            let api_file_index = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::stream_file(api_id, api_file_index).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__network__test_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_proxy = <crate::api::config::ProxyConfig>::sse_decode(&mut deserializer);
+            let api_target = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::network::test_proxy(api_proxy, api_target).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__peers__unban_peer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unban_peer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::peers::unban_peer(api_address)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::simple::AppTorrentStatus,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::create::CreateTorrentProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::simple::AddRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        let mut var_torrentBytes = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_outputDir = <String>::sse_decode(deserializer);
+        let mut var_selectedFileIndices = <Option<Vec<usize>>>::sse_decode(deserializer);
+        let mut var_useDefaultTrackers = <bool>::sse_decode(deserializer);
+        return crate::api::simple::AddRequest {
+            source: var_source,
+            torrent_bytes: var_torrentBytes,
+            output_dir: var_outputDir,
+            selected_file_indices: var_selectedFileIndices,
+            use_default_trackers: var_useDefaultTrackers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::AddResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<usize>>::sse_decode(deserializer);
+        let mut var_infoHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_alreadyAdded = <bool>::sse_decode(deserializer);
+        let mut var_queued = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::simple::AddResult {
+            id: var_id,
+            info_hash: var_infoHash,
+            name: var_name,
+            already_added: var_alreadyAdded,
+            queued: var_queued,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::AppTorrentStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <usize>::sse_decode(deserializer);
+        let mut var_totalPieces = <u32>::sse_decode(deserializer);
+        let mut var_completedPieces = <u32>::sse_decode(deserializer);
+        let mut var_peers = <u32>::sse_decode(deserializer);
+        let mut var_speedMbps = <f64>::sse_decode(deserializer);
+        let mut var_webSeedSpeedMbps = <f64>::sse_decode(deserializer);
+        let mut var_downloading = <bool>::sse_decode(deserializer);
+        let mut var_isFetchingMetadata = <bool>::sse_decode(deserializer);
+        let mut var_isChecking = <bool>::sse_decode(deserializer);
+        let mut var_isMoving = <bool>::sse_decode(deserializer);
+        let mut var_isSequential = <bool>::sse_decode(deserializer);
+        let mut var_isNetworkPaused = <bool>::sse_decode(deserializer);
+        let mut var_isQueued = <bool>::sse_decode(deserializer);
+        let mut var_statusMessage = <String>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_downloadedBytes = <u64>::sse_decode(deserializer);
+        let mut var_webSeedDownloadedBytes = <u64>::sse_decode(deserializer);
+        let mut var_files =
+            <Option<Vec<crate::api::simple::FileProgress>>>::sse_decode(deserializer);
+        return crate::api::simple::AppTorrentStatus {
+            id: var_id,
+            total_pieces: var_totalPieces,
+            completed_pieces: var_completedPieces,
+            peers: var_peers,
+            speed_mbps: var_speedMbps,
+            web_seed_speed_mbps: var_webSeedSpeedMbps,
+            downloading: var_downloading,
+            is_fetching_metadata: var_isFetchingMetadata,
+            is_checking: var_isChecking,
+            is_moving: var_isMoving,
+            is_sequential: var_isSequential,
+            is_network_paused: var_isNetworkPaused,
+            is_queued: var_isQueued,
+            status_message: var_statusMessage,
+            error: var_error,
+            total_bytes: var_totalBytes,
+            downloaded_bytes: var_downloadedBytes,
+            web_seed_downloaded_bytes: var_webSeedDownloadedBytes,
+            files: var_files,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::create::CreateTorrentOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_pieceLength = <Option<u32>>::sse_decode(deserializer);
+        let mut var_trackers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_webSeeds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        let mut var_private = <bool>::sse_decode(deserializer);
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        return crate::api::create::CreateTorrentOptions {
+            name: var_name,
+            piece_length: var_pieceLength,
+            trackers: var_trackers,
+            web_seeds: var_webSeeds,
+            comment: var_comment,
+            private: var_private,
+            source: var_source,
+        };
+    }
+}
+
+impl SseDecode for crate::api::create::CreateTorrentProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hashedBytes = <u64>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_torrent =
+            <Option<crate::api::create::CreatedTorrent>>::sse_decode(deserializer);
+        return crate::api::create::CreateTorrentProgress {
+            hashed_bytes: var_hashedBytes,
+            total_bytes: var_totalBytes,
+            torrent: var_torrent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::create::CreatedTorrent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_torrentBytes = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_magnet = <String>::sse_decode(deserializer);
+        let mut var_infoHash = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_totalSize = <u64>::sse_decode(deserializer);
+        let mut var_pieceLength = <u32>::sse_decode(deserializer);
+        let mut var_pieceCount = <usize>::sse_decode(deserializer);
+        return crate::api::create::CreatedTorrent {
+            torrent_bytes: var_torrentBytes,
+            magnet: var_magnet,
+            info_hash: var_infoHash,
+            name: var_name,
+            total_size: var_totalSize,
+            piece_length: var_pieceLength,
+            piece_count: var_pieceCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::network::DhtBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_prefixBits = <u8>::sse_decode(deserializer);
+        let mut var_nodes = <u32>::sse_decode(deserializer);
+        return crate::api::network::DhtBucket {
+            prefix_bits: var_prefixBits,
+            nodes: var_nodes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::network::DhtStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_listenPort = <u16>::sse_decode(deserializer);
+        let mut var_nodes = <u32>::sse_decode(deserializer);
+        let mut var_buckets = <Vec<crate::api::network::DhtBucket>>::sse_decode(deserializer);
+        let mut var_outstandingQueries = <u32>::sse_decode(deserializer);
+        return crate::api::network::DhtStats {
+            enabled: var_enabled,
+            node_id: var_nodeId,
+            listen_port: var_listenPort,
+            nodes: var_nodes,
+            buckets: var_buckets,
+            outstanding_queries: var_outstandingQueries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::config::EncryptionPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::config::EncryptionPolicy::Disabled,
+            1 => crate::api::config::EncryptionPolicy::Prefer,
+            2 => crate::api::config::EncryptionPolicy::Require,
+            _ => unreachable!("Invalid variant for EncryptionPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::simple::FileInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <usize>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_size = <usize>::sse_decode(deserializer);
+        let mut var_originalPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_priority = <crate::api::simple::FilePriority>::sse_decode(deserializer);
+        let mut var_downloadedBytes = <u64>::sse_decode(deserializer);
+        let mut var_merkleRoot = <Option<String>>::sse_decode(deserializer);
+        return crate::api::simple::FileInfo {
+            index: var_index,
+            path: var_path,
+            size: var_size,
+            original_path: var_originalPath,
+            priority: var_priority,
+            downloaded_bytes: var_downloadedBytes,
+            merkle_root: var_merkleRoot,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::FilePriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::FilePriority::Skip,
+            1 => crate::api::simple::FilePriority::Low,
+            2 => crate::api::simple::FilePriority::Normal,
+            3 => crate::api::simple::FilePriority::High,
+            _ => unreachable!("Invalid variant for FilePriority: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::FileProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <usize>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_downloaded = <u64>::sse_decode(deserializer);
+        let mut var_complete = <bool>::sse_decode(deserializer);
+        return crate::api::simple::FileProgress {
+            index: var_index,
+            path: var_path,
+            size: var_size,
+            downloaded: var_downloaded,
+            complete: var_complete,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::peers::IpFilterStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_entries = <usize>::sse_decode(deserializer);
+        let mut var_allowedEntries = <usize>::sse_decode(deserializer);
+        let mut var_invalidLines = <usize>::sse_decode(deserializer);
+        let mut var_ipv4Ranges = <usize>::sse_decode(deserializer);
+        let mut var_ipv6Ranges = <usize>::sse_decode(deserializer);
+        let mut var_ipv4Addresses = <u64>::sse_decode(deserializer);
+        let mut var_active = <bool>::sse_decode(deserializer);
+        return crate::api::peers::IpFilterStats {
+            source: var_source,
+            format: var_format,
+            entries: var_entries,
+            allowed_entries: var_allowedEntries,
+            invalid_lines: var_invalidLines,
+            ipv4_ranges: var_ipv4Ranges,
+            ipv6_ranges: var_ipv6Ranges,
+            ipv4_addresses: var_ipv4Addresses,
+            active: var_active,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::AddRequest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::AddRequest>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::AddResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::AddResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::AppTorrentStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::AppTorrentStatus>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::network::DhtBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::network::DhtBucket>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::FileInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::FileInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::FileProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::FileProgress>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::peers::PeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::peers::PeerInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::trackers::PeerSourceResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::trackers::PeerSourceResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<usize>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(usize, crate::api::simple::FilePriority)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(usize, crate::api::simple::FilePriority)>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::trackers::TrackerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::trackers::TrackerInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::V2FileInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::V2FileInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::trackers::WebSeedInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::trackers::WebSeedInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::simple::MagnetInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_infoHash = <String>::sse_decode(deserializer);
+        let mut var_infoHashV2 = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_webSeeds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::MagnetInfo {
+            url: var_url,
+            info_hash: var_infoHash,
+            info_hash_v2: var_infoHashV2,
+            name: var_name,
+            trackers: var_trackers,
+            web_seeds: var_webSeeds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::MetaVersion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::MetaVersion::V1,
+            1 => crate::api::simple::MetaVersion::V2,
+            2 => crate::api::simple::MetaVersion::Hybrid,
+            _ => unreachable!("Invalid variant for MetaVersion: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::network::NetworkStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_listenAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_portMapping = <Option<String>>::sse_decode(deserializer);
+        let mut var_externalAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_externalPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_mappingError = <Option<String>>::sse_decode(deserializer);
+        let mut var_incomingSeen = <bool>::sse_decode(deserializer);
+        let mut var_interfaceUp = <bool>::sse_decode(deserializer);
+        let mut var_bindAddress = <Option<String>>::sse_decode(deserializer);
+        return crate::api::network::NetworkStatus {
+            listen_address: var_listenAddress,
+            port_mapping: var_portMapping,
+            external_address: var_externalAddress,
+            external_port: var_externalPort,
+            mapping_error: var_mappingError,
+            incoming_seen: var_incomingSeen,
+            interface_up: var_interfaceUp,
+            bind_address: var_bindAddress,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::create::CreatedTorrent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::create::CreatedTorrent>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::config::ProxyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::config::ProxyConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::simple::FileProgress>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::simple::FileProgress>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<usize>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<usize>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::peers::PeerBan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_disconnected = <u32>::sse_decode(deserializer);
+        let mut var_active = <bool>::sse_decode(deserializer);
+        return crate::api::peers::PeerBan {
+            disconnected: var_disconnected,
+            active: var_active,
+        };
+    }
+}
+
+impl SseDecode for crate::api::peers::PeerDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::peers::PeerDirection::Incoming,
+            1 => crate::api::peers::PeerDirection::Outgoing,
+            _ => unreachable!("Invalid variant for PeerDirection: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::peers::PeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_direction = <crate::api::peers::PeerDirection>::sse_decode(deserializer);
+        let mut var_encrypted = <bool>::sse_decode(deserializer);
+        let mut var_downloadBps = <u64>::sse_decode(deserializer);
+        let mut var_downloadedBytes = <u64>::sse_decode(deserializer);
+        let mut var_piecesDownloaded = <u32>::sse_decode(deserializer);
+        let mut var_errors = <u32>::sse_decode(deserializer);
+        return crate::api::peers::PeerInfo {
+            address: var_address,
+            direction: var_direction,
+            encrypted: var_encrypted,
+            download_bps: var_downloadBps,
+            downloaded_bytes: var_downloadedBytes,
+            pieces_downloaded: var_piecesDownloaded,
+            errors: var_errors,
+        };
+    }
+}
+
+impl SseDecode for crate::api::trackers::PeerSourceResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_peers = <Option<u32>>::sse_decode(deserializer);
+        let mut var_newPeers = <Option<u32>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::trackers::PeerSourceResult {
+            source: var_source,
+            peers: var_peers,
+            new_peers: var_newPeers,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::config::ProxyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_proxyType = <crate::api::config::ProxyType>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        let mut var_proxyPeers = <bool>::sse_decode(deserializer);
+        let mut var_proxyTrackers = <bool>::sse_decode(deserializer);
+        return crate::api::config::ProxyConfig {
+            proxy_type: var_proxyType,
+            host: var_host,
+            port: var_port,
+            username: var_username,
+            password: var_password,
+            proxy_peers: var_proxyPeers,
+            proxy_trackers: var_proxyTrackers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::config::ProxyType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::config::ProxyType::Socks5,
+            1 => crate::api::config::ProxyType::Http,
+            _ => unreachable!("Invalid variant for ProxyType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::trackers::ReannounceSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sources =
+            <Vec<crate::api::trackers::PeerSourceResult>>::sse_decode(deserializer);
+        let mut var_newPeers = <u32>::sse_decode(deserializer);
+        return crate::api::trackers::ReannounceSummary {
+            sources: var_sources,
+            new_peers: var_newPeers,
+        };
+    }
+}
+
+impl SseDecode for (usize, crate::api::simple::FilePriority) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <usize>::sse_decode(deserializer);
+        let mut var_field1 = <crate::api::simple::FilePriority>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
 }
 
-impl SseDecode for f64 {
+impl SseDecode for crate::api::config::SessionConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+        let mut var_incompleteDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_completeDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_partSuffix = <bool>::sse_decode(deserializer);
+        let mut var_includeFileProgress = <bool>::sse_decode(deserializer);
+        let mut var_defaultTrackers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_defaultTrackersFile = <Option<String>>::sse_decode(deserializer);
+        let mut var_dhtPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_dhtBootstrapNodes = <Vec<String>>::sse_decode(deserializer);
+        let mut var_listenPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_listenPortMax = <Option<u16>>::sse_decode(deserializer);
+        let mut var_upnp = <bool>::sse_decode(deserializer);
+        let mut var_natpmp = <bool>::sse_decode(deserializer);
+        let mut var_outgoingInterface = <Option<String>>::sse_decode(deserializer);
+        let mut var_bindAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_proxy = <Option<crate::api::config::ProxyConfig>>::sse_decode(deserializer);
+        let mut var_encryption = <crate::api::config::EncryptionPolicy>::sse_decode(deserializer);
+        let mut var_maxActiveDownloads = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::config::SessionConfig {
+            incomplete_dir: var_incompleteDir,
+            complete_dir: var_completeDir,
+            part_suffix: var_partSuffix,
+            include_file_progress: var_includeFileProgress,
+            default_trackers: var_defaultTrackers,
+            default_trackers_file: var_defaultTrackersFile,
+            dht_port: var_dhtPort,
+            dht_bootstrap_nodes: var_dhtBootstrapNodes,
+            listen_port: var_listenPort,
+            listen_port_max: var_listenPortMax,
+            upnp: var_upnp,
+            natpmp: var_natpmp,
+            outgoing_interface: var_outgoingInterface,
+            bind_address: var_bindAddress,
+            proxy: var_proxy,
+            encryption: var_encryption,
+            max_active_downloads: var_maxActiveDownloads,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::TorrentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_totalSize = <usize>::sse_decode(deserializer);
+        let mut var_pieceCount = <usize>::sse_decode(deserializer);
+        let mut var_pieceLength = <usize>::sse_decode(deserializer);
+        let mut var_files = <Vec<crate::api::simple::FileInfo>>::sse_decode(deserializer);
+        let mut var_infoHash = <String>::sse_decode(deserializer);
+        let mut var_infoHashV2 = <Option<String>>::sse_decode(deserializer);
+        let mut var_metaVersion = <crate::api::simple::MetaVersion>::sse_decode(deserializer);
+        let mut var_announce = <String>::sse_decode(deserializer);
+        let mut var_webSeeds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_fileTree = <Vec<crate::api::simple::V2FileInfo>>::sse_decode(deserializer);
+        return crate::api::simple::TorrentInfo {
+            name: var_name,
+            total_size: var_totalSize,
+            piece_count: var_pieceCount,
+            piece_length: var_pieceLength,
+            files: var_files,
+            info_hash: var_infoHash,
+            info_hash_v2: var_infoHashV2,
+            meta_version: var_metaVersion,
+            announce: var_announce,
+            web_seeds: var_webSeeds,
+            file_tree: var_fileTree,
+        };
+    }
+}
+
+impl SseDecode for crate::api::trackers::TrackerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_tier = <usize>::sse_decode(deserializer);
+        let mut var_lastUpdate = <Option<u64>>::sse_decode(deserializer);
+        let mut var_nextUpdate = <Option<u64>>::sse_decode(deserializer);
+        let mut var_isScraped = <bool>::sse_decode(deserializer);
+        let mut var_seeders = <Option<u64>>::sse_decode(deserializer);
+        let mut var_leechers = <Option<u64>>::sse_decode(deserializer);
+        let mut var_completed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_peers = <Option<u32>>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        let mut var_isUpdating = <bool>::sse_decode(deserializer);
+        return crate::api::trackers::TrackerInfo {
+            url: var_url,
+            tier: var_tier,
+            last_update: var_lastUpdate,
+            next_update: var_nextUpdate,
+            is_scraped: var_isScraped,
+            seeders: var_seeders,
+            leechers: var_leechers,
+            completed: var_completed,
+            peers: var_peers,
+            last_error: var_lastError,
+            is_updating: var_isUpdating,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::simple::V2FileInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_merkleRoot = <Option<String>>::sse_decode(deserializer);
+        return crate::api::simple::V2FileInfo {
+            path: var_path,
+            size: var_size,
+            merkle_root: var_merkleRoot,
+        };
+    }
+}
+
+impl SseDecode for crate::api::trackers::WebSeedInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_downloadedBytes = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::trackers::WebSeedInfo {
+            url: var_url,
+            downloaded_bytes: var_downloadedBytes,
+            last_error: var_lastError,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__peers__add_peer_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__simple__add_request_default_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__simple__add_result_default_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__simple__add_torrents_batch_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__trackers__add_trackers_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__peers__ban_peer_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__peers__clear_ip_filter_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__create__create_torrent_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__create__create_torrent_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__network__dht_stats_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__config__encryption_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => {
+            wire__crate__api__simple__fetch_magnet_metadata_impl(port, ptr, rust_vec_len, data_len)
+        }
+        13 => {
+            wire__crate__api__simple__file_priority_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        14 => wire__crate__api__trackers__force_reannounce_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__simple__get_file_progress_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__peers__get_peers_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__config__get_session_config_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__simple__get_torrent_files_impl(port, ptr, rust_vec_len, data_len),
+        19 => {
+            wire__crate__api__simple__get_torrent_info_file_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__simple__get_torrents_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__trackers__get_trackers_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__trackers__get_web_seeds_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__peers__ip_filter_stats_impl(port, ptr, rust_vec_len, data_len),
+        25 => {
+            wire__crate__api__peers__ip_filter_stats_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__peers__list_bans_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__peers__load_ip_filter_impl(port, ptr, rust_vec_len, data_len),
+        28 => {
+            wire__crate__api__simple__meta_version_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        29 => wire__crate__api__simple__move_storage_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__network__network_status_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__simple__parse_magnet_impl(port, ptr, rust_vec_len, data_len),
+        32 => {
+            wire__crate__api__config__proxy_config_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__api__config__proxy_type_default_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__simple__recheck_torrent_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__trackers__remove_tracker_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__config__restart_session_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__simple__seed_local_impl(port, ptr, rust_vec_len, data_len),
+        38 => {
+            wire__crate__api__config__session_config_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__simple__set_file_priorities_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__simple__set_sequential_download_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__config__set_session_config_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__simple__start_download_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__simple__stream_file_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__network__test_proxy_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__peers__unban_peer_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AddRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.torrent_bytes.into_into_dart().into_dart(),
+            self.output_dir.into_into_dart().into_dart(),
+            self.selected_file_indices.into_into_dart().into_dart(),
+            self.use_default_trackers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::AddRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AddRequest>
+    for crate::api::simple::AddRequest
+{
+    fn into_into_dart(self) -> crate::api::simple::AddRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AddResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.info_hash.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.already_added.into_into_dart().into_dart(),
+            self.queued.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::AddResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AddResult>
+    for crate::api::simple::AddResult
+{
+    fn into_into_dart(self) -> crate::api::simple::AddResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AppTorrentStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.total_pieces.into_into_dart().into_dart(),
+            self.completed_pieces.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+            self.speed_mbps.into_into_dart().into_dart(),
+            self.web_seed_speed_mbps.into_into_dart().into_dart(),
+            self.downloading.into_into_dart().into_dart(),
+            self.is_fetching_metadata.into_into_dart().into_dart(),
+            self.is_checking.into_into_dart().into_dart(),
+            self.is_moving.into_into_dart().into_dart(),
+            self.is_sequential.into_into_dart().into_dart(),
+            self.is_network_paused.into_into_dart().into_dart(),
+            self.is_queued.into_into_dart().into_dart(),
+            self.status_message.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.downloaded_bytes.into_into_dart().into_dart(),
+            self.web_seed_downloaded_bytes.into_into_dart().into_dart(),
+            self.files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::AppTorrentStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AppTorrentStatus>
+    for crate::api::simple::AppTorrentStatus
+{
+    fn into_into_dart(self) -> crate::api::simple::AppTorrentStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::create::CreateTorrentOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.piece_length.into_into_dart().into_dart(),
+            self.trackers.into_into_dart().into_dart(),
+            self.web_seeds.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.private.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::create::CreateTorrentOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::create::CreateTorrentOptions>
+    for crate::api::create::CreateTorrentOptions
+{
+    fn into_into_dart(self) -> crate::api::create::CreateTorrentOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::create::CreateTorrentProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hashed_bytes.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.torrent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::create::CreateTorrentProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::create::CreateTorrentProgress>
+    for crate::api::create::CreateTorrentProgress
+{
+    fn into_into_dart(self) -> crate::api::create::CreateTorrentProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::create::CreatedTorrent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.torrent_bytes.into_into_dart().into_dart(),
+            self.magnet.into_into_dart().into_dart(),
+            self.info_hash.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.total_size.into_into_dart().into_dart(),
+            self.piece_length.into_into_dart().into_dart(),
+            self.piece_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::create::CreatedTorrent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::create::CreatedTorrent>
+    for crate::api::create::CreatedTorrent
+{
+    fn into_into_dart(self) -> crate::api::create::CreatedTorrent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network::DhtBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.prefix_bits.into_into_dart().into_dart(),
+            self.nodes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network::DhtBucket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network::DhtBucket>
+    for crate::api::network::DhtBucket
+{
+    fn into_into_dart(self) -> crate::api::network::DhtBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network::DhtStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.node_id.into_into_dart().into_dart(),
+            self.listen_port.into_into_dart().into_dart(),
+            self.nodes.into_into_dart().into_dart(),
+            self.buckets.into_into_dart().into_dart(),
+            self.outstanding_queries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::simple::FileInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_size = <usize>::sse_decode(deserializer);
-        return crate::api::simple::FileInfo {
-            path: var_path,
-            size: var_size,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::network::DhtStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network::DhtStats>
+    for crate::api::network::DhtStats
+{
+    fn into_into_dart(self) -> crate::api::network::DhtStats {
+        self
     }
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::config::EncryptionPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Disabled => 0.into_dart(),
+            Self::Prefer => 1.into_dart(),
+            Self::Require => 2.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::simple::AppTorrentStatus> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::AppTorrentStatus>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::config::EncryptionPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::config::EncryptionPolicy>
+    for crate::api::config::EncryptionPolicy
+{
+    fn into_into_dart(self) -> crate::api::config::EncryptionPolicy {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::simple::FileInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::FileInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FileInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.original_path.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.downloaded_bytes.into_into_dart().into_dart(),
+            self.merkle_root.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::FileInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FileInfo>
+    for crate::api::simple::FileInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::FileInfo {
+        self
     }
 }
-
-impl SseDecode for Vec<usize> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<usize>::sse_decode(deserializer));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FilePriority {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Skip => 0.into_dart(),
+            Self::Low => 1.into_dart(),
+            Self::Normal => 2.into_dart(),
+            Self::High => 3.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for crate::api::simple::MagnetInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_url = <String>::sse_decode(deserializer);
-        let mut var_infoHash = <String>::sse_decode(deserializer);
-        let mut var_name = <Option<String>>::sse_decode(deserializer);
-        let mut var_trackers = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::simple::MagnetInfo {
-            url: var_url,
-            info_hash: var_infoHash,
-            name: var_name,
-            trackers: var_trackers,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::FilePriority
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FilePriority>
+    for crate::api::simple::FilePriority
+{
+    fn into_into_dart(self) -> crate::api::simple::FilePriority {
+        self
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FileProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.downloaded.into_into_dart().into_dart(),
+            self.complete.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<Vec<usize>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<usize>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::FileProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FileProgress>
+    for crate::api::simple::FileProgress
+{
+    fn into_into_dart(self) -> crate::api::simple::FileProgress {
+        self
     }
 }
-
-impl SseDecode for crate::api::simple::TorrentInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_totalSize = <usize>::sse_decode(deserializer);
-        let mut var_pieceCount = <usize>::sse_decode(deserializer);
-        let mut var_pieceLength = <usize>::sse_decode(deserializer);
-        let mut var_files = <Vec<crate::api::simple::FileInfo>>::sse_decode(deserializer);
-        let mut var_infoHash = <String>::sse_decode(deserializer);
-        let mut var_announce = <String>::sse_decode(deserializer);
-        return crate::api::simple::TorrentInfo {
-            name: var_name,
-            total_size: var_totalSize,
-            piece_count: var_pieceCount,
-            piece_length: var_pieceLength,
-            files: var_files,
-            info_hash: var_infoHash,
-            announce: var_announce,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::peers::IpFilterStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+            self.allowed_entries.into_into_dart().into_dart(),
+            self.invalid_lines.into_into_dart().into_dart(),
+            self.ipv4_ranges.into_into_dart().into_dart(),
+            self.ipv6_ranges.into_into_dart().into_dart(),
+            self.ipv4_addresses.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::peers::IpFilterStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::peers::IpFilterStats>
+    for crate::api::peers::IpFilterStats
+{
+    fn into_into_dart(self) -> crate::api::peers::IpFilterStats {
+        self
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MagnetInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.info_hash.into_into_dart().into_dart(),
+            self.info_hash_v2.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.trackers.into_into_dart().into_dart(),
+            self.web_seeds.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MagnetInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MagnetInfo>
+    for crate::api::simple::MagnetInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::MagnetInfo {
+        self
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MetaVersion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::V1 => 0.into_dart(),
+            Self::V2 => 1.into_dart(),
+            Self::Hybrid => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MetaVersion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MetaVersion>
+    for crate::api::simple::MetaVersion
+{
+    fn into_into_dart(self) -> crate::api::simple::MetaVersion {
+        self
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::network::NetworkStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.listen_address.into_into_dart().into_dart(),
+            self.port_mapping.into_into_dart().into_dart(),
+            self.external_address.into_into_dart().into_dart(),
+            self.external_port.into_into_dart().into_dart(),
+            self.mapping_error.into_into_dart().into_dart(),
+            self.incoming_seen.into_into_dart().into_dart(),
+            self.interface_up.into_into_dart().into_dart(),
+            self.bind_address.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::network::NetworkStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::network::NetworkStatus>
+    for crate::api::network::NetworkStatus
+{
+    fn into_into_dart(self) -> crate::api::network::NetworkStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::peers::PeerBan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.disconnected.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::peers::PeerBan {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::peers::PeerBan> for crate::api::peers::PeerBan {
+    fn into_into_dart(self) -> crate::api::peers::PeerBan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::peers::PeerDirection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Incoming => 0.into_dart(),
+            Self::Outgoing => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::peers::PeerDirection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::peers::PeerDirection>
+    for crate::api::peers::PeerDirection
+{
+    fn into_into_dart(self) -> crate::api::peers::PeerDirection {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => {
-            wire__crate__api__simple__fetch_magnet_metadata_impl(port, ptr, rust_vec_len, data_len)
-        }
-        2 => {
-            wire__crate__api__simple__get_torrent_info_file_impl(port, ptr, rust_vec_len, data_len)
-        }
-        3 => wire__crate__api__simple__get_torrents_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__simple__parse_magnet_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__simple__start_download_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::peers::PeerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.encrypted.into_into_dart().into_dart(),
+            self.download_bps.into_into_dart().into_dart(),
+            self.downloaded_bytes.into_into_dart().into_dart(),
+            self.pieces_downloaded.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::peers::PeerInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::peers::PeerInfo>
+    for crate::api::peers::PeerInfo
+{
+    fn into_into_dart(self) -> crate::api::peers::PeerInfo {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::AppTorrentStatus {
+impl flutter_rust_bridge::IntoDart for crate::api::trackers::PeerSourceResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.total_pieces.into_into_dart().into_dart(),
-            self.completed_pieces.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
             self.peers.into_into_dart().into_dart(),
-            self.speed_mbps.into_into_dart().into_dart(),
-            self.downloading.into_into_dart().into_dart(),
-            self.is_fetching_metadata.into_into_dart().into_dart(),
-            self.status_message.into_into_dart().into_dart(),
+            self.new_peers.into_into_dart().into_dart(),
             self.error.into_into_dart().into_dart(),
-            self.total_bytes.into_into_dart().into_dart(),
-            self.downloaded_bytes.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::AppTorrentStatus
+    for crate::api::trackers::PeerSourceResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AppTorrentStatus>
-    for crate::api::simple::AppTorrentStatus
+impl flutter_rust_bridge::IntoIntoDart<crate::api::trackers::PeerSourceResult>
+    for crate::api::trackers::PeerSourceResult
 {
-    fn into_into_dart(self) -> crate::api::simple::AppTorrentStatus {
+    fn into_into_dart(self) -> crate::api::trackers::PeerSourceResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::FileInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::config::ProxyConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.path.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
+            self.proxy_type.into_into_dart().into_dart(),
+            self.host.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.proxy_peers.into_into_dart().into_dart(),
+            self.proxy_trackers.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::FileInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FileInfo>
-    for crate::api::simple::FileInfo
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::config::ProxyConfig
 {
-    fn into_into_dart(self) -> crate::api::simple::FileInfo {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::config::ProxyConfig>
+    for crate::api::config::ProxyConfig
+{
+    fn into_into_dart(self) -> crate::api::config::ProxyConfig {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::MagnetInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::config::ProxyType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Socks5 => 0.into_dart(),
+            Self::Http => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::config::ProxyType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::config::ProxyType>
+    for crate::api::config::ProxyType
+{
+    fn into_into_dart(self) -> crate::api::config::ProxyType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::trackers::ReannounceSummary {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.url.into_into_dart().into_dart(),
-            self.info_hash.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.trackers.into_into_dart().into_dart(),
+            self.sources.into_into_dart().into_dart(),
+            self.new_peers.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::MagnetInfo
+    for crate::api::trackers::ReannounceSummary
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MagnetInfo>
-    for crate::api::simple::MagnetInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::trackers::ReannounceSummary>
+    for crate::api::trackers::ReannounceSummary
 {
-    fn into_into_dart(self) -> crate::api::simple::MagnetInfo {
+    fn into_into_dart(self) -> crate::api::trackers::ReannounceSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::config::SessionConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.incomplete_dir.into_into_dart().into_dart(),
+            self.complete_dir.into_into_dart().into_dart(),
+            self.part_suffix.into_into_dart().into_dart(),
+            self.include_file_progress.into_into_dart().into_dart(),
+            self.default_trackers.into_into_dart().into_dart(),
+            self.default_trackers_file.into_into_dart().into_dart(),
+            self.dht_port.into_into_dart().into_dart(),
+            self.dht_bootstrap_nodes.into_into_dart().into_dart(),
+            self.listen_port.into_into_dart().into_dart(),
+            self.listen_port_max.into_into_dart().into_dart(),
+            self.upnp.into_into_dart().into_dart(),
+            self.natpmp.into_into_dart().into_dart(),
+            self.outgoing_interface.into_into_dart().into_dart(),
+            self.bind_address.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+            self.encryption.into_into_dart().into_dart(),
+            self.max_active_downloads.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::config::SessionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::config::SessionConfig>
+    for crate::api::config::SessionConfig
+{
+    fn into_into_dart(self) -> crate::api::config::SessionConfig {
         self
     }
 }
@@ -639,7 +3342,11 @@ impl flutter_rust_bridge::IntoDart for crate::api::simple::TorrentInfo {
             self.piece_length.into_into_dart().into_dart(),
             self.files.into_into_dart().into_dart(),
             self.info_hash.into_into_dart().into_dart(),
+            self.info_hash_v2.into_into_dart().into_dart(),
+            self.meta_version.into_into_dart().into_dart(),
             self.announce.into_into_dart().into_dart(),
+            self.web_seeds.into_into_dart().into_dart(),
+            self.file_tree.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -655,77 +3362,343 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TorrentInfo>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::trackers::TrackerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.tier.into_into_dart().into_dart(),
+            self.last_update.into_into_dart().into_dart(),
+            self.next_update.into_into_dart().into_dart(),
+            self.is_scraped.into_into_dart().into_dart(),
+            self.seeders.into_into_dart().into_dart(),
+            self.leechers.into_into_dart().into_dart(),
+            self.completed.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+            self.is_updating.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::trackers::TrackerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::trackers::TrackerInfo>
+    for crate::api::trackers::TrackerInfo
+{
+    fn into_into_dart(self) -> crate::api::trackers::TrackerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::V2FileInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.merkle_root.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::V2FileInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::V2FileInfo>
+    for crate::api::simple::V2FileInfo
+{
+    fn into_into_dart(self) -> crate::api::simple::V2FileInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::trackers::WebSeedInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.downloaded_bytes.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::trackers::WebSeedInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::trackers::WebSeedInfo>
+    for crate::api::trackers::WebSeedInfo
+{
+    fn into_into_dart(self) -> crate::api::trackers::WebSeedInfo {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::simple::AppTorrentStatus,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::create::CreateTorrentProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::AddRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.source, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.torrent_bytes, serializer);
+        <String>::sse_encode(self.output_dir, serializer);
+        <Option<Vec<usize>>>::sse_encode(self.selected_file_indices, serializer);
+        <bool>::sse_encode(self.use_default_trackers, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::AddResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<usize>>::sse_encode(self.id, serializer);
+        <Option<String>>::sse_encode(self.info_hash, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.already_added, serializer);
+        <bool>::sse_encode(self.queued, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::AppTorrentStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.id, serializer);
+        <u32>::sse_encode(self.total_pieces, serializer);
+        <u32>::sse_encode(self.completed_pieces, serializer);
+        <u32>::sse_encode(self.peers, serializer);
+        <f64>::sse_encode(self.speed_mbps, serializer);
+        <f64>::sse_encode(self.web_seed_speed_mbps, serializer);
+        <bool>::sse_encode(self.downloading, serializer);
+        <bool>::sse_encode(self.is_fetching_metadata, serializer);
+        <bool>::sse_encode(self.is_checking, serializer);
+        <bool>::sse_encode(self.is_moving, serializer);
+        <bool>::sse_encode(self.is_sequential, serializer);
+        <bool>::sse_encode(self.is_network_paused, serializer);
+        <bool>::sse_encode(self.is_queued, serializer);
+        <String>::sse_encode(self.status_message, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <u64>::sse_encode(self.downloaded_bytes, serializer);
+        <u64>::sse_encode(self.web_seed_downloaded_bytes, serializer);
+        <Option<Vec<crate::api::simple::FileProgress>>>::sse_encode(self.files, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::create::CreateTorrentOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<u32>>::sse_encode(self.piece_length, serializer);
+        <Vec<String>>::sse_encode(self.trackers, serializer);
+        <Vec<String>>::sse_encode(self.web_seeds, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+        <bool>::sse_encode(self.private, serializer);
+        <Option<String>>::sse_encode(self.source, serializer);
+    }
+}
+
+impl SseEncode for crate::api::create::CreateTorrentProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.hashed_bytes, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <Option<crate::api::create::CreatedTorrent>>::sse_encode(self.torrent, serializer);
+    }
+}
+
+impl SseEncode for crate::api::create::CreatedTorrent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.torrent_bytes, serializer);
+        <String>::sse_encode(self.magnet, serializer);
+        <String>::sse_encode(self.info_hash, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.total_size, serializer);
+        <u32>::sse_encode(self.piece_length, serializer);
+        <usize>::sse_encode(self.piece_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::network::DhtBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.prefix_bits, serializer);
+        <u32>::sse_encode(self.nodes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::network::DhtStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <String>::sse_encode(self.node_id, serializer);
+        <u16>::sse_encode(self.listen_port, serializer);
+        <u32>::sse_encode(self.nodes, serializer);
+        <Vec<crate::api::network::DhtBucket>>::sse_encode(self.buckets, serializer);
+        <u32>::sse_encode(self.outstanding_queries, serializer);
+    }
+}
+
+impl SseEncode for crate::api::config::EncryptionPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::config::EncryptionPolicy::Disabled => 0,
+                crate::api::config::EncryptionPolicy::Prefer => 1,
+                crate::api::config::EncryptionPolicy::Require => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::simple::FileInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <usize>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <usize>::sse_encode(self.size, serializer);
+        <Option<String>>::sse_encode(self.original_path, serializer);
+        <crate::api::simple::FilePriority>::sse_encode(self.priority, serializer);
+        <u64>::sse_encode(self.downloaded_bytes, serializer);
+        <Option<String>>::sse_encode(self.merkle_root, serializer);
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::simple::AppTorrentStatus,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for crate::api::simple::FilePriority {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::FilePriority::Skip => 0,
+                crate::api::simple::FilePriority::Low => 1,
+                crate::api::simple::FilePriority::Normal => 2,
+                crate::api::simple::FilePriority::High => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::simple::FileProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <usize>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <u64>::sse_encode(self.downloaded, serializer);
+        <bool>::sse_encode(self.complete, serializer);
     }
 }
 
-impl SseEncode for crate::api::simple::AppTorrentStatus {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.total_pieces, serializer);
-        <u32>::sse_encode(self.completed_pieces, serializer);
-        <u32>::sse_encode(self.peers, serializer);
-        <f64>::sse_encode(self.speed_mbps, serializer);
-        <bool>::sse_encode(self.downloading, serializer);
-        <bool>::sse_encode(self.is_fetching_metadata, serializer);
-        <String>::sse_encode(self.status_message, serializer);
-        <Option<String>>::sse_encode(self.error, serializer);
-        <u64>::sse_encode(self.total_bytes, serializer);
-        <u64>::sse_encode(self.downloaded_bytes, serializer);
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::peers::IpFilterStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <Option<String>>::sse_encode(self.source, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <usize>::sse_encode(self.entries, serializer);
+        <usize>::sse_encode(self.allowed_entries, serializer);
+        <usize>::sse_encode(self.invalid_lines, serializer);
+        <usize>::sse_encode(self.ipv4_ranges, serializer);
+        <usize>::sse_encode(self.ipv6_ranges, serializer);
+        <u64>::sse_encode(self.ipv4_addresses, serializer);
+        <bool>::sse_encode(self.active, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::FileInfo {
+impl SseEncode for Vec<crate::api::simple::AddRequest> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.path, serializer);
-        <usize>::sse_encode(self.size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::AddRequest>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<crate::api::simple::AddResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <crate::api::simple::AddResult>::sse_encode(item, serializer);
         }
     }
 }
@@ -740,6 +3713,16 @@ impl SseEncode for Vec<crate::api::simple::AppTorrentStatus> {
     }
 }
 
+impl SseEncode for Vec<crate::api::network::DhtBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::network::DhtBucket>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::simple::FileInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -750,6 +3733,36 @@ impl SseEncode for Vec<crate::api::simple::FileInfo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::simple::FileProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::FileProgress>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::peers::PeerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::peers::PeerInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::trackers::PeerSourceResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::trackers::PeerSourceResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -770,13 +3783,86 @@ impl SseEncode for Vec<usize> {
     }
 }
 
+impl SseEncode for Vec<(usize, crate::api::simple::FilePriority)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(usize, crate::api::simple::FilePriority)>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::trackers::TrackerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::trackers::TrackerInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::V2FileInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::V2FileInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::trackers::WebSeedInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::trackers::WebSeedInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::api::simple::MagnetInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.url, serializer);
         <String>::sse_encode(self.info_hash, serializer);
+        <Option<String>>::sse_encode(self.info_hash_v2, serializer);
         <Option<String>>::sse_encode(self.name, serializer);
         <Vec<String>>::sse_encode(self.trackers, serializer);
+        <Vec<String>>::sse_encode(self.web_seeds, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::MetaVersion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::MetaVersion::V1 => 0,
+                crate::api::simple::MetaVersion::V2 => 1,
+                crate::api::simple::MetaVersion::Hybrid => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::network::NetworkStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.listen_address, serializer);
+        <Option<String>>::sse_encode(self.port_mapping, serializer);
+        <Option<String>>::sse_encode(self.external_address, serializer);
+        <Option<u16>>::sse_encode(self.external_port, serializer);
+        <Option<String>>::sse_encode(self.mapping_error, serializer);
+        <bool>::sse_encode(self.incoming_seen, serializer);
+        <bool>::sse_encode(self.interface_up, serializer);
+        <Option<String>>::sse_encode(self.bind_address, serializer);
     }
 }
 
@@ -790,6 +3876,86 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<crate::api::create::CreatedTorrent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::create::CreatedTorrent>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::config::ProxyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::config::ProxyConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<crate::api::simple::FileProgress>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::api::simple::FileProgress>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<Vec<usize>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -800,6 +3966,121 @@ impl SseEncode for Option<Vec<usize>> {
     }
 }
 
+impl SseEncode for crate::api::peers::PeerBan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.disconnected, serializer);
+        <bool>::sse_encode(self.active, serializer);
+    }
+}
+
+impl SseEncode for crate::api::peers::PeerDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::peers::PeerDirection::Incoming => 0,
+                crate::api::peers::PeerDirection::Outgoing => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::peers::PeerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <crate::api::peers::PeerDirection>::sse_encode(self.direction, serializer);
+        <bool>::sse_encode(self.encrypted, serializer);
+        <u64>::sse_encode(self.download_bps, serializer);
+        <u64>::sse_encode(self.downloaded_bytes, serializer);
+        <u32>::sse_encode(self.pieces_downloaded, serializer);
+        <u32>::sse_encode(self.errors, serializer);
+    }
+}
+
+impl SseEncode for crate::api::trackers::PeerSourceResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source, serializer);
+        <Option<u32>>::sse_encode(self.peers, serializer);
+        <Option<u32>>::sse_encode(self.new_peers, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::config::ProxyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::config::ProxyType>::sse_encode(self.proxy_type, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+        <bool>::sse_encode(self.proxy_peers, serializer);
+        <bool>::sse_encode(self.proxy_trackers, serializer);
+    }
+}
+
+impl SseEncode for crate::api::config::ProxyType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::config::ProxyType::Socks5 => 0,
+                crate::api::config::ProxyType::Http => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::trackers::ReannounceSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::trackers::PeerSourceResult>>::sse_encode(self.sources, serializer);
+        <u32>::sse_encode(self.new_peers, serializer);
+    }
+}
+
+impl SseEncode for (usize, crate::api::simple::FilePriority) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.0, serializer);
+        <crate::api::simple::FilePriority>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for crate::api::config::SessionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.incomplete_dir, serializer);
+        <Option<String>>::sse_encode(self.complete_dir, serializer);
+        <bool>::sse_encode(self.part_suffix, serializer);
+        <bool>::sse_encode(self.include_file_progress, serializer);
+        <Vec<String>>::sse_encode(self.default_trackers, serializer);
+        <Option<String>>::sse_encode(self.default_trackers_file, serializer);
+        <Option<u16>>::sse_encode(self.dht_port, serializer);
+        <Vec<String>>::sse_encode(self.dht_bootstrap_nodes, serializer);
+        <Option<u16>>::sse_encode(self.listen_port, serializer);
+        <Option<u16>>::sse_encode(self.listen_port_max, serializer);
+        <bool>::sse_encode(self.upnp, serializer);
+        <bool>::sse_encode(self.natpmp, serializer);
+        <Option<String>>::sse_encode(self.outgoing_interface, serializer);
+        <Option<String>>::sse_encode(self.bind_address, serializer);
+        <Option<crate::api::config::ProxyConfig>>::sse_encode(self.proxy, serializer);
+        <crate::api::config::EncryptionPolicy>::sse_encode(self.encryption, serializer);
+        <Option<u32>>::sse_encode(self.max_active_downloads, serializer);
+    }
+}
+
 impl SseEncode for crate::api::simple::TorrentInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -809,7 +4090,35 @@ impl SseEncode for crate::api::simple::TorrentInfo {
         <usize>::sse_encode(self.piece_length, serializer);
         <Vec<crate::api::simple::FileInfo>>::sse_encode(self.files, serializer);
         <String>::sse_encode(self.info_hash, serializer);
+        <Option<String>>::sse_encode(self.info_hash_v2, serializer);
+        <crate::api::simple::MetaVersion>::sse_encode(self.meta_version, serializer);
         <String>::sse_encode(self.announce, serializer);
+        <Vec<String>>::sse_encode(self.web_seeds, serializer);
+        <Vec<crate::api::simple::V2FileInfo>>::sse_encode(self.file_tree, serializer);
+    }
+}
+
+impl SseEncode for crate::api::trackers::TrackerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <usize>::sse_encode(self.tier, serializer);
+        <Option<u64>>::sse_encode(self.last_update, serializer);
+        <Option<u64>>::sse_encode(self.next_update, serializer);
+        <bool>::sse_encode(self.is_scraped, serializer);
+        <Option<u64>>::sse_encode(self.seeders, serializer);
+        <Option<u64>>::sse_encode(self.leechers, serializer);
+        <Option<u64>>::sse_encode(self.completed, serializer);
+        <Option<u32>>::sse_encode(self.peers, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <bool>::sse_encode(self.is_updating, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -849,10 +4158,21 @@ impl SseEncode for usize {
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::simple::V2FileInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <Option<String>>::sse_encode(self.merkle_root, serializer);
+    }
+}
+
+impl SseEncode for crate::api::trackers::WebSeedInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <u64>::sse_encode(self.downloaded_bytes, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
 
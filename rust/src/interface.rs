@@ -0,0 +1,125 @@
+// Pausing torrents while a network interface, such as a VPN's, is gone.
+//
+// This is not a kill switch. librqbit accepts peers on every address and
+// connects to peers and to its trackers through whatever interface the
+// system routes over, and can't be told otherwise. Only the DHT socket and
+// our own tracker requests are bound to the configured address. While the
+// interface is gone, a watchdog keeps every torrent paused, and resumes the
+// ones it paused once it is back. Peer traffic therefore stays on the
+// interface only if the system routes everything through it while it is
+// there, as a full-tunnel VPN does, and may leak in the up to
+// CHECK_INTERVAL it takes to notice that it went away.
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::time::Duration;
+use network_interface::{NetworkInterface, NetworkInterfaceConfig};
+use crate::api::config::{session_config, SessionConfig};
+use crate::{get_session, torrents};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+static INTERFACE_UP: AtomicBool = AtomicBool::new(true);
+
+/// Whether an interface or address is configured
+pub fn is_configured(config: &SessionConfig) -> bool {
+    config.outgoing_interface.is_some() || config.bind_address.is_some()
+}
+
+/// Address to bind to: the configured one, whether it is there or not, or
+/// the first address of the configured interface, preferring IPv4. None if
+/// neither is configured, or the interface is down.
+pub fn bind_ip() -> Option<IpAddr> {
+    let config = session_config();
+    if let Some(address) = &config.bind_address {
+        return address.parse().ok();
+    }
+    let name = config.outgoing_interface?;
+    let mut addresses: Vec<IpAddr> = NetworkInterface::show().ok()?.into_iter()
+        .filter(|i| i.name == name)
+        .flat_map(|i| i.addr.into_iter().map(|a| a.ip()))
+        .collect();
+    addresses.sort_by_key(|ip| ip.is_ipv6());
+    addresses.first().copied()
+}
+
+/// Whether the configured interface is there, with the configured address
+/// if there is one. Always true if nothing is configured.
+pub fn is_up() -> bool {
+    INTERFACE_UP.load(Ordering::Relaxed)
+}
+
+/// Check the system's interfaces for the configured interface and address
+pub fn is_present(config: &SessionConfig) -> bool {
+    if !is_configured(config) {
+        return true;
+    }
+    let address = config.bind_address.as_ref().and_then(|a| a.parse::<IpAddr>().ok());
+    let Ok(interfaces) = NetworkInterface::show() else {
+        // Can't tell, so assume the worst
+        return false;
+    };
+    interfaces.iter()
+        .filter(|i| config.outgoing_interface.as_ref().is_none_or(|name| i.name == *name))
+        .any(|i| match address {
+            Some(address) => i.addr.iter().any(|a| a.ip() == address),
+            None => !i.addr.is_empty(),
+        })
+}
+
+/// Start the watchdog, once for the lifetime of the app. It reads the
+/// config on every check, so changes apply without a session restart.
+pub fn spawn_watchdog() {
+    static STARTED: Once = Once::new();
+    STARTED.call_once(|| {
+        tokio::spawn(async {
+            loop {
+                watch().await;
+                tokio::time::sleep(CHECK_INTERVAL).await;
+            }
+        });
+    });
+}
+
+async fn watch() {
+    let config = session_config();
+    let up = is_present(&config);
+    if up != INTERFACE_UP.swap(up, Ordering::Relaxed) {
+        let name = config.outgoing_interface.or(config.bind_address).unwrap_or_default();
+        if up {
+            println!("NETWORK: {} is back, resuming torrents", name);
+        } else {
+            println!("NETWORK: {} is down, pausing torrents", name);
+        }
+    }
+
+    let Ok(session) = get_session().await else {
+        return;
+    };
+    let handles = session.with_torrents(|torrents| {
+        torrents.map(|(_, handle)| handle.clone()).collect::<Vec<_>>()
+    });
+    for handle in handles {
+        let id = handle.id();
+        if torrents::is_readding(id) {
+            continue;
+        }
+        // Only live torrents have connections. Torrents that are added,
+        // resumed or done checking while the interface is down are paused
+        // on the next check.
+        if !up && handle.live().is_some() {
+            match session.pause(&handle).await {
+                Ok(()) => torrents::set_network_paused(id, true),
+                Err(e) => println!("NETWORK: failed to pause torrent {}: {:#}", id, e),
+            }
+        } else if up && torrents::get(id).is_some_and(|e| e.network_paused) {
+            if handle.is_paused() {
+                if let Err(e) = session.unpause(&handle).await {
+                    println!("NETWORK: failed to resume torrent {}: {:#}", id, e);
+                    continue;
+                }
+            }
+            torrents::set_network_paused(id, false);
+        }
+    }
+}
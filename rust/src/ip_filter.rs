@@ -0,0 +1,391 @@
+// IP range filters in the eMule and PeerGuardian P2P formats.
+//
+// eMule (ipfilter.dat): "001.002.003.004 - 001.002.003.255 , 000 , Description"
+//   Ranges with an access level of 128 or more are allowed, not blocked.
+// P2P:                  "Description:1.2.3.4-1.2.3.255"
+//
+// Either may be gzip-compressed. Ranges are merged into sorted, disjoint
+// intervals, which makes a lookup a single binary search and keeps the
+// filter small however much the source lists overlap.
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// eMule levels from this one up mean "allow"
+const EMULE_ALLOW_LEVEL: u32 = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterFormat {
+    Emule,
+    P2p,
+}
+
+/// Blocked ranges, inclusive, sorted and disjoint
+#[derive(Debug, Clone, Default)]
+pub struct IpFilter {
+    v4: Vec<(u32, u32)>,
+    v6: Vec<(u128, u128)>,
+}
+
+/// Sort and merge overlapping or adjacent inclusive ranges
+fn merge(mut ranges: Vec<(u128, u128)>) -> Vec<(u128, u128)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u128, u128)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn contains<T: Ord + Copy>(ranges: &[(T, T)], value: T) -> bool {
+    // The last range starting at or before `value` is the only candidate
+    let index = ranges.partition_point(|(start, _)| *start <= value);
+    index > 0 && value <= ranges[index - 1].1
+}
+
+impl IpFilter {
+    /// Build a filter from inclusive (first, last) ranges. Ranges mixing
+    /// IPv4 and IPv6 are ignored.
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (IpAddr, IpAddr)>) -> Self {
+        let mut v4 = Vec::new();
+        let mut v6 = Vec::new();
+        for range in ranges {
+            match range {
+                (IpAddr::V4(a), IpAddr::V4(b)) => {
+                    let (a, b) = (u32::from(a), u32::from(b));
+                    v4.push((a.min(b) as u128, a.max(b) as u128));
+                }
+                (IpAddr::V6(a), IpAddr::V6(b)) => {
+                    let (a, b) = (u128::from(a), u128::from(b));
+                    v6.push((a.min(b), a.max(b)));
+                }
+                _ => {}
+            }
+        }
+        Self {
+            v4: merge(v4).into_iter().map(|(a, b)| (a as u32, b as u32)).collect(),
+            v6: merge(v6),
+        }
+    }
+
+    pub fn is_blocked(&self, ip: IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(ip) => contains(&self.v4, u32::from(ip)),
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(ip) => contains(&self.v4, u32::from(ip)),
+                None => contains(&self.v6, u128::from(ip)),
+            },
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty()
+    }
+
+    pub fn ipv4_range_count(&self) -> usize {
+        self.v4.len()
+    }
+
+    pub fn ipv6_range_count(&self) -> usize {
+        self.v6.len()
+    }
+
+    /// Number of IPv4 addresses blocked
+    pub fn ipv4_address_count(&self) -> u64 {
+        self.v4.iter().map(|(a, b)| (b - a) as u64 + 1).sum()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = (IpAddr, IpAddr)> + '_ {
+        let v4 = self.v4.iter()
+            .map(|(a, b)| (IpAddr::V4(Ipv4Addr::from(*a)), IpAddr::V4(Ipv4Addr::from(*b))));
+        let v6 = self.v6.iter()
+            .map(|(a, b)| (IpAddr::V6(Ipv6Addr::from(*a)), IpAddr::V6(Ipv6Addr::from(*b))));
+        v4.chain(v6)
+    }
+}
+
+/// A filter file after parsing
+#[derive(Debug, Clone)]
+pub struct ParsedFilter {
+    pub filter: IpFilter,
+    /// Format of the first range in the file
+    pub format: Option<FilterFormat>,
+    /// Blocking entries read, before merging
+    pub entries: usize,
+    /// eMule entries whose level allows the range
+    pub allowed_entries: usize,
+    pub invalid_lines: usize,
+}
+
+enum Line {
+    Blocked(IpAddr, IpAddr, FilterFormat),
+    Allowed,
+    Ignored,
+    Invalid,
+}
+
+/// IPv4 with leading zeros ("001.002.003.004"), which eMule lists use and
+/// the standard parser rejects, or IPv6
+fn parse_ip(s: &str) -> Option<IpAddr> {
+    let s = s.trim();
+    let octets: Vec<&str> = s.split('.').collect();
+    if octets.len() == 4 {
+        let mut bytes = [0u8; 4];
+        for (byte, octet) in bytes.iter_mut().zip(octets) {
+            if octet.is_empty() || octet.len() > 3 {
+                return None;
+            }
+            *byte = octet.parse().ok()?;
+        }
+        return Some(IpAddr::V4(Ipv4Addr::from(bytes)));
+    }
+    s.parse::<Ipv6Addr>().ok().map(IpAddr::V6)
+}
+
+fn parse_range(s: &str) -> Option<(IpAddr, IpAddr)> {
+    // IPv6 addresses don't contain '-', so the first one splits the range
+    let (first, last) = s.split_once('-')?;
+    let (first, last) = (parse_ip(first)?, parse_ip(last)?);
+    (first.is_ipv4() == last.is_ipv4()).then_some((first, last))
+}
+
+fn parse_emule(line: &str) -> Line {
+    let mut fields = line.splitn(3, ',');
+    let Some((first, last)) = fields.next().and_then(parse_range) else {
+        return Line::Invalid;
+    };
+    match fields.next().map(|level| level.trim().parse::<u32>()) {
+        Some(Ok(level)) if level >= EMULE_ALLOW_LEVEL => Line::Allowed,
+        Some(Err(_)) => Line::Invalid,
+        _ => Line::Blocked(first, last, FilterFormat::Emule),
+    }
+}
+
+fn parse_p2p(line: &str) -> Line {
+    // Descriptions can contain ':', and so can IPv6 ranges: try every
+    // split point until the rest parses as a range
+    for (index, _) in line.match_indices(':') {
+        if let Some((first, last)) = parse_range(&line[index + 1..]) {
+            return Line::Blocked(first, last, FilterFormat::P2p);
+        }
+    }
+    Line::Invalid
+}
+
+fn parse_line(line: &str) -> Line {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+        return Line::Ignored;
+    }
+    if line.contains(',') {
+        if let blocked @ (Line::Blocked(..) | Line::Allowed) = parse_emule(line) {
+            return blocked;
+        }
+    }
+    match parse_p2p(line) {
+        // A bare "first - last" line is eMule without level or description
+        Line::Invalid => parse_emule(line),
+        parsed => parsed,
+    }
+}
+
+/// Parse a filter in either format, gzip-compressed or not
+pub fn parse(reader: impl Read) -> Result<ParsedFilter> {
+    let mut reader = BufReader::new(reader);
+    let is_gzip = reader.fill_buf()?.starts_with(&GZIP_MAGIC);
+    let reader: Box<dyn BufRead> = if is_gzip {
+        Box::new(BufReader::new(GzDecoder::new(reader)))
+    } else {
+        Box::new(reader)
+    };
+
+    let mut ranges = Vec::new();
+    let mut format = None;
+    let mut allowed_entries = 0;
+    let mut invalid_lines = 0;
+    for line in reader.split(b'\n') {
+        let line = line.context("Failed to read the filter")?;
+        match parse_line(&String::from_utf8_lossy(&line)) {
+            Line::Blocked(first, last, line_format) => {
+                format.get_or_insert(line_format);
+                ranges.push((first, last));
+            }
+            Line::Allowed => allowed_entries += 1,
+            Line::Ignored => {}
+            Line::Invalid => invalid_lines += 1,
+        }
+    }
+
+    Ok(ParsedFilter {
+        entries: ranges.len(),
+        filter: IpFilter::from_ranges(ranges),
+        format,
+        allowed_entries,
+        invalid_lines,
+    })
+}
+
+pub fn load(path: &Path) -> Result<ParsedFilter> {
+    let file = File::open(path).context(format!("Failed to open {:?}", path))?;
+    parse(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn range(first: &str, last: &str) -> (IpAddr, IpAddr) {
+        (ip(first), ip(last))
+    }
+
+    #[test]
+    fn parses_emule() {
+        let text = "\
+# comment
+001.002.003.004 - 001.002.003.255 , 000 , Some ISP
+010.000.000.000 - 010.255.255.255 , 127 , Private
+192.168.000.000 - 192.168.255.255 , 200 , Allowed LAN
+005.006.007.008 - 005.006.007.009
+";
+        let parsed = parse(text.as_bytes()).unwrap();
+        assert_eq!(parsed.format, Some(FilterFormat::Emule));
+        assert_eq!(parsed.entries, 3);
+        assert_eq!(parsed.allowed_entries, 1);
+        assert_eq!(parsed.invalid_lines, 0);
+        assert_eq!(parsed.filter.ranges().collect::<Vec<_>>(), vec![
+            range("1.2.3.4", "1.2.3.255"),
+            range("5.6.7.8", "5.6.7.9"),
+            range("10.0.0.0", "10.255.255.255"),
+        ]);
+        assert!(!parsed.filter.is_blocked(ip("192.168.1.1")));
+    }
+
+    #[test]
+    fn parses_p2p() {
+        let text = "\
+// comment
+Some ISP:1.2.3.4-1.2.3.255
+Name: with colons:5.6.7.8-5.6.7.8
+
+IPv6 block:2001:db8::-2001:db8::ffff
+";
+        let parsed = parse(text.as_bytes()).unwrap();
+        assert_eq!(parsed.format, Some(FilterFormat::P2p));
+        assert_eq!(parsed.entries, 3);
+        assert_eq!(parsed.invalid_lines, 0);
+        assert_eq!(parsed.filter.ipv4_range_count(), 2);
+        assert_eq!(parsed.filter.ipv6_range_count(), 1);
+        assert!(parsed.filter.is_blocked(ip("5.6.7.8")));
+        assert!(parsed.filter.is_blocked(ip("2001:db8::abcd")));
+    }
+
+    #[test]
+    fn parses_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"Some ISP:1.2.3.4-1.2.3.255\n").unwrap();
+        let bytes = encoder.finish().unwrap();
+
+        let parsed = parse(bytes.as_slice()).unwrap();
+        assert_eq!(parsed.entries, 1);
+        assert!(parsed.filter.is_blocked(ip("1.2.3.100")));
+    }
+
+    #[test]
+    fn counts_malformed_lines() {
+        // (line, invalid)
+        let cases = [
+            ("garbage", true),
+            ("1.2.3.4", true),
+            ("1.2.3.4 - 1.2.3", true),
+            ("1.2.3.256 - 1.2.3.300 , 0 , x", true),
+            ("1.2.3.4 - 1.2.3.5 , abc , x", true),
+            ("1.2.3.4 - ::1 , 0 , mixed families", true),
+            ("Name:1.2.3.4-", true),
+            ("0001.2.3.4 - 1.2.3.5", true),
+            ("   ", false),
+            ("# 1.2.3.4 - 1.2.3.5", false),
+            ("1.2.3.4 - 1.2.3.5 , 0 , x", false),
+        ];
+        for (line, invalid) in cases {
+            let parsed = parse(line.as_bytes()).unwrap();
+            assert_eq!(parsed.invalid_lines, invalid as usize, "{:?}", line);
+        }
+    }
+
+    type Ranges<'a> = &'a [(&'a str, &'a str)];
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        // (input ranges, merged ranges)
+        let cases: [(Ranges, Ranges); 5] = [
+            (&[("1.0.0.0", "1.0.0.10"), ("1.0.0.5", "1.0.0.20")], &[("1.0.0.0", "1.0.0.20")]),
+            (&[("1.0.0.0", "1.0.0.10"), ("1.0.0.11", "1.0.0.20")], &[("1.0.0.0", "1.0.0.20")]),
+            (&[("1.0.0.0", "1.0.0.10"), ("1.0.0.12", "1.0.0.20")], &[("1.0.0.0", "1.0.0.10"), ("1.0.0.12", "1.0.0.20")]),
+            (&[("1.0.0.0", "1.0.0.255"), ("1.0.0.3", "1.0.0.4")], &[("1.0.0.0", "1.0.0.255")]),
+            (&[("1.0.0.20", "1.0.0.10"), ("255.255.255.255", "255.255.255.0")], &[("1.0.0.10", "1.0.0.20"), ("255.255.255.0", "255.255.255.255")]),
+        ];
+        for (input, merged) in cases {
+            let filter = IpFilter::from_ranges(input.iter().map(|(a, b)| range(a, b)));
+            let expected: Vec<_> = merged.iter().map(|(a, b)| range(a, b)).collect();
+            assert_eq!(filter.ranges().collect::<Vec<_>>(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn ignores_mixed_family_ranges() {
+        let filter = IpFilter::from_ranges([range("1.2.3.4", "::1")]);
+        assert!(filter.is_empty());
+    }
+
+    #[test]
+    fn counts_ipv4_addresses() {
+        let filter = IpFilter::from_ranges([range("1.0.0.0", "1.0.0.255"), range("0.0.0.0", "255.255.255.255")]);
+        assert_eq!(filter.ipv4_address_count(), 1 << 32);
+    }
+
+    #[test]
+    fn blocks_at_range_boundaries() {
+        let filter = IpFilter::from_ranges([
+            range("0.0.0.0", "0.0.0.0"),
+            range("1.2.3.4", "1.2.3.10"),
+            range("255.255.255.255", "255.255.255.255"),
+            range("2001:db8::10", "2001:db8::20"),
+        ]);
+        // (address, blocked)
+        let cases = [
+            ("0.0.0.0", true),
+            ("0.0.0.1", false),
+            ("1.2.3.3", false),
+            ("1.2.3.4", true),
+            ("1.2.3.10", true),
+            ("1.2.3.11", false),
+            ("255.255.255.254", false),
+            ("255.255.255.255", true),
+            ("::ffff:1.2.3.4", true),
+            ("::ffff:1.2.3.11", false),
+            ("2001:db8::f", false),
+            ("2001:db8::10", true),
+            ("2001:db8::20", true),
+            ("2001:db8::21", false),
+            ("::", false),
+        ];
+        for (address, blocked) in cases {
+            assert_eq!(filter.is_blocked(ip(address)), blocked, "{}", address);
+        }
+    }
+}
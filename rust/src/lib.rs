@@ -1,57 +1,164 @@
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. */
 mod api;
+mod announce;
+mod blocklist;
+mod completion;
+mod create;
+mod dht;
+mod interface;
+mod ip_filter;
+mod meta_v2;
+mod port_mapping;
+mod priorities;
+mod proxy;
+mod queue;
+pub mod storage;
+mod streaming;
+#[cfg(test)]
+mod test_util;
+mod torrent_url;
+mod torrents;
+mod web_seeds;
 
-use std::sync::Arc;
-use tokio::sync::OnceCell;
-use librqbit::Session;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::Mutex;
+use librqbit::{Session, SessionOptions};
+use rand::Rng;
+use crate::api::config::{session_config, SessionConfig};
 
-static SESSION: OnceCell<Arc<Session>> = OnceCell::const_new();
+static SESSION: Mutex<Option<Arc<Session>>> = Mutex::const_new(None);
+/// Settings the running session was started with, to fall back to when new
+/// ones keep a restarted session from starting
+static STARTED_WITH: std::sync::Mutex<Option<SessionConfig>> = std::sync::Mutex::new(None);
 
-/// Get or initialize the global librqbit session
-/// Tries multiple paths for Android compatibility
-pub async fn get_session() -> anyhow::Result<Arc<Session>> {
-    // Check if already initialized
-    if let Some(session) = SESSION.get() {
-        return Ok(session.clone());
-    }
-    
-    use librqbit::SessionOptions;
-    
-    // Try multiple paths - Android app data, then fallback paths
-    let paths_to_try = vec![
+/// Directories tried, in order, for the session and the app's own files.
+/// Several for Android compatibility
+fn data_dirs() -> Vec<PathBuf> {
+    vec![
         // Android internal app data (typical for BlueStacks/emulators)
-        "/data/data/com.example.torrent_app/files/torrent_session".to_string(),
+        "/data/data/com.example.torrent_app/files/torrent_session".into(),
         // Android external storage
-        "/storage/emulated/0/Android/data/com.example.torrent_app/files".to_string(),
+        "/storage/emulated/0/Android/data/com.example.torrent_app/files".into(),
         // Generic app data path
-        "/data/user/0/com.example.torrent_app/files/torrent_session".to_string(),
+        "/data/user/0/com.example.torrent_app/files/torrent_session".into(),
         // Linux/Desktop fallback
-        "/tmp/torrent_dr_session".to_string(),
+        "/tmp/torrent_dr_session".into(),
         // Windows fallback
-        std::env::temp_dir().join("torrent_dr_session").to_string_lossy().to_string(),
-    ];
-    
+        std::env::temp_dir().join("torrent_dr_session"),
+    ]
+}
+
+/// Directory for the files the app keeps between runs (config, ban list):
+/// the first of the data directories that can be created
+pub(crate) fn app_data_dir() -> &'static PathBuf {
+    static DIR: OnceLock<PathBuf> = OnceLock::new();
+    DIR.get_or_init(|| {
+        data_dirs()
+            .into_iter()
+            .find(|dir| std::fs::create_dir_all(dir).is_ok())
+            .unwrap_or_else(std::env::temp_dir)
+    })
+}
+
+/// Get or initialize the global librqbit session
+pub async fn get_session() -> anyhow::Result<Arc<Session>> {
+    let mut session = SESSION.lock().await;
+    if let Some(session) = session.as_ref() {
+        return Ok(session.clone());
+    }
+    let new_session = start_session().await?;
+    *session = Some(new_session.clone());
+    drop(session);
+
+    // Left over from a restart that no session could be started for
+    torrents::restore_all(&new_session, torrents::take_all_unadded());
+    Ok(new_session)
+}
+
+/// Options for a new session, from the persisted settings
+async fn session_options() -> SessionOptions {
+    // All persistence disabled for Android compatibility
+    let mut options = SessionOptions {
+        disable_dht: false,            // Keep DHT enabled
+        disable_dht_persistence: true, // No DHT persistence
+        persistence: None,             // No session persistence
+        peer_id: Some(*announce::PEER_ID), // Shared with our own tracker announces
+        ..Default::default()
+    };
+
+    match blocklist::write_blocklist() {
+        Ok(Some(path)) => {
+            options.blocklist_url = url::Url::from_file_path(&path).ok().map(String::from);
+        }
+        Ok(None) => {}
+        Err(e) => println!("SESSION: blocklist not applied: {:#}", e),
+    }
+
+    options.listen_port_range = listen_port().map(|port| port..port + 1);
+    options.socks_proxy_url = proxy::peer_proxy_url();
+
+    // The DHT runs without torrents, so the watchdog can't stop it
+    if !interface::is_present(&session_config()) {
+        println!("SESSION: bind address not available, DHT disabled");
+        options.disable_dht = true;
+    }
+
+    if !options.disable_dht {
+        match dht::persistence_config().await {
+            Ok(Some(config)) => {
+                options.disable_dht_persistence = false;
+                options.dht_config = Some(config);
+            }
+            Ok(None) => {}
+            Err(e) => println!("SESSION: DHT settings not applied: {:#}", e),
+        }
+    }
+    options
+}
+
+/// A free port for incoming peers: the configured one, or a random one from
+/// the configured range. None if it isn't set or no port is free.
+fn listen_port() -> Option<u16> {
+    let config = session_config();
+    let min = config.listen_port?;
+    let max = config.listen_port_max.unwrap_or(min).max(min);
+
+    // Every port is tried, starting from a random one
+    let count = u32::from(max - min) + 1;
+    let offset = rand::thread_rng().gen_range(0..count);
+    let port = (0..count)
+        .map(|i| min + ((offset + i) % count) as u16)
+        .find(|port| std::net::TcpListener::bind((std::net::Ipv4Addr::UNSPECIFIED, *port)).is_ok());
+    if port.is_none() {
+        println!("SESSION: no free listen port in {}-{}, not accepting peers", min, max);
+    }
+    port
+}
+
+/// Create a session, trying each data directory in turn
+async fn start_session() -> anyhow::Result<Arc<Session>> {
+    // A config file edited by hand may ask for it
+    api::config::check_encryption(session_config().encryption)?;
+
     let mut last_error: Option<anyhow::Error> = None;
     
-    for path in paths_to_try {
-        // Options with all persistence disabled for Android compatibility
-        let options = SessionOptions {
-            disable_dht: false,            // Keep DHT enabled
-            disable_dht_persistence: true, // No DHT persistence
-            persistence: None,             // No session persistence
-            ..Default::default()
-        };
-
+    for path in data_dirs() {
         // Try to create directory
         let _ = std::fs::create_dir_all(&path);
         
-        match Session::new_with_opts(path.clone().into(), options).await {
+        match Session::new_with_opts(path.clone(), session_options().await).await {
             Ok(session) => {
-                let _ = SESSION.set(session.clone());
-                return Ok(SESSION.get().unwrap().clone());
+                let config = session_config();
+                *STARTED_WITH.lock().unwrap_or_else(|e| e.into_inner()) = Some(config.clone());
+                api::network::reset_incoming_seen();
+                port_mapping::start(session.tcp_listen_port(), config.upnp, config.natpmp).await;
+                interface::spawn_watchdog();
+                queue::spawn_manager();
+                return Ok(session);
             }
             Err(e) => {
-                last_error = Some(anyhow::anyhow!("Path {} failed: {}", path, e));
+                last_error = Some(anyhow::anyhow!("Path {:?} failed: {}", path, e));
                 continue;
             }
         }
@@ -61,5 +168,98 @@ pub async fn get_session() -> anyhow::Result<Arc<Session>> {
     Err(last_error.unwrap_or_else(|| anyhow::anyhow!("No valid session path found")))
 }
 
+/// Replace the session with a new one built from the current settings, and
+/// add every torrent back to it under the same id. If the new settings fail,
+/// the session is started with the previous ones and the error returned; if
+/// that fails too, the torrents wait for the next session to be started.
+pub(crate) async fn replace_session() -> anyhow::Result<()> {
+    let mut session = SESSION.lock().await;
+    let saved = match session.take() {
+        Some(old) => {
+            let saved = torrents::save_all(&old);
+            old.stop().await;
+            saved
+        }
+        None => Vec::new(),
+    };
+    
+    let (new_session, result) = match start_session().await {
+        Ok(new_session) => (new_session, Ok(())),
+        Err(e) => {
+            // Back to the settings the old session ran with, so the torrents
+            // still have a session
+            let previous = STARTED_WITH.lock().unwrap_or_else(|e| e.into_inner()).clone();
+            let fallback = match previous {
+                Some(previous) => api::config::with_config(previous, start_session()).await,
+                None => Err(anyhow::anyhow!("No previous settings")),
+            };
+            match fallback {
+                Ok(new_session) => {
+                    let e = e.context("The new settings were not applied, the session runs with the previous ones");
+                    (new_session, Err(e))
+                }
+                Err(_) => {
+                    torrents::keep_unadded(saved);
+                    return Err(e);
+                }
+            }
+        }
+    };
+    *session = Some(new_session.clone());
+    drop(session);
+    
+    torrents::restore_all(&new_session, saved);
+    result
+}
+
 // Re-export types used in Flutter API
-pub use api::simple::{TorrentInfo, FileInfo, AppTorrentStatus};
+pub use api::simple::{TorrentInfo, FileInfo, FilePriority, AppTorrentStatus};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librqbit::api::TorrentIdOrHash;
+    use librqbit::{AddTorrent, AddTorrentOptions};
+    use crate::api::config::{with_config, EncryptionPolicy};
+    use crate::storage::StorageLocation;
+    use crate::test_util::{content, create_torrent, wait_for, with_session};
+
+    #[test]
+    fn restarts_fall_back_to_the_previous_settings() {
+        with_session(async {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join("a.bin"), content(20_000)).unwrap();
+            let created = create_torrent(&dir.path().join("a.bin"), None);
+            let location = StorageLocation::new(dir.path(), false);
+            let id = torrents::allocate_id();
+            torrents::register(id, location.clone(), None);
+            get_session().await.unwrap()
+                .add_torrent(AddTorrent::from_bytes(created.torrent_bytes), Some(AddTorrentOptions {
+                    preferred_id: Some(id),
+                    ..torrents::download_options(&location)
+                }))
+                .await.unwrap();
+
+            // Settings a session can't be started with
+            let broken = SessionConfig { encryption: EncryptionPolicy::Require, ..session_config() };
+            let err = with_config(broken.clone(), replace_session()).await.err().unwrap();
+            let err = format!("{:#}", err);
+            assert!(err.contains("runs with the previous ones") && err.contains("Required encryption"), "{}", err);
+
+            let session = get_session().await.unwrap();
+            wait_for("the torrent to be restored", || session.get(TorrentIdOrHash::Id(id)).is_some()).await;
+            assert!(!torrents::is_readding(id));
+
+            // With the previous settings failing too, there is no session and
+            // the torrents wait for the next one
+            *STARTED_WITH.lock().unwrap() = Some(broken.clone());
+            let err = with_config(broken, replace_session()).await.err().unwrap();
+            assert!(format!("{:#}", err).contains("Required encryption"), "{:#}", err);
+
+            let session = get_session().await.unwrap();
+            wait_for("the torrent to be restored", || session.get(TorrentIdOrHash::Id(id)).is_some()).await;
+            assert!(!torrents::is_readding(id));
+            session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+}
@@ -0,0 +1,296 @@
+// BitTorrent v2 metadata (BEP 52).
+//
+// librqbit only reads v1 metadata: hybrid torrents, which carry both, are
+// downloaded as v1 torrents, and v2-only torrents can't be added at all.
+// Their v2 parts are read here so they can be shown: the SHA-256 info hash
+// and the file tree, with the merkle root of each file.
+use anyhow::{bail, Context, Result};
+use librqbit::{ByteBufOwned, FileIteratorName, TorrentMetaV1Info};
+use librqbit_bencode::{BencodeDeserializer, BencodeValue};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+#[derive(Deserialize)]
+struct Torrent {
+    info: Info,
+}
+
+#[derive(Deserialize)]
+struct Info {
+    name: Option<ByteBufOwned>,
+    #[serde(rename = "piece length")]
+    piece_length: u64,
+    // Only in v1 and hybrid torrents
+    pieces: Option<ByteBufOwned>,
+    #[serde(rename = "meta version")]
+    meta_version: Option<u64>,
+    #[serde(rename = "file tree")]
+    file_tree: Option<BencodeValue<ByteBufOwned>>,
+}
+
+/// The v2 parts of a torrent's metadata
+pub struct V2Metadata {
+    pub info_hash: [u8; 32],
+    /// Also has v1 metadata, which librqbit downloads it with
+    pub hybrid: bool,
+    pub name: String,
+    pub piece_length: u64,
+    /// Files in tree order, which is path order
+    pub files: Vec<V2File>,
+}
+
+pub struct V2File {
+    /// Path within the torrent; for single-file torrents only the name
+    pub path: Vec<String>,
+    pub length: u64,
+    /// None for empty files
+    pub pieces_root: Option<[u8; 32]>,
+}
+
+impl V2Metadata {
+    /// The SHA-256 info hash, as 64 hex digits
+    pub fn info_hash_hex(&self) -> String {
+        hex::encode(self.info_hash)
+    }
+
+    /// The info hash truncated to 20 bytes, which v2 swarms use on the wire
+    pub fn truncated_info_hash(&self) -> String {
+        hex::encode(&self.info_hash[..20])
+    }
+
+    /// Merkle roots of the files of the torrent's v1 metadata, by index.
+    /// Files are matched by path; padding files have none.
+    pub fn merkle_roots(&self, info: &TorrentMetaV1Info<ByteBufOwned>) -> Result<Vec<Option<[u8; 32]>>> {
+        Ok(info.iter_file_details()?
+            .map(|details| {
+                let path: Vec<String> = match details.filename {
+                    FileIteratorName::Single(_) => vec![self.name.clone()],
+                    FileIteratorName::Tree(parts) => parts.iter()
+                        .map(|p| String::from_utf8_lossy(p.as_ref()).to_string())
+                        .collect(),
+                };
+                self.files.iter().find(|f| f.path == path).and_then(|f| f.pieces_root)
+            })
+            .collect())
+    }
+}
+
+/// The v2 parts of a torrent file's metadata, None for v1-only torrents
+pub fn parse(torrent_bytes: &[u8]) -> Result<Option<V2Metadata>> {
+    // The deserializer keeps the raw info dictionary, for the info hash
+    let mut de = BencodeDeserializer::new_from_buf(torrent_bytes);
+    de.is_torrent_info = true;
+    let torrent = Torrent::deserialize(&mut de).context("Invalid torrent metadata")?;
+    let info_bytes = de.torrent_info_bytes.context("Torrent has no info dictionary")?;
+    let info = torrent.info;
+
+    if info.meta_version != Some(2) {
+        return Ok(None);
+    }
+    let name = info.name.as_ref()
+        .map(|n| String::from_utf8_lossy(n.as_ref()).to_string())
+        .unwrap_or_default();
+    let mut files = Vec::new();
+    match &info.file_tree {
+        Some(tree) => walk_tree(tree, &mut Vec::new(), &mut files)?,
+        None => bail!("v2 torrent has no file tree"),
+    }
+
+    Ok(Some(V2Metadata {
+        info_hash: Sha256::digest(info_bytes).into(),
+        hybrid: info.pieces.is_some(),
+        name,
+        piece_length: info.piece_length,
+        files,
+    }))
+}
+
+/// Flatten a file tree: directories are dictionaries keyed by name, and
+/// files dictionaries with a single "" key holding their length and root
+fn walk_tree(node: &BencodeValue<ByteBufOwned>, path: &mut Vec<String>, files: &mut Vec<V2File>) -> Result<()> {
+    let BencodeValue::Dict(entries) = node else {
+        bail!("Invalid file tree entry: {}", path.join("/"));
+    };
+    if let Some(BencodeValue::Dict(file)) = entries.get(&ByteBufOwned::from(&b""[..])) {
+        let length = match file.get(&ByteBufOwned::from(&b"length"[..])) {
+            Some(BencodeValue::Integer(length)) if *length >= 0 => *length as u64,
+            _ => bail!("File without a length in the file tree: {}", path.join("/")),
+        };
+        let pieces_root = match file.get(&ByteBufOwned::from(&b"pieces root"[..])) {
+            Some(BencodeValue::Bytes(root)) => Some(<[u8; 32]>::try_from(root.as_ref())
+                .ok()
+                .context(format!("Invalid merkle root for {}", path.join("/")))?),
+            _ => None,
+        };
+        files.push(V2File { path: path.clone(), length, pieces_root });
+        return Ok(());
+    }
+
+    let mut names: Vec<&ByteBufOwned> = entries.keys().collect();
+    names.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+    for name in names {
+        path.push(String::from_utf8_lossy(name.as_ref()).to_string());
+        walk_tree(&entries[name], path, files)?;
+        path.pop();
+    }
+    Ok(())
+}
+
+/// Parse the hash of a "urn:btmh:" magnet topic: a SHA-256 multihash
+pub fn parse_btmh(topic: &str) -> Result<[u8; 32]> {
+    let digest = topic.strip_prefix("1220")
+        .context(format!("Unsupported multihash, expected SHA-256: {}", topic))?;
+    let mut hash = [0u8; 32];
+    hex::decode_to_slice(digest, &mut hash)
+        .map_err(|_| anyhow::anyhow!("Invalid v2 info hash: {}", digest))?;
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librqbit::torrent_from_bytes;
+
+    const ROOT_A: [u8; 32] = [0xaa; 32];
+    const ROOT_B: [u8; 32] = [0xbb; 32];
+
+    /// A bencoded string
+    fn string(s: &[u8]) -> Vec<u8> {
+        [format!("{}:", s.len()).as_bytes(), s].concat()
+    }
+
+    /// A bencoded dictionary of already encoded values, keys in order
+    fn dict(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut out = b"d".to_vec();
+        for (key, value) in entries {
+            out.extend(string(key.as_bytes()));
+            out.extend(value);
+        }
+        out.push(b'e');
+        out
+    }
+
+    fn int(n: u64) -> Vec<u8> {
+        format!("i{}e", n).into_bytes()
+    }
+
+    fn list(items: &[Vec<u8>]) -> Vec<u8> {
+        [b"l".to_vec(), items.concat(), b"e".to_vec()].concat()
+    }
+
+    fn tree_file(length: u64, root: Option<[u8; 32]>) -> Vec<u8> {
+        let mut entries = vec![("length", int(length))];
+        if let Some(root) = root {
+            entries.push(("pieces root", string(&root)));
+        }
+        dict(&[("", dict(&entries))])
+    }
+
+    /// "album" with dir/a.bin, dir/b.bin and an empty file, v2 or hybrid
+    fn info(hybrid: bool) -> Vec<u8> {
+        let tree = dict(&[
+            ("dir", dict(&[
+                ("a.bin", tree_file(20_000, Some(ROOT_A))),
+                ("b.bin", tree_file(100, Some(ROOT_B))),
+            ])),
+            ("empty.txt", tree_file(0, None)),
+        ]);
+        let v1_file = |length, path: &[&[u8]], attr: Option<&[u8]>| {
+            let mut entries = Vec::new();
+            if let Some(attr) = attr {
+                entries.push(("attr", string(attr)));
+            }
+            entries.push(("length", int(length)));
+            entries.push(("path", list(&path.iter().map(|p| string(p)).collect::<Vec<_>>())));
+            dict(&entries)
+        };
+        let mut entries = vec![("file tree", tree)];
+        if hybrid {
+            entries.push(("files", list(&[
+                v1_file(20_000, &[b"dir", b"a.bin"], None),
+                v1_file(12_768, &[b".pad", b"12768"], Some(b"p")),
+                v1_file(100, &[b"dir", b"b.bin"], None),
+                v1_file(0, &[b"empty.txt"], None),
+            ])));
+        }
+        entries.push(("meta version", int(2)));
+        entries.push(("name", string(b"album")));
+        entries.push(("piece length", int(32 * 1024)));
+        if hybrid {
+            entries.push(("pieces", string(&[0u8; 40])));
+        }
+        dict(&entries)
+    }
+
+    fn torrent(info: &[u8]) -> Vec<u8> {
+        // Keys around the info dictionary stay out of the info hash
+        [&b"d"[..], &string(b"announce"), &string(b"http://t.example/announce"), &string(b"info"), info, &string(b"zz"), &int(1), b"e"].concat()
+    }
+
+    #[test]
+    fn info_hash_covers_the_raw_info_dictionary() {
+        let info = info(false);
+        let v2 = parse(&torrent(&info)).unwrap().unwrap();
+        let expected: [u8; 32] = Sha256::digest(&info).into();
+        assert_eq!(v2.info_hash, expected);
+        assert_eq!(v2.info_hash_hex(), hex::encode(expected));
+        assert_eq!(v2.truncated_info_hash(), hex::encode(&expected[..20]));
+        assert!(!v2.hybrid);
+        assert_eq!((v2.name.as_str(), v2.piece_length), ("album", 32 * 1024));
+    }
+
+    #[test]
+    fn file_trees_are_walked_in_path_order() {
+        let v2 = parse(&torrent(&info(false))).unwrap().unwrap();
+        let files: Vec<(String, u64, Option<[u8; 32]>)> = v2.files.iter()
+            .map(|f| (f.path.join("/"), f.length, f.pieces_root))
+            .collect();
+        assert_eq!(files, vec![
+            ("dir/a.bin".to_string(), 20_000, Some(ROOT_A)),
+            ("dir/b.bin".to_string(), 100, Some(ROOT_B)),
+            ("empty.txt".to_string(), 0, None),
+        ]);
+
+        let bad_root = dict(&[("info", dict(&[
+            ("file tree", dict(&[("x", dict(&[("", dict(&[("length", int(1)), ("pieces root", string(b"short"))]))]))])),
+            ("meta version", int(2)),
+            ("piece length", int(16384)),
+        ]))]);
+        assert!(parse(&bad_root).is_err());
+        let no_length = dict(&[("info", dict(&[
+            ("file tree", dict(&[("x", dict(&[("", dict(&[]))]))])),
+            ("meta version", int(2)),
+            ("piece length", int(16384)),
+        ]))]);
+        assert!(parse(&no_length).is_err());
+    }
+
+    #[test]
+    fn v1_torrents_have_no_v2_metadata() {
+        let info = dict(&[
+            ("length", int(5)),
+            ("name", string(b"a.txt")),
+            ("piece length", int(16384)),
+            ("pieces", string(&[0u8; 20])),
+        ]);
+        assert!(parse(&torrent(&info)).unwrap().is_none());
+    }
+
+    #[test]
+    fn merkle_roots_follow_the_v1_files() {
+        let bytes = torrent(&info(true));
+        let v2 = parse(&bytes).unwrap().unwrap();
+        assert!(v2.hybrid);
+        let v1 = torrent_from_bytes::<ByteBufOwned>(&bytes).unwrap();
+        // The padding file and the empty file have none
+        assert_eq!(v2.merkle_roots(&v1.info).unwrap(), vec![Some(ROOT_A), None, Some(ROOT_B), None]);
+    }
+
+    #[test]
+    fn btmh_topics_must_be_sha256() {
+        let hash = [0x12; 32];
+        assert_eq!(parse_btmh(&format!("1220{}", hex::encode(hash))).unwrap(), hash);
+        assert!(parse_btmh(&format!("1114{}", hex::encode(&hash[..20]))).is_err());
+        assert!(parse_btmh("1220abcd").is_err());
+    }
+}
@@ -0,0 +1,619 @@
+// Mapping the session's listen port on the router.
+//
+// librqbit can forward its port with UPnP, but only logs how that went and
+// has no NAT-PMP, so the mapping is kept here instead: the gateway is asked
+// over NAT-PMP and/or UPnP IGD, whichever is enabled and answers first, and
+// the mapping is renewed at half its lease for as long as the session runs.
+// NAT-PMP needs the gateway's address, which is read from the route table
+// where there is one (Linux, Android) and found with SSDP elsewhere.
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use anyhow::{bail, Context, Result};
+use librqbit_upnp::{Device, Service};
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+use url::Url;
+
+/// Lease asked for; gateways may grant less
+const LEASE: Duration = Duration::from_secs(3600);
+/// Wait after every gateway failed to map the port
+const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+/// Lower bound for renewals, whatever lease was granted
+const MIN_RENEW_INTERVAL: Duration = Duration::from_secs(30);
+/// How long gateways get to answer the SSDP search
+const SSDP_TIMEOUT: Duration = Duration::from_secs(3);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const NATPMP_PORT: u16 = 5351;
+/// NAT-PMP requests are sent again after 250ms, 500ms, ... (RFC 6886 3.1)
+const NATPMP_ATTEMPTS: u32 = 4;
+/// SSDP search target answered by routers, to find the gateway where there
+/// is no route table to read
+const SSDP_SEARCH_IGD_ST: &str = "urn:schemas-upnp-org:device:InternetGatewayDevice:1";
+/// IGD services that can map ports, preferred in this order
+const UPNP_SERVICES: [&str; 3] = [
+    "urn:schemas-upnp-org:service:WANIPConnection:2",
+    "urn:schemas-upnp-org:service:WANIPConnection:1",
+    "urn:schemas-upnp-org:service:WANPPPConnection:1",
+];
+
+static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+});
+
+/// A gateway that can map ports
+#[derive(Debug, Clone)]
+pub enum Gateway {
+    NatPmp(SocketAddr),
+    Upnp {
+        control_url: Url,
+        service_type: String,
+        /// Our address on the gateway's network, where it forwards to
+        local_ip: IpAddr,
+    },
+}
+
+/// The listen port as mapped on a gateway
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    pub gateway: Gateway,
+    pub local_port: u16,
+    pub external_port: u16,
+    pub external_ip: Option<IpAddr>,
+}
+
+impl Mapping {
+    pub fn protocol(&self) -> &'static str {
+        match self.gateway {
+            Gateway::NatPmp(_) => "NAT-PMP",
+            Gateway::Upnp { .. } => "UPnP",
+        }
+    }
+}
+
+#[derive(Default)]
+struct MapperState {
+    task: Option<JoinHandle<()>>,
+    mapping: Option<Mapping>,
+    error: Option<String>,
+}
+
+static STATE: LazyLock<Mutex<MapperState>> = LazyLock::new(Mutex::default);
+
+fn with_state<R>(f: impl FnOnce(&mut MapperState) -> R) -> R {
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut state)
+}
+
+/// The current mapping, and why the last attempt failed if there is none
+pub fn status() -> (Option<Mapping>, Option<String>) {
+    with_state(|s| (s.mapping.clone(), s.error.clone()))
+}
+
+/// Port to announce to trackers: the external one if the gateway mapped
+/// the listen port to another
+pub fn announce_port(listen_port: Option<u16>) -> Option<u16> {
+    with_state(|s| s.mapping.as_ref().map(|m| m.external_port)).or(listen_port)
+}
+
+/// Map `port` for a new session, replacing the previous session's mapping.
+/// With no port, or neither protocol enabled, the previous mapping is only
+/// removed.
+pub async fn start(port: Option<u16>, upnp: bool, natpmp: bool) {
+    let previous = with_state(|s| {
+        if let Some(task) = s.task.take() {
+            task.abort();
+        }
+        s.error = None;
+        s.mapping.take()
+    });
+    if let Some(mapping) = previous {
+        if let Err(e) = unmap(&mapping).await {
+            println!("PORTMAP: failed to remove the mapping of port {}: {:#}", mapping.local_port, e);
+        }
+    }
+
+    let Some(port) = port.filter(|_| upnp || natpmp) else {
+        return;
+    };
+    let task = tokio::spawn(run(port, upnp, natpmp));
+    with_state(|s| s.task = Some(task));
+}
+
+async fn run(port: u16, upnp: bool, natpmp: bool) {
+    let mut external_port = None;
+    loop {
+        let renew_in = match map(port, upnp, natpmp, external_port).await {
+            Ok((mapping, lease)) => {
+                if external_port != Some(mapping.external_port) {
+                    println!("PORTMAP: port {} mapped to {} with {}", port, mapping.external_port, mapping.protocol());
+                }
+                external_port = Some(mapping.external_port);
+                with_state(|s| {
+                    s.mapping = Some(mapping);
+                    s.error = None;
+                });
+                (lease / 2).max(MIN_RENEW_INTERVAL)
+            }
+            Err(e) => {
+                println!("PORTMAP: failed to map port {}: {:#}", port, e);
+                external_port = None;
+                with_state(|s| {
+                    s.mapping = None;
+                    s.error = Some(format!("{:#}", e));
+                });
+                RETRY_INTERVAL
+            }
+        };
+        tokio::time::sleep(renew_in).await;
+    }
+}
+
+/// Map `port` with the first enabled protocol that works, returning the
+/// mapping and its lease
+async fn map(port: u16, upnp: bool, natpmp: bool, external_port: Option<u16>) -> Result<(Mapping, Duration)> {
+    let mut errors = Vec::new();
+    if natpmp {
+        let result = match default_gateway().await {
+            Ok(gateway) => natpmp_map(SocketAddr::from((gateway, NATPMP_PORT)), port, external_port).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(mapping) => return Ok(mapping),
+            Err(e) => errors.push(format!("NAT-PMP: {:#}", e)),
+        }
+    }
+    if upnp {
+        match upnp_map(port).await {
+            Ok(mapping) => return Ok(mapping),
+            Err(e) => errors.push(format!("UPnP: {:#}", e)),
+        }
+    }
+    bail!("{}", errors.join("; "))
+}
+
+async fn unmap(mapping: &Mapping) -> Result<()> {
+    match &mapping.gateway {
+        Gateway::NatPmp(gateway) => {
+            natpmp_request(*gateway, &natpmp_mapping_request(mapping.local_port, 0, Duration::ZERO), 130).await?;
+        }
+        Gateway::Upnp { control_url, service_type, .. } => {
+            soap(control_url, service_type, "DeletePortMapping", &[
+                ("NewRemoteHost", String::new()),
+                ("NewExternalPort", mapping.external_port.to_string()),
+                ("NewProtocol", "TCP".to_string()),
+            ]).await?;
+        }
+    }
+    Ok(())
+}
+
+/// The IPv4 default gateway: from the kernel's route table where there is
+/// one (Linux, Android), otherwise the router answering an SSDP search for
+/// an IGD
+async fn default_gateway() -> Result<Ipv4Addr> {
+    let routes = std::fs::read_to_string("/proc/net/route").ok();
+    if let Some(gateway) = routes.as_deref().and_then(route_table_gateway) {
+        return Ok(gateway);
+    }
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    librqbit_upnp::discover_once(&tx, SSDP_SEARCH_IGD_ST, SSDP_TIMEOUT).await?;
+    drop(tx);
+    while let Some(response) = rx.recv().await {
+        if let IpAddr::V4(ip) = response.received_from.ip() {
+            return Ok(ip);
+        }
+    }
+    bail!("No default gateway found with SSDP")
+}
+
+/// The default route's gateway in the format of /proc/net/route
+fn route_table_gateway(routes: &str) -> Option<Ipv4Addr> {
+    for line in routes.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 3 || fields[1] != "00000000" {
+            continue;
+        }
+        // Stored in host byte order, printed as hex
+        if let Ok(gateway) = u32::from_str_radix(fields[2], 16) {
+            if gateway != 0 {
+                return Some(Ipv4Addr::from(gateway.to_le_bytes()));
+            }
+        }
+    }
+    None
+}
+
+fn natpmp_mapping_request(local_port: u16, external_port: u16, lease: Duration) -> Vec<u8> {
+    let mut request = vec![0, 2, 0, 0]; // Version 0, map TCP, reserved
+    request.extend_from_slice(&local_port.to_be_bytes());
+    request.extend_from_slice(&external_port.to_be_bytes());
+    request.extend_from_slice(&(lease.as_secs() as u32).to_be_bytes());
+    request
+}
+
+/// Send a NAT-PMP request and return the response, checked for `opcode`
+/// and a success result code
+async fn natpmp_request(gateway: SocketAddr, request: &[u8], opcode: u8) -> Result<Vec<u8>> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+    socket.connect(gateway).await?;
+    let mut buf = [0u8; 16];
+    let mut wait = Duration::from_millis(250);
+    for _ in 0..NATPMP_ATTEMPTS {
+        socket.send(request).await?;
+        if let Ok(received) = tokio::time::timeout(wait, socket.recv(&mut buf)).await {
+            let len = received?;
+            if len < 8 || buf[0] != 0 || buf[1] != opcode {
+                bail!("Invalid NAT-PMP response");
+            }
+            match u16::from_be_bytes([buf[2], buf[3]]) {
+                0 => return Ok(buf[..len].to_vec()),
+                2 => bail!("Mapping refused by the gateway (not authorized)"),
+                3 => bail!("Gateway has no external address yet"),
+                4 => bail!("Gateway is out of ports"),
+                code => bail!("Gateway returned error {}", code),
+            }
+        }
+        wait *= 2;
+    }
+    bail!("No response from {}", gateway)
+}
+
+/// Map `port` with the NAT-PMP gateway, asking for `external_port` (the
+/// current one when renewing) or the same port
+async fn natpmp_map(gateway: SocketAddr, port: u16, external_port: Option<u16>) -> Result<(Mapping, Duration)> {
+    let request = natpmp_mapping_request(port, external_port.unwrap_or(port), LEASE);
+    let response = natpmp_request(gateway, &request, 130).await?;
+    if response.len() < 16 {
+        bail!("Invalid NAT-PMP response");
+    }
+    let external_port = u16::from_be_bytes([response[10], response[11]]);
+    let lease = u32::from_be_bytes([response[12], response[13], response[14], response[15]]);
+
+    let external_ip = natpmp_request(gateway, &[0, 0], 128).await.ok()
+        .filter(|response| response.len() >= 12)
+        .map(|response| IpAddr::from([response[8], response[9], response[10], response[11]]));
+
+    Ok((Mapping {
+        gateway: Gateway::NatPmp(gateway),
+        local_port: port,
+        external_port,
+        external_ip,
+    }, Duration::from_secs(lease.into())))
+}
+
+/// Find IGDs with an SSDP search and map `port` with the first that accepts
+async fn upnp_map(port: u16) -> Result<(Mapping, Duration)> {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    librqbit_upnp::discover_once(&tx, librqbit_upnp::SSDP_SEARCH_ROOT_ST, SSDP_TIMEOUT).await?;
+    drop(tx);
+
+    let mut last_error = None;
+    while let Some(response) = rx.recv().await {
+        let result = match upnp_gateway(response.location, response.received_from).await {
+            Ok(gateway) => upnp_add(gateway, port).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(mapping) => return Ok(mapping),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| anyhow::anyhow!("No gateway found")))
+}
+
+/// The port mapping service of the device described at `location`, which
+/// answered the search from `received_from`
+async fn upnp_gateway(location: Url, received_from: SocketAddr) -> Result<Gateway> {
+    fn services(devices: &[Device]) -> Vec<&Service> {
+        devices.iter()
+            .flat_map(|d| d.service_list.services.iter().chain(services(&d.device_list.devices)))
+            .collect()
+    }
+
+    let description = librqbit_upnp::discover_services(location.clone()).await?;
+    let all = services(&description.devices);
+    let service = UPNP_SERVICES.iter()
+        .find_map(|kind| all.iter().find(|s| s.service_type == *kind))
+        .context(format!("No port mapping service at {}", location))?;
+    Ok(Gateway::Upnp {
+        control_url: location.join(&service.control_url)?,
+        service_type: service.service_type.clone(),
+        local_ip: librqbit_upnp::get_local_ip_relative_to(received_from)?,
+    })
+}
+
+/// Map `port` to the same external port on a UPnP gateway
+async fn upnp_add(gateway: Gateway, port: u16) -> Result<(Mapping, Duration)> {
+    let Gateway::Upnp { control_url, service_type, local_ip } = &gateway else {
+        bail!("Not a UPnP gateway");
+    };
+    let add = |lease: Duration| async move {
+        soap(control_url, service_type, "AddPortMapping", &[
+            ("NewRemoteHost", String::new()),
+            ("NewExternalPort", port.to_string()),
+            ("NewProtocol", "TCP".to_string()),
+            ("NewInternalPort", port.to_string()),
+            ("NewInternalClient", local_ip.to_string()),
+            ("NewEnabled", "1".to_string()),
+            ("NewPortMappingDescription", "torrent_app".to_string()),
+            ("NewLeaseDuration", lease.as_secs().to_string()),
+        ]).await
+    };
+    match add(LEASE).await {
+        Ok(_) => {}
+        // Some IGDs only take permanent mappings; they are renewed all the same
+        Err(e) if e.to_string().contains("error 725") => {
+            add(Duration::ZERO).await?;
+        }
+        Err(e) => return Err(e),
+    }
+
+    let external_ip = soap(control_url, service_type, "GetExternalIPAddress", &[]).await.ok()
+        .and_then(|response| xml_value(&response, "NewExternalIPAddress")?.parse().ok());
+
+    Ok((Mapping {
+        gateway: gateway.clone(),
+        local_port: port,
+        external_port: port,
+        external_ip,
+    }, LEASE))
+}
+
+/// Call a UPnP action and return the response body
+async fn soap(control_url: &Url, service_type: &str, action: &str, arguments: &[(&str, String)]) -> Result<String> {
+    let mut body = format!(
+        "<?xml version=\"1.0\"?>\
+        <s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\" \
+        s:encodingStyle=\"http://schemas.xmlsoap.org/soap/encoding/\">\
+        <s:Body><u:{} xmlns:u=\"{}\">",
+        action, service_type,
+    );
+    for (name, value) in arguments {
+        body.push_str(&format!("<{0}>{1}</{0}>", name, value));
+    }
+    body.push_str(&format!("</u:{}></s:Body></s:Envelope>", action));
+
+    let response = HTTP_CLIENT.post(control_url.clone())
+        .header("Content-Type", "text/xml; charset=\"utf-8\"")
+        .header("SOAPAction", format!("\"{}#{}\"", service_type, action))
+        .body(body)
+        .send()
+        .await?;
+    let status = response.status();
+    let text = response.text().await?;
+    if !status.is_success() {
+        match xml_value(&text, "errorCode") {
+            Some(code) => bail!("{} failed with error {}: {}", action, code,
+                xml_value(&text, "errorDescription").unwrap_or_default()),
+            None => bail!("{} failed: {}", action, status),
+        }
+    }
+    Ok(text)
+}
+
+/// Text of the first `<tag>` element, whatever its namespace prefix
+fn xml_value<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let start = xml.find(&format!("{}>", tag))? + tag.len() + 1;
+    let end = start + xml[start..].find('<')?;
+    Some(xml[start..end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use crate::test_util::{HttpRequest, HttpResponse, HttpServer};
+
+    /// A NAT-PMP gateway answering with `result` and mapping to port 40000
+    /// for 7200s. Returns its address and the requests it got.
+    async fn natpmp_gateway(result: u16) -> (SocketAddr, Arc<Mutex<Vec<Vec<u8>>>>) {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = socket.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 64];
+            while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+                let request = buf[..len].to_vec();
+                recorded.lock().unwrap().push(request.clone());
+                let mut response = vec![0, 128 + request[1]];
+                response.extend_from_slice(&result.to_be_bytes());
+                response.extend_from_slice(&1234u32.to_be_bytes()); // Seconds since epoch
+                if request[1] == 0 {
+                    response.extend_from_slice(&[203, 0, 113, 7]);
+                } else {
+                    response.extend_from_slice(&request[4..6]);
+                    response.extend_from_slice(&40000u16.to_be_bytes());
+                    response.extend_from_slice(&7200u32.to_be_bytes());
+                }
+                socket.send_to(&response, from).await.unwrap();
+            }
+        });
+        (address, requests)
+    }
+
+    #[tokio::test]
+    async fn natpmp_maps_and_unmaps() {
+        let (gateway, requests) = natpmp_gateway(0).await;
+
+        let (mapping, lease) = natpmp_map(gateway, 6881, Some(6890)).await.unwrap();
+        assert_eq!(mapping.local_port, 6881);
+        assert_eq!(mapping.external_port, 40000);
+        assert_eq!(mapping.external_ip, Some(IpAddr::from([203, 0, 113, 7])));
+        assert_eq!(mapping.protocol(), "NAT-PMP");
+        assert_eq!(lease, Duration::from_secs(7200));
+
+        unmap(&mapping).await.unwrap();
+        let requests = requests.lock().unwrap().clone();
+        // Map TCP 6881 to 6890 for an hour, ask for the external address,
+        // then remove the mapping with a zero lease
+        assert_eq!(requests, vec![
+            vec![0, 2, 0, 0, 0x1a, 0xe1, 0x1a, 0xea, 0, 0, 0x0e, 0x10],
+            vec![0, 0],
+            vec![0, 2, 0, 0, 0x1a, 0xe1, 0, 0, 0, 0, 0, 0],
+        ]);
+    }
+
+    #[tokio::test]
+    async fn natpmp_errors_are_reported() {
+        // (result code, error)
+        let cases = [
+            (2, "not authorized"),
+            (3, "no external address"),
+            (4, "out of ports"),
+            (5, "error 5"),
+        ];
+        for (result, error) in cases {
+            let (gateway, _) = natpmp_gateway(result).await;
+            let err = natpmp_map(gateway, 6881, None).await.err().unwrap();
+            assert!(err.to_string().contains(error), "{}: {}", result, err);
+        }
+    }
+
+    #[tokio::test]
+    async fn natpmp_gives_up_without_response() {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let err = natpmp_map(socket.local_addr().unwrap(), 6881, None).await.err().unwrap();
+        assert!(err.to_string().contains("No response"), "{}", err);
+    }
+
+    const DESCRIPTION: &str = "<?xml version=\"1.0\"?>\
+        <root xmlns=\"urn:schemas-upnp-org:device-1-0\"><device>\
+        <deviceType>urn:schemas-upnp-org:device:InternetGatewayDevice:1</deviceType>\
+        <deviceList><device>\
+        <deviceType>urn:schemas-upnp-org:device:WANDevice:1</deviceType>\
+        <deviceList><device>\
+        <deviceType>urn:schemas-upnp-org:device:WANConnectionDevice:1</deviceType>\
+        <serviceList><service>\
+        <serviceType>urn:schemas-upnp-org:service:WANIPConnection:1</serviceType>\
+        <controlURL>/ctl/IPConn</controlURL><SCPDURL>/scpd.xml</SCPDURL>\
+        </service></serviceList>\
+        </device></deviceList>\
+        </device></deviceList>\
+        </device></root>";
+
+    fn soap_error(code: u32) -> HttpResponse {
+        HttpResponse {
+            status: 500,
+            ..HttpResponse::ok(format!(
+                "<s:Envelope><s:Body><s:Fault><detail><UPnPError>\
+                <errorCode>{}</errorCode><errorDescription>Refused</errorDescription>\
+                </UPnPError></detail></s:Fault></s:Body></s:Envelope>", code))
+        }
+    }
+
+    /// An IGD whose AddPortMapping answers with `add` for each lease
+    async fn igd(add: impl Fn(&str) -> HttpResponse + Send + Sync + 'static) -> HttpServer {
+        HttpServer::start(move |request: &HttpRequest| match request.target.as_str() {
+            "/desc.xml" => HttpResponse::ok(DESCRIPTION),
+            "/ctl/IPConn" => {
+                let body = String::from_utf8_lossy(&request.body);
+                match request.header("SOAPAction").unwrap_or_default() {
+                    a if a.ends_with("#AddPortMapping\"") => add(xml_value(&body, "NewLeaseDuration").unwrap()),
+                    a if a.ends_with("#GetExternalIPAddress\"") => HttpResponse::ok(
+                        "<s:Envelope><s:Body><u:GetExternalIPAddressResponse>\
+                        <NewExternalIPAddress>198.51.100.4</NewExternalIPAddress>\
+                        </u:GetExternalIPAddressResponse></s:Body></s:Envelope>"),
+                    _ => HttpResponse::ok(""),
+                }
+            }
+            _ => HttpResponse::status(404),
+        }).await
+    }
+
+    fn soap_actions(server: &HttpServer) -> Vec<(String, String)> {
+        server.requests().into_iter()
+            .filter(|r| r.method == "POST")
+            .map(|r| (r.header("SOAPAction").unwrap().to_string(), String::from_utf8(r.body).unwrap()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn upnp_maps_and_unmaps() {
+        let server = igd(|_| HttpResponse::ok("")).await;
+        let location = Url::parse(&server.url("/desc.xml")).unwrap();
+        let gateway = upnp_gateway(location, "127.0.0.1:1900".parse().unwrap()).await.unwrap();
+        let Gateway::Upnp { control_url, service_type, local_ip } = &gateway else {
+            panic!("Not a UPnP gateway: {:?}", gateway);
+        };
+        assert_eq!(control_url.as_str(), server.url("/ctl/IPConn"));
+        assert_eq!(service_type, UPNP_SERVICES[1]);
+        assert!(local_ip.is_loopback());
+
+        let (mapping, lease) = upnp_add(gateway.clone(), 6881).await.unwrap();
+        assert_eq!((mapping.local_port, mapping.external_port), (6881, 6881));
+        assert_eq!(mapping.external_ip, Some(IpAddr::from([198, 51, 100, 4])));
+        assert_eq!(lease, LEASE);
+        unmap(&mapping).await.unwrap();
+
+        let actions = soap_actions(&server);
+        let names: Vec<&str> = actions.iter().map(|(a, _)| a.rsplit('#').next().unwrap()).collect();
+        assert_eq!(names, ["AddPortMapping\"", "GetExternalIPAddress\"", "DeletePortMapping\""]);
+        let add = &actions[0].1;
+        assert_eq!(actions[0].0, format!("\"{}#AddPortMapping\"", UPNP_SERVICES[1]));
+        assert_eq!(xml_value(add, "NewExternalPort"), Some("6881"));
+        assert_eq!(xml_value(add, "NewInternalPort"), Some("6881"));
+        assert_eq!(xml_value(add, "NewProtocol"), Some("TCP"));
+        assert_eq!(xml_value(add, "NewInternalClient"), Some(local_ip.to_string().as_str()));
+        assert_eq!(xml_value(add, "NewLeaseDuration"), Some("3600"));
+        assert_eq!(xml_value(&actions[2].1, "NewExternalPort"), Some("6881"));
+    }
+
+    #[tokio::test]
+    async fn upnp_falls_back_to_permanent_mappings() {
+        let server = igd(|lease| if lease == "0" { HttpResponse::ok("") } else { soap_error(725) }).await;
+        let location = Url::parse(&server.url("/desc.xml")).unwrap();
+        let gateway = upnp_gateway(location, "127.0.0.1:1900".parse().unwrap()).await.unwrap();
+
+        upnp_add(gateway, 6881).await.unwrap();
+        let leases: Vec<String> = soap_actions(&server).iter()
+            .filter_map(|(_, body)| xml_value(body, "NewLeaseDuration").map(String::from))
+            .collect();
+        assert_eq!(leases, ["3600", "0"]);
+    }
+
+    #[tokio::test]
+    async fn upnp_errors_are_reported() {
+        let server = igd(|_| soap_error(718)).await;
+        let location = Url::parse(&server.url("/desc.xml")).unwrap();
+        let gateway = upnp_gateway(location, "127.0.0.1:1900".parse().unwrap()).await.unwrap();
+        let err = upnp_add(gateway, 6881).await.err().unwrap();
+        assert_eq!(err.to_string(), "AddPortMapping failed with error 718: Refused");
+
+        let missing = Url::parse(&server.url("/missing.xml")).unwrap();
+        assert!(upnp_gateway(missing, "127.0.0.1:1900".parse().unwrap()).await.is_err());
+    }
+
+    #[test]
+    fn reads_the_default_route() {
+        let header = "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT\n";
+        // (routes, gateway)
+        let cases = [
+            ("wlan0\t00000000\t0101A8C0\t0003\t0\t0\t0\t00000000\t0\t0\t0\n", Some([192, 168, 1, 1])),
+            ("wlan0\t0001A8C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\t0\t0\t0\n", None),
+            ("tun0\t00000000\t00000000\t0001\t0\t0\t0\t00000000\t0\t0\t0\n", None),
+            ("", None),
+        ];
+        for (routes, gateway) in cases {
+            let table = format!("{}{}", header, routes);
+            assert_eq!(route_table_gateway(&table), gateway.map(Ipv4Addr::from), "{:?}", routes);
+        }
+    }
+
+    #[test]
+    fn reads_xml_values() {
+        // (xml, tag, value)
+        let cases = [
+            ("<a><NewExternalIPAddress>1.2.3.4</NewExternalIPAddress></a>", "NewExternalIPAddress", Some("1.2.3.4")),
+            ("<s:x><u:errorCode> 725 </u:errorCode></s:x>", "errorCode", Some("725")),
+            ("<a><b>1</b></a>", "c", None),
+        ];
+        for (xml, tag, value) in cases {
+            assert_eq!(xml_value(xml, tag), value, "{}", xml);
+        }
+    }
+}
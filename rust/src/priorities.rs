@@ -0,0 +1,81 @@
+// Per-file priorities on top of librqbit, which only knows which files are
+// selected for download:
+//
+// - Skip: not selected
+// - Low: selected once every Normal and High file is complete
+// - Normal: selected
+// - High: selected, and read through by a stream so its pieces are requested
+//   before anything else
+use std::collections::HashSet;
+use std::sync::Arc;
+use librqbit::{ManagedTorrent, Session, TorrentStatsState};
+use crate::api::simple::FilePriority;
+use crate::streaming::{self, ReadThrough};
+use crate::torrents;
+
+/// Priorities of every file of the torrent: the ones set by the user, or
+/// derived from librqbit's selection if none were set
+pub fn file_priorities(handle: &ManagedTorrent) -> anyhow::Result<Vec<FilePriority>> {
+    let file_count = handle.with_metadata(|m| m.file_infos.len())?;
+    let mut priorities = torrents::get(handle.id())
+        .map(|e| e.file_priorities)
+        .unwrap_or_default();
+    if priorities.is_empty() {
+        let only_files = handle.only_files();
+        priorities = (0..file_count)
+            .map(|i| match &only_files {
+                Some(only) if !only.contains(&i) => FilePriority::Skip,
+                _ => FilePriority::Normal,
+            })
+            .collect();
+    }
+    priorities.resize(file_count, FilePriority::Normal);
+    Ok(priorities)
+}
+
+/// Bring librqbit's file selection and the high priority reader in line
+/// with the torrent's priorities. Does nothing until the torrent has been
+/// checked, or if no priorities were set.
+pub async fn apply(session: &Arc<Session>, handle: &Arc<ManagedTorrent>) -> anyhow::Result<()> {
+    let id = handle.id();
+    if torrents::get(id).is_none_or(|e| e.file_priorities.is_empty()) {
+        return Ok(());
+    }
+    let stats = handle.stats();
+    if !matches!(stats.state, TorrentStatsState::Live | TorrentStatsState::Paused) {
+        return Ok(());
+    }
+
+    let priorities = file_priorities(handle)?;
+    let lengths = handle.with_metadata(|m| {
+        m.file_infos.iter().map(|f| f.len).collect::<Vec<_>>()
+    })?;
+    let is_complete = |i: usize| stats.file_progress.get(i).copied().unwrap_or(0) >= lengths[i];
+
+    let others_complete = priorities.iter().enumerate()
+        .filter(|(_, p)| matches!(p, FilePriority::Normal | FilePriority::High))
+        .all(|(i, _)| is_complete(i));
+    let selected: HashSet<usize> = priorities.iter().enumerate()
+        .filter(|(_, p)| match p {
+            FilePriority::Skip => false,
+            FilePriority::Low => others_complete,
+            FilePriority::Normal | FilePriority::High => true,
+        })
+        .map(|(i, _)| i)
+        .collect();
+    let current: Option<HashSet<usize>> = handle.only_files().map(|f| f.into_iter().collect());
+    if current.as_ref() != Some(&selected) {
+        session.update_only_files(handle, &selected).await?;
+    }
+
+    let high: Vec<usize> = priorities.iter().enumerate()
+        .filter(|(i, p)| **p == FilePriority::High && !is_complete(*i))
+        .map(|(i, _)| i)
+        .collect();
+    if high.is_empty() {
+        streaming::stop_read_through(id, ReadThrough::HighPriority);
+    } else if streaming::read_through_files(id, ReadThrough::HighPriority).as_ref() != Some(&high) {
+        streaming::start_read_through(id, ReadThrough::HighPriority, high);
+    }
+    Ok(())
+}
@@ -0,0 +1,458 @@
+// Proxy settings, and checking a proxy works.
+//
+// librqbit takes a single SOCKS5 proxy URL, which it uses for peer
+// connections and its HTTP tracker announces alike; its UDP announces and
+// the DHT always go direct. The proxy is given to librqbit when peers are to
+// be proxied, and our own tracker requests use it when trackers are, which
+// requires peers to be. UDP can't be proxied, so our requests to UDP
+// trackers are skipped while trackers are.
+// Web seeds are fetched through the proxy when peers are proxied, and
+// .torrent files from URLs when trackers are.
+use std::net::IpAddr;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use url::Url;
+use crate::api::config::{session_config, ProxyConfig, ProxyType};
+use crate::interface;
+
+const TEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// Longest HTTP CONNECT response header read
+const MAX_HEADER_LEN: usize = 8192;
+
+/// Check a proxy config before it is saved
+pub fn validate(proxy: &ProxyConfig) -> Result<()> {
+    if proxy.host.is_empty() || proxy.port == 0 {
+        bail!("Proxy host and port are required");
+    }
+    if proxy.password.is_some() && proxy.username.is_none() {
+        bail!("Proxy password given without a username");
+    }
+    if proxy.proxy_peers && proxy.proxy_type != ProxyType::Socks5 {
+        bail!("Only SOCKS5 proxies can carry peer connections");
+    }
+    // librqbit announces through the peer proxy or not at all, so proxying
+    // only our own requests would still send the real address to trackers
+    if proxy.proxy_trackers && !proxy.proxy_peers {
+        bail!("Trackers can only be proxied along with peer connections");
+    }
+    let url = proxy_url(proxy)?;
+    // librqbit takes the credentials from the URL without decoding them
+    if proxy.proxy_peers
+        && (url.username() != proxy.username.as_deref().unwrap_or_default()
+            || url.password() != proxy.password.as_deref())
+    {
+        bail!("Proxy credentials for peer connections can't contain characters escaped in URLs");
+    }
+    Ok(())
+}
+
+/// The proxy as a URL: socks5:// or http://, with any credentials
+pub fn proxy_url(proxy: &ProxyConfig) -> Result<Url> {
+    let scheme = match proxy.proxy_type {
+        ProxyType::Socks5 => "socks5",
+        ProxyType::Http => "http",
+    };
+    let mut url = Url::parse(&format!("{}://{}:{}", scheme, proxy.host, proxy.port))
+        .context(format!("Invalid proxy address: {}:{}", proxy.host, proxy.port))?;
+    if let Some(username) = &proxy.username {
+        url.set_username(username).ok().context("Invalid proxy username")?;
+        url.set_password(proxy.password.as_deref()).ok().context("Invalid proxy password")?;
+    }
+    Ok(url)
+}
+
+/// Proxy for peer connections and web seeds, if peers are proxied
+pub fn peer_proxy() -> Option<Url> {
+    let proxy = session_config().proxy.filter(|p| p.proxy_peers)?;
+    proxy_url(&proxy).ok()
+}
+
+/// Proxy URL for librqbit's peer connections, if peers are proxied
+pub fn peer_proxy_url() -> Option<String> {
+    peer_proxy().map(String::from)
+}
+
+/// Proxy for our tracker announces, if trackers are proxied
+pub fn tracker_proxy() -> Option<Url> {
+    let proxy = session_config().proxy.filter(|p| p.proxy_trackers)?;
+    proxy_url(&proxy).ok()
+}
+
+/// HTTP client with the bind address and proxy it was built for
+struct HttpClient {
+    bind_ip: Option<IpAddr>,
+    proxy: Option<Url>,
+    client: reqwest::Client,
+}
+
+static HTTP_CLIENTS: LazyLock<Mutex<Vec<HttpClient>>> = LazyLock::new(Mutex::default);
+
+/// HTTP client bound to the configured interface, going through `proxy`.
+/// Clients are reused until the bind address changes.
+pub fn http_client(proxy: Option<Url>) -> Result<reqwest::Client> {
+    let bind_ip = interface::bind_ip();
+    let mut clients = HTTP_CLIENTS.lock().unwrap_or_else(|e| e.into_inner());
+    clients.retain(|c| c.bind_ip == bind_ip);
+    if let Some(current) = clients.iter().find(|c| c.proxy == proxy) {
+        return Ok(current.client.clone());
+    }
+    let client = http_client_builder(proxy.as_ref())?.build()?;
+    clients.push(HttpClient { bind_ip, proxy, client: client.clone() });
+    Ok(client)
+}
+
+/// Builder for an HTTP client bound to the configured interface, going
+/// through `proxy`, for clients that need settings of their own
+pub fn http_client_builder(proxy: Option<&Url>) -> Result<reqwest::ClientBuilder> {
+    let mut builder = reqwest::Client::builder().local_address(interface::bind_ip());
+    if let Some(url) = proxy {
+        builder = builder.proxy(reqwest::Proxy::all(url.clone())?);
+    }
+    Ok(builder)
+}
+
+/// Connect to the proxy and authenticate, then have it connect to `target`
+/// ("host:port") if given. Returns the time taken.
+pub async fn test(proxy: &ProxyConfig, target: Option<&str>) -> Result<Duration> {
+    test_within(proxy, target, TEST_TIMEOUT).await
+}
+
+async fn test_within(proxy: &ProxyConfig, target: Option<&str>, timeout: Duration) -> Result<Duration> {
+    let target = match target {
+        Some(target) => {
+            let (host, port) = target.rsplit_once(':')
+                .and_then(|(host, port)| Some((host.trim_matches(['[', ']']), port.parse::<u16>().ok()?)))
+                .context(format!("Invalid target, expected host:port: {}", target))?;
+            Some((host, port))
+        }
+        None => None,
+    };
+
+    let started = Instant::now();
+    tokio::time::timeout(timeout, async {
+        let mut stream = TcpStream::connect((proxy.host.as_str(), proxy.port)).await
+            .context(format!("Can't connect to the proxy at {}:{}", proxy.host, proxy.port))?;
+        match proxy.proxy_type {
+            ProxyType::Socks5 => socks5_connect(&mut stream, proxy, target).await,
+            ProxyType::Http => match target {
+                Some(target) => http_connect(&mut stream, proxy, target).await,
+                // Nothing to check without a CONNECT
+                None => Ok(()),
+            },
+        }
+    }).await.context("The proxy didn't respond in time")??;
+    Ok(started.elapsed())
+}
+
+/// SOCKS5 greeting and authentication (RFC 1928, RFC 1929), then a CONNECT
+async fn socks5_connect(stream: &mut TcpStream, proxy: &ProxyConfig, target: Option<(&str, u16)>) -> Result<()> {
+    let greeting: &[u8] = match proxy.username {
+        Some(_) => &[5, 2, 0, 2], // No authentication, or username/password
+        None => &[5, 1, 0],
+    };
+    stream.write_all(greeting).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await.context("The proxy closed the connection")?;
+    if reply[0] != 5 {
+        bail!("Not a SOCKS5 proxy");
+    }
+    match reply[1] {
+        0 => {}
+        2 => {
+            let username = proxy.username.as_deref().unwrap_or_default().as_bytes();
+            let password = proxy.password.as_deref().unwrap_or_default().as_bytes();
+            if username.len() > 255 || password.len() > 255 {
+                bail!("Proxy username or password too long");
+            }
+            let mut request = vec![1, username.len() as u8];
+            request.extend_from_slice(username);
+            request.push(password.len() as u8);
+            request.extend_from_slice(password);
+            stream.write_all(&request).await?;
+            stream.read_exact(&mut reply).await?;
+            if reply[1] != 0 {
+                bail!("The proxy rejected the username or password");
+            }
+        }
+        _ => bail!("The proxy accepts none of our authentication methods"),
+    }
+
+    let Some((host, port)) = target else {
+        return Ok(());
+    };
+    if host.len() > 255 {
+        bail!("Target host name too long");
+    }
+    let mut request = vec![5, 1, 0, 3, host.len() as u8];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header).await?;
+    match header[1] {
+        0 => {}
+        1 => bail!("The proxy failed to connect to {}:{}", host, port),
+        2 => bail!("The proxy doesn't allow connecting to {}:{}", host, port),
+        3 => bail!("The proxy reports {} unreachable (network)", host),
+        4 => bail!("The proxy reports {} unreachable (host)", host),
+        5 => bail!("{}:{} refused the proxy's connection", host, port),
+        6 => bail!("The proxy's connection to {}:{} timed out", host, port),
+        code => bail!("The proxy failed to connect with error {}", code),
+    }
+    // Skip the address the proxy bound
+    let address_len = match header[3] {
+        1 => 4,
+        4 => 16,
+        3 => stream.read_u8().await? as usize,
+        _ => bail!("Invalid SOCKS5 reply"),
+    };
+    let mut bound = vec![0u8; address_len + 2];
+    stream.read_exact(&mut bound).await?;
+    Ok(())
+}
+
+/// HTTP CONNECT to `target`, authenticating with Basic credentials
+async fn http_connect(stream: &mut TcpStream, proxy: &ProxyConfig, (host, port): (&str, u16)) -> Result<()> {
+    let authority = if host.contains(':') { format!("[{}]:{}", host, port) } else { format!("{}:{}", host, port) };
+    let mut request = format!("CONNECT {0} HTTP/1.1\r\nHost: {0}\r\n", authority);
+    if let Some(username) = &proxy.username {
+        let credentials = format!("{}:{}", username, proxy.password.as_deref().unwrap_or_default());
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n",
+            base64::engine::general_purpose::STANDARD.encode(credentials)));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_HEADER_LEN {
+            bail!("Invalid HTTP proxy response");
+        }
+        let byte = stream.read_u8().await.context("The proxy closed the connection")?;
+        response.push(byte);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some(code) if code.starts_with('2') => Ok(()),
+        Some("407") if proxy.username.is_some() => bail!("The proxy rejected the username or password"),
+        Some("407") => bail!("The proxy requires a username and password"),
+        Some(_) => bail!("The proxy refused to connect to {}: {}", authority, status_line),
+        None => bail!("Not an HTTP proxy"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    fn config(proxy_type: ProxyType, port: u16, credentials: Option<(&str, &str)>) -> ProxyConfig {
+        ProxyConfig {
+            proxy_type,
+            host: "127.0.0.1".to_string(),
+            port,
+            username: credentials.map(|(u, _)| u.to_string()),
+            password: credentials.map(|(_, p)| p.to_string()),
+            proxy_peers: false,
+            proxy_trackers: false,
+        }
+    }
+
+    #[test]
+    fn validates_configs() {
+        let socks = |peers, trackers| ProxyConfig {
+            proxy_peers: peers,
+            proxy_trackers: trackers,
+            ..config(ProxyType::Socks5, 1080, None)
+        };
+        let http = |peers, trackers| ProxyConfig {
+            proxy_peers: peers,
+            proxy_trackers: trackers,
+            ..config(ProxyType::Http, 8080, None)
+        };
+        // (config, error)
+        let cases = [
+            (socks(true, true), None),
+            (socks(true, false), None),
+            (socks(false, false), None),
+            (socks(false, true), Some("only be proxied along with peer")),
+            (http(false, false), None),
+            (http(false, true), Some("only be proxied along with peer")),
+            (http(true, false), Some("Only SOCKS5")),
+            (ProxyConfig { host: String::new(), ..socks(false, false) }, Some("host and port")),
+            (ProxyConfig { port: 0, ..socks(false, false) }, Some("host and port")),
+            (ProxyConfig { password: Some("p".into()), ..socks(false, false) }, Some("without a username")),
+            (ProxyConfig { username: Some("a@b".into()), ..socks(true, false) }, Some("escaped in URLs")),
+            (ProxyConfig { username: Some("a@b".into()), ..socks(false, false) }, None),
+        ];
+        for (proxy, error) in cases {
+            match (validate(&proxy), error) {
+                (Ok(()), None) => {}
+                (Err(e), Some(error)) => assert!(e.to_string().contains(error), "{:?}: {}", proxy, e),
+                (result, _) => panic!("{:?}: {:?}", proxy, result),
+            }
+        }
+    }
+
+    /// A SOCKS5 proxy that wants `auth` (0 none, 2 username/password),
+    /// accepts `credentials` and answers CONNECTs with `reply`. Returns its
+    /// port and what it was sent after the greeting.
+    async fn socks5_proxy(auth: u8, credentials: &'static [u8], reply: u8) -> (u16, tokio::sync::oneshot::Receiver<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut greeting = [0u8; 2];
+            stream.read_exact(&mut greeting).await.unwrap();
+            let mut methods = vec![0u8; greeting[1] as usize];
+            stream.read_exact(&mut methods).await.unwrap();
+            stream.write_all(&[5, if methods.contains(&auth) { auth } else { 0xff }]).await.unwrap();
+
+            let mut received = Vec::new();
+            if auth == 2 {
+                let mut buf = vec![0u8; credentials.len()];
+                if stream.read_exact(&mut buf).await.is_err() {
+                    return;
+                }
+                received.extend_from_slice(&buf);
+                let ok = buf == credentials;
+                stream.write_all(&[1, if ok { 0 } else { 1 }]).await.unwrap();
+                if !ok {
+                    let _ = tx.send(received);
+                    return;
+                }
+            }
+            let mut buf = [0u8; 512];
+            if let Ok(n) = stream.read(&mut buf).await {
+                received.extend_from_slice(&buf[..n]);
+                if n > 0 {
+                    stream.write_all(&[5, reply, 0, 1, 127, 0, 0, 1, 0x1f, 0x90]).await.unwrap();
+                }
+            }
+            let _ = tx.send(received);
+        });
+        (port, rx)
+    }
+
+    #[tokio::test]
+    async fn socks5_connects() {
+        let (port, received) = socks5_proxy(0, b"", 0).await;
+        let proxy = config(ProxyType::Socks5, port, None);
+        test(&proxy, Some("example.com:80")).await.unwrap();
+        let mut expected = vec![5, 1, 0, 3, 11];
+        expected.extend_from_slice(b"example.com");
+        expected.extend_from_slice(&80u16.to_be_bytes());
+        assert_eq!(received.await.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn socks5_authenticates() {
+        let (port, received) = socks5_proxy(2, b"\x01\x04user\x04pass", 0).await;
+        let proxy = config(ProxyType::Socks5, port, Some(("user", "pass")));
+        test(&proxy, None).await.unwrap();
+        assert_eq!(received.await.unwrap(), b"\x01\x04user\x04pass");
+
+        let (port, _) = socks5_proxy(2, b"\x01\x04user\x04pass", 0).await;
+        let proxy = config(ProxyType::Socks5, port, Some(("user", "wrong")));
+        let err = test(&proxy, None).await.err().unwrap();
+        assert!(err.to_string().contains("rejected the username or password"), "{}", err);
+
+        let (port, _) = socks5_proxy(2, b"", 0).await;
+        let err = test(&config(ProxyType::Socks5, port, None), None).await.err().unwrap();
+        assert!(err.to_string().contains("none of our authentication methods"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn socks5_connect_failures_are_reported() {
+        // (reply, error)
+        let cases = [
+            (2, "doesn't allow connecting"),
+            (4, "unreachable (host)"),
+            (5, "refused the proxy's connection"),
+            (9, "error 9"),
+        ];
+        for (reply, error) in cases {
+            let (port, _) = socks5_proxy(0, b"", reply).await;
+            let err = test(&config(ProxyType::Socks5, port, None), Some("example.com:80")).await.err().unwrap();
+            assert!(err.to_string().contains(error), "{}: {}", reply, err);
+        }
+    }
+
+    /// An HTTP proxy answering CONNECT with `status`. Returns its port and
+    /// the request it got.
+    async fn http_proxy(status: &'static str) -> (u16, tokio::sync::oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            while !request.ends_with(b"\r\n\r\n") {
+                request.push(stream.read_u8().await.unwrap());
+            }
+            stream.write_all(format!("HTTP/1.1 {}\r\nServer: test\r\n\r\n", status).as_bytes()).await.unwrap();
+            let _ = tx.send(String::from_utf8(request).unwrap());
+        });
+        (port, rx)
+    }
+
+    #[tokio::test]
+    async fn http_connects() {
+        let (port, received) = http_proxy("200 Connection established").await;
+        let proxy = config(ProxyType::Http, port, Some(("user", "pass")));
+        test(&proxy, Some("[::1]:443")).await.unwrap();
+        let request = received.await.unwrap();
+        assert!(request.starts_with("CONNECT [::1]:443 HTTP/1.1\r\n"), "{}", request);
+        // base64("user:pass")
+        assert!(request.contains("Proxy-Authorization: Basic dXNlcjpwYXNz\r\n"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn http_failures_are_reported() {
+        // (status, credentials, error)
+        let cases = [
+            ("407 Proxy Authentication Required", Some(("user", "pass")), "rejected the username or password"),
+            ("407 Proxy Authentication Required", None, "requires a username and password"),
+            ("403 Forbidden", None, "refused to connect to example.com:80: HTTP/1.1 403 Forbidden"),
+        ];
+        for (status, credentials, error) in cases {
+            let (port, _) = http_proxy(status).await;
+            let err = test(&config(ProxyType::Http, port, credentials), Some("example.com:80")).await.err().unwrap();
+            assert!(err.to_string().contains(error), "{}: {}", status, err);
+        }
+    }
+
+    #[tokio::test]
+    async fn silent_proxies_time_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+        let proxy = config(ProxyType::Socks5, port, None);
+        let err = test_within(&proxy, None, Duration::from_millis(200)).await.err().unwrap();
+        assert!(err.to_string().contains("didn't respond in time"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn bad_targets_and_closed_ports_fail() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+        let err = test(&config(ProxyType::Socks5, port, None), None).await.err().unwrap();
+        assert!(err.to_string().contains("Can't connect to the proxy"), "{}", err);
+
+        for target in ["example.com", "example.com:http", ":"] {
+            let err = test(&config(ProxyType::Http, port, None), Some(target)).await.err().unwrap();
+            assert!(err.to_string().contains("Invalid target"), "{}: {}", target, err);
+        }
+    }
+}
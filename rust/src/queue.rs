@@ -0,0 +1,191 @@
+// Download queue for torrents added with add_torrents_batch.
+//
+// With max_active_downloads set, a batch only starts as many torrents as
+// there are free download slots. The rest are added paused and wait here,
+// and are started oldest first as running downloads finish. Every download
+// takes a slot, however it was added, but only batch torrents are queued.
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex, Once};
+use std::time::Duration;
+use librqbit::api::TorrentIdOrHash;
+use librqbit::dht::Id20;
+use librqbit::Session;
+use crate::api::config::session_config;
+use crate::{get_session, interface, torrents};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct Queue {
+    /// Queued torrent ids, oldest first
+    waiting: VecDeque<usize>,
+    /// Torrents that aren't in the session yet, by info hash: magnet links
+    /// are only added once their metadata is resolved
+    adding: HashMap<Id20, usize>,
+}
+
+static QUEUE: LazyLock<Mutex<Queue>> = LazyLock::new(|| Mutex::new(Queue::default()));
+
+fn with_queue<R>(f: impl FnOnce(&mut Queue) -> R) -> R {
+    let mut queue = QUEUE.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut queue)
+}
+
+/// Take a download slot for a torrent about to be added, or queue it if
+/// there is none. Returns true if it can start, false if it must be added
+/// paused. Either way it counts as being added until `added` is called.
+pub fn admit(session: &Session, id: usize, info_hash: Id20) -> bool {
+    let active = active_downloads(session);
+    with_queue(|q| {
+        let start = session_config().max_active_downloads
+            .is_none_or(|limit| active + starting(q, session) < limit as usize);
+        if !start {
+            q.waiting.push_back(id);
+        }
+        q.adding.insert(info_hash, id);
+        start
+    })
+}
+
+/// The torrent is in the session now, or failed to be added
+pub fn added(info_hash: Id20) {
+    with_queue(|q| q.adding.remove(&info_hash));
+}
+
+/// Id of a torrent with this info hash that is still being added
+pub fn adding(info_hash: Id20) -> Option<usize> {
+    with_queue(|q| q.adding.get(&info_hash).copied())
+}
+
+pub fn is_queued(id: usize) -> bool {
+    with_queue(|q| q.waiting.contains(&id))
+}
+
+/// Torrents being added to start right away, which take a slot too
+fn starting(q: &Queue, session: &Session) -> usize {
+    q.adding.values()
+        .filter(|id| !q.waiting.contains(id) && session.get(TorrentIdOrHash::Id(**id)).is_none())
+        .count()
+}
+
+/// Downloads running in the session. Torrents paused by the interface
+/// watchdog keep their slot, to get it back when the interface returns.
+fn active_downloads(session: &Session) -> usize {
+    session.with_torrents(|torrents| {
+        torrents
+            .filter(|(_, handle)| {
+                let paused = handle.is_paused()
+                    && !torrents::get(handle.id()).is_some_and(|e| e.network_paused);
+                let stats = handle.stats();
+                !paused && (stats.total_bytes == 0 || stats.progress_bytes < stats.total_bytes)
+            })
+            .count()
+    })
+}
+
+/// Start the queue, once for the lifetime of the app. It reads the config
+/// on every check, so a changed limit applies without a session restart.
+pub fn spawn_manager() {
+    static STARTED: Once = Once::new();
+    STARTED.call_once(|| {
+        tokio::spawn(async {
+            loop {
+                start_queued().await;
+                tokio::time::sleep(CHECK_INTERVAL).await;
+            }
+        });
+    });
+}
+
+async fn start_queued() {
+    // Torrents started now would only be paused again
+    if !interface::is_up() {
+        return;
+    }
+    let Ok(session) = get_session().await else {
+        return;
+    };
+
+    let waiting: Vec<usize> = with_queue(|q| q.waiting.iter().copied().collect());
+    for id in waiting {
+        let free = session_config().max_active_downloads.is_none_or(|limit| {
+            active_downloads(&session) + with_queue(|q| starting(q, &session)) < limit as usize
+        });
+        if !free {
+            break;
+        }
+
+        let handle = match session.get(TorrentIdOrHash::Id(id)) {
+            Some(handle) => handle,
+            // Still fetching metadata, or being re-added
+            None if torrents::is_readding(id) => continue,
+            None => {
+                with_queue(|q| q.waiting.retain(|w| *w != id));
+                continue;
+            }
+        };
+        if handle.is_paused() {
+            if let Err(e) = session.unpause(&handle).await {
+                println!("QUEUE: failed to start torrent {}: {:#}", id, e);
+                continue;
+            }
+        }
+        println!("QUEUE: started torrent {}", id);
+        with_queue(|q| q.waiting.retain(|w| *w != id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::config::{with_config, SessionConfig};
+    use crate::storage::StorageLocation;
+    use crate::test_util::with_session;
+
+    #[test]
+    fn admits_up_to_the_download_limit() {
+        with_session(async {
+            let session = get_session().await.unwrap();
+            let limit = active_downloads(&session) + 2;
+            let config = SessionConfig { max_active_downloads: Some(limit as u32), ..session_config() };
+            with_config(config, async {
+                let dir = tempfile::tempdir().unwrap();
+                let torrents: Vec<(usize, Id20)> = (0..4u8)
+                    .map(|n| {
+                        let id = torrents::allocate_id();
+                        // Flagged as being added, so the manager leaves them queued
+                        torrents::register(id, StorageLocation::new(dir.path(), false), None);
+                        torrents::set_readding(id, true);
+                        (id, Id20::new([0xe0 + n; 20]))
+                    })
+                    .collect();
+
+                // Torrents still being added to start take a slot too
+                let admitted: Vec<bool> = torrents.iter().map(|(id, hash)| admit(&session, *id, *hash)).collect();
+                assert_eq!(admitted, vec![true, true, false, false]);
+                for (n, (id, hash)) in torrents.iter().enumerate() {
+                    assert_eq!(is_queued(*id), n >= 2);
+                    assert_eq!(adding(*hash), Some(*id));
+                }
+
+                // Added and out of the session again: the slots are free
+                for (_, hash) in &torrents[..2] {
+                    added(*hash);
+                }
+                let (id, hash) = (torrents::allocate_id(), Id20::new([0xef; 20]));
+                torrents::register(id, StorageLocation::new(dir.path(), false), None);
+                torrents::set_readding(id, true);
+                assert!(admit(&session, id, hash));
+                added(hash);
+
+                for (id, hash) in &torrents[2..] {
+                    added(*hash);
+                    with_queue(|q| q.waiting.retain(|w| w != id));
+                }
+                for (id, _) in torrents.iter().chain([&(id, hash)]) {
+                    torrents::unregister(*id);
+                }
+            }).await;
+        });
+    }
+}
@@ -1,161 +1,497 @@
+mod relocate;
+pub mod sanitize;
+
+use std::collections::HashSet;
 use std::fs::{File, OpenOptions, create_dir_all};
-use std::io::{Write, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::io::{Read, Write, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock, Weak};
 use anyhow::{Result, Context};
-use crate::torrent::Metainfo;
+use librqbit::storage::{BoxStorageFactory, StorageFactory, StorageFactoryExt, TorrentStorage};
+use librqbit::{ByteBufOwned, FileIteratorName, ManagedTorrentShared, TorrentMetaV1Info, TorrentMetadata};
+use self::sanitize::{sanitize_path, truncate_name, truncate_str, SanitizedPath, MAX_COMPONENT_BYTES};
+
+/// Name used for the top-level folder when the torrent doesn't carry one
+const FALLBACK_NAME: &[u8] = b"torrent-content";
+
+/// Appended to unfinished files when the location asks for it
+const PART_SUFFIX: &str = ".part";
+
+/// On-disk placement of one file of a torrent
+#[derive(Debug, Clone)]
+pub struct FileLayout {
+    /// Sanitised path relative to the output directory
+    pub path: SanitizedPath,
+    /// Offset of the file within the torrent's flat byte stream
+    pub offset: u64,
+    pub length: u64,
+    /// BEP 47 padding files are never written to disk
+    pub padding: bool,
+}
+
+/// Work out where every file of a torrent goes, relative to the output directory.
+///
+/// Single-file torrents are placed directly in the output directory, multi-file
+/// torrents in a folder named after the torrent. Every name goes through the
+/// sanitiser; names that end up identical get a " (n)" suffix.
+pub fn file_layout(info: &TorrentMetaV1Info<ByteBufOwned>) -> Result<Vec<FileLayout>> {
+    let torrent_name: &[u8] = info.name.as_ref().map(|n| n.as_ref()).unwrap_or(FALLBACK_NAME);
+
+    let mut layout = Vec::new();
+    let mut seen = HashSet::new();
+    let mut offset = 0u64;
+
+    for details in info.iter_file_details()? {
+        let mut path = match details.filename {
+            FileIteratorName::Single(_) => sanitize_path([torrent_name])?,
+            FileIteratorName::Tree(parts) => {
+                sanitize_path(std::iter::once(torrent_name).chain(parts.iter().map(|p| p.as_ref())))?
+            }
+        };
+
+        let padding = details.attrs().padding;
+        if !padding {
+            dedupe(&mut path, &mut seen);
+        }
+
+        layout.push(FileLayout {
+            path,
+            offset,
+            length: details.len,
+            padding,
+        });
+        offset += details.len;
+    }
+
+    Ok(layout)
+}
+
+/// Number a path that collides with one already in `seen`, and add it
+pub(crate) fn dedupe(path: &mut SanitizedPath, seen: &mut HashSet<String>) {
+    // Windows and Android shared storage are case-insensitive
+    let key = |p: &Path| p.to_string_lossy().to_lowercase();
+    if seen.insert(key(&path.path)) {
+        return;
+    }
+
+    let stem = path.path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    let ext = path.path.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    for n in 1.. {
+        // The name may already be at the length limit, so the stem makes room
+        let tail = format!(" ({}){}", n, ext);
+        let stem = truncate_str(&stem, MAX_COMPONENT_BYTES.saturating_sub(tail.len()));
+        let name = truncate_name(&format!("{}{}", stem, tail), MAX_COMPONENT_BYTES);
+        let candidate = path.path.with_file_name(name);
+        if seen.insert(key(&candidate)) {
+            path.path = candidate;
+            path.renamed = true;
+            return;
+        }
+    }
+}
+
+/// Where a torrent's data lives on disk.
+///
+/// Shared by the torrent's storage factory, every [`Storage`] created from it
+/// and [`StorageLocation::relocate`], so the data can be moved while librqbit
+/// keeps using the same storage object.
+pub struct StorageLocation {
+    root: RwLock<PathBuf>,
+    part_suffix: bool,
+    // Files of the storage librqbit currently uses; older ones have been taken
+    active: Mutex<Weak<Vec<StorageFile>>>,
+}
+
+impl StorageLocation {
+    /// With `part_suffix`, files that don't exist yet under their final name
+    /// are written as "<name>.part" until [`StorageLocation::finish_file`]
+    pub fn new(root: impl Into<PathBuf>, part_suffix: bool) -> Arc<Self> {
+        Arc::new(Self {
+            root: RwLock::new(root.into()),
+            part_suffix,
+            active: Mutex::new(Weak::new()),
+        })
+    }
+
+    /// Directory the torrent's files are placed in, as laid out by [`file_layout`]
+    pub fn root(&self) -> PathBuf {
+        self.root.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn set_root(&self, root: PathBuf) {
+        *self.root.write().unwrap_or_else(|e| e.into_inner()) = root;
+    }
+
+    fn set_active(&self, files: &Arc<Vec<StorageFile>>) {
+        *self.active.lock().unwrap_or_else(|e| e.into_inner()) = Arc::downgrade(files);
+    }
+
+    fn active(&self) -> Option<Arc<Vec<StorageFile>>> {
+        self.active.lock().unwrap_or_else(|e| e.into_inner()).upgrade()
+    }
+
+    /// The storage librqbit currently uses, to read and write pieces it
+    /// doesn't know about, such as those fetched from web seeds
+    pub fn active_storage(self: &Arc<Self>, piece_length: u64) -> Result<Storage> {
+        Ok(Storage {
+            location: self.clone(),
+            files: self.active().context("Torrent storage is not open")?,
+            piece_length,
+        })
+    }
+
+    /// Drop the ".part" suffix of a file that has been fully downloaded.
+    /// Does nothing for files that already have their final name.
+    pub fn finish_file(&self, file_id: usize) -> Result<()> {
+        let files = self.active().context("Torrent storage is not open")?;
+        let storage_file = files.get(file_id).context(format!("No such file: {}", file_id))?;
+        if !storage_file.part.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let root = self.root();
+        let mut guard = storage_file.file.lock().map_err(|_| anyhow::anyhow!("File lock poisoned"))?;
+        // Closed around the rename, Windows can't rename open files
+        let was_open = guard.take().is_some();
+
+        let result = std::fs::rename(storage_file.disk_path(&root), root.join(&storage_file.path));
+        if result.is_ok() {
+            storage_file.part.store(false, Ordering::Relaxed);
+        }
+        if was_open {
+            *guard = Some(open_file(&storage_file.disk_path(&root))?);
+        }
+        result.context(format!("Failed to rename finished file: {:?}", storage_file.path))
+    }
+}
+
+fn open_file(path: &Path) -> Result<File> {
+    // Create parent directory
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)
+            .context("Failed to create output directory")?;
+    }
+
+    // Open or create file
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .context(format!("Failed to open file: {:?}", path))
+}
 
+/// Filesystem storage backend handed to librqbit for every torrent we add.
+///
+/// Unlike librqbit's default backend, all paths come from [`file_layout`], so
+/// nothing from the torrent metadata reaches the filesystem unsanitised.
 pub struct Storage {
-    files: Vec<StorageFile>,
-    piece_length: usize,
+    location: Arc<StorageLocation>,
+    files: Arc<Vec<StorageFile>>,
+    piece_length: u64,
 }
 
 struct StorageFile {
-    file: File,
-    offset: usize,
-    length: usize,
+    // Relative to the location's root, without any ".part" suffix
+    path: PathBuf,
+    // Currently stored as "<path>.part"
+    part: AtomicBool,
+    // None for padding files and for storages that have been taken
+    file: Mutex<Option<File>>,
+    offset: u64,
+    length: u64,
+    padding: bool,
+}
+
+impl StorageFile {
+    fn disk_path(&self, root: &Path) -> PathBuf {
+        let path = root.join(&self.path);
+        if !self.part.load(Ordering::Relaxed) {
+            return path;
+        }
+        let mut name = path.into_os_string();
+        name.push(PART_SUFFIX);
+        PathBuf::from(name)
+    }
+
+    fn with_file<R>(&self, f: impl FnOnce(&mut File) -> Result<R>) -> Result<R> {
+        let mut guard = self.file.lock().map_err(|_| anyhow::anyhow!("File lock poisoned"))?;
+        let file = guard.as_mut().context(format!("File not open: {:?}", self.path))?;
+        f(file)
+    }
 }
 
 impl Storage {
-    pub fn new(metainfo: &Metainfo, output_dir: &str) -> Result<Self> {
+    pub fn new(info: &TorrentMetaV1Info<ByteBufOwned>, location: &Arc<StorageLocation>) -> Result<Self> {
+        let root = location.root();
         let mut files = Vec::new();
-        let mut current_offset = 0;
-        
-        for file_info in &metainfo.info.files {
-            let mut path = PathBuf::from(output_dir);
-            path.push(&metainfo.info.name);
-            
-            for component in &file_info.path {
-                path.push(component);
-            }
-            
-            // Create parent directory
-            if let Some(parent) = path.parent() {
-                create_dir_all(parent)
-                    .context("Failed to create output directory")?;
+
+        for entry in file_layout(info)? {
+            let part = location.part_suffix && !entry.padding && !root.join(&entry.path.path).exists();
+            let mut storage_file = StorageFile {
+                path: entry.path.path,
+                part: AtomicBool::new(part),
+                file: Mutex::new(None),
+                offset: entry.offset,
+                length: entry.length,
+                padding: entry.padding,
+            };
+            if !entry.padding {
+                storage_file.file = Mutex::new(Some(open_file(&storage_file.disk_path(&root))?));
             }
-            
-            // Open or create file
-            let file = OpenOptions::new()
-                .read(true)
-                .write(true)
-                .create(true)
-                .open(&path)
-                .context(format!("Failed to open file: {:?}", path))?;
-            
-            // Set file size
-            file.set_len(file_info.length as u64)
-                .context("Failed to set file size")?;
-            
-            files.push(StorageFile {
-                file,
-                offset: current_offset,
-                length: file_info.length,
-            });
-            
-            current_offset += file_info.length;
+            files.push(storage_file);
         }
-        
+
+        let files = Arc::new(files);
+        location.set_active(&files);
+
         Ok(Storage {
+            location: location.clone(),
             files,
-            piece_length: metainfo.info.piece_length,
+            piece_length: info.piece_length as u64,
         })
     }
-    
-    pub fn write_piece(&mut self, piece_index: usize, data: &[u8]) -> Result<()> {
-        let piece_offset = piece_index * self.piece_length;
+
+    pub fn write_piece(&self, piece_index: usize, data: &[u8]) -> Result<()> {
+        let piece_offset = piece_index as u64 * self.piece_length;
         let mut data_offset = 0;
-        let data_len = data.len();
-        
-        for storage_file in &mut self.files {
+
+        for storage_file in self.files.iter() {
             // Check if this file overlaps with the piece
             let file_start = storage_file.offset;
             let file_end = storage_file.offset + storage_file.length;
-            
-            if piece_offset >= file_end || piece_offset + data_len <= file_start {
+            let write_pos = piece_offset + data_offset as u64;
+
+            if write_pos >= file_end || piece_offset + data.len() as u64 <= file_start {
                 continue; // No overlap
             }
-            
+
             // Calculate overlap
-            let write_start = if piece_offset > file_start {
-                piece_offset - file_start
-            } else {
-                0
-            };
-            
-            let write_end = std::cmp::min(
-                write_start + data_len - data_offset,
-                storage_file.length
-            );
-            
-            let write_len = write_end - write_start;
-            
-            // Write to file
-            storage_file.file.seek(SeekFrom::Start(write_start as u64))
-                .context("Failed to seek in file")?;
-            
-            storage_file.file.write_all(&data[data_offset..data_offset + write_len])
-                .context("Failed to write to file")?;
-            
-            // CRITICAL: Flush to disk immediately to ensure data isn't lost
-            storage_file.file.flush()
-                .context("Failed to flush file")?;
-            
+            let write_start = write_pos - file_start;
+            let write_len = std::cmp::min(
+                (data.len() - data_offset) as u64,
+                storage_file.length - write_start,
+            ) as usize;
+
+            // Padding files have no backing storage
+            if !storage_file.padding {
+                self.pwrite_all_file(storage_file, write_start, &data[data_offset..data_offset + write_len])?;
+            }
+
             data_offset += write_len;
-            
-            if data_offset >= data_len {
+
+            if data_offset >= data.len() {
                 break;
             }
         }
-        
+
         Ok(())
     }
 
-    pub fn read_piece(&mut self, piece_index: usize, length: usize) -> Result<Vec<u8>> {
-        let piece_offset = piece_index * self.piece_length;
+    pub fn read_piece(&self, piece_index: usize, length: usize) -> Result<Vec<u8>> {
+        let piece_offset = piece_index as u64 * self.piece_length;
         let mut data = vec![0u8; length];
         let mut data_offset = 0;
-        
-        for storage_file in &mut self.files {
+
+        for storage_file in self.files.iter() {
             // Check if this file overlaps with the piece
             let file_start = storage_file.offset;
             let file_end = storage_file.offset + storage_file.length;
-            
-            if piece_offset >= file_end || piece_offset + length <= file_start {
+            let read_pos = piece_offset + data_offset as u64;
+
+            if read_pos >= file_end || piece_offset + length as u64 <= file_start {
                 continue; // No overlap
             }
-            
+
             // Calculate overlap
-            let read_start = if piece_offset > file_start {
-                piece_offset - file_start
-            } else {
-                0
-            };
-            
-            let read_end = std::cmp::min(
-                read_start + length - data_offset,
-                storage_file.length
-            );
-            
-            let read_len = read_end - read_start;
-            
-            // Read from file
-            storage_file.file.seek(SeekFrom::Start(read_start as u64))
-                .context("Failed to seek in file")?;
-            
-            use std::io::Read;
-            storage_file.file.read_exact(&mut data[data_offset..data_offset + read_len])
-                .context("Failed to read from file")?;
-            
+            let read_start = read_pos - file_start;
+            let read_len = std::cmp::min(
+                (length - data_offset) as u64,
+                storage_file.length - read_start,
+            ) as usize;
+
+            // Padding files read back as zeroes, which is what the buffer already holds
+            if !storage_file.padding {
+                self.pread_exact_file(storage_file, read_start, &mut data[data_offset..data_offset + read_len])?;
+            }
+
             data_offset += read_len;
-            
+
             if data_offset >= length {
                 break;
             }
         }
-        
-        // If we didn't fill the buffer (sparse file?), fill remainder with 0s? 
-        // Actually storage_file.length should map to magnet layout.
-        
+
         Ok(data)
     }
+
+    fn pread_exact_file(&self, storage_file: &StorageFile, offset: u64, buf: &mut [u8]) -> Result<()> {
+        storage_file.with_file(|file| {
+            file.seek(SeekFrom::Start(offset))
+                .context("Failed to seek in file")?;
+            file.read_exact(buf)
+                .context(format!("Failed to read from file: {:?}", storage_file.path))
+        })
+    }
+
+    fn pwrite_all_file(&self, storage_file: &StorageFile, offset: u64, buf: &[u8]) -> Result<()> {
+        storage_file.with_file(|file| {
+            file.seek(SeekFrom::Start(offset))
+                .context("Failed to seek in file")?;
+            file.write_all(buf)
+                .context(format!("Failed to write to file: {:?}", storage_file.path))?;
+            file.flush()
+                .context("Failed to flush file")
+        })
+    }
+
+    fn file(&self, file_id: usize) -> Result<&StorageFile> {
+        self.files.get(file_id).context(format!("No such file: {}", file_id))
+    }
+}
+
+impl TorrentStorage for Storage {
+    fn init(&mut self, _shared: &ManagedTorrentShared, _metadata: &TorrentMetadata) -> Result<()> {
+        // Files are opened in Storage::new
+        Ok(())
+    }
+
+    fn pread_exact(&self, file_id: usize, offset: u64, buf: &mut [u8]) -> Result<()> {
+        self.pread_exact_file(self.file(file_id)?, offset, buf)
+    }
+
+    fn pwrite_all(&self, file_id: usize, offset: u64, buf: &[u8]) -> Result<()> {
+        self.pwrite_all_file(self.file(file_id)?, offset, buf)
+    }
+
+    fn remove_file(&self, file_id: usize, _filename: &Path) -> Result<()> {
+        // librqbit passes its own unsanitised name, so go by the id instead
+        let storage_file = self.file(file_id)?;
+        if let Ok(mut file) = storage_file.file.lock() {
+            file.take();
+        }
+        let root = self.location.root();
+        let path = storage_file.disk_path(&root);
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).context(format!("Failed to remove file: {:?}", path)),
+        }
+
+        remove_empty_parents(&root, &storage_file.path);
+        Ok(())
+    }
+
+    fn remove_directory_if_empty(&self, _path: &Path) -> Result<()> {
+        // Directories are cleaned up in remove_file, whose paths match ours
+        Ok(())
+    }
+
+    fn ensure_file_length(&self, file_id: usize, length: u64) -> Result<()> {
+        self.file(file_id)?.with_file(|file| {
+            file.set_len(length).context("Failed to set file size")
+        })
+    }
+
+    fn take(&self) -> Result<Box<dyn TorrentStorage>> {
+        let files = self.files.iter().map(|f| {
+            let file = f.file.lock().map_err(|_| anyhow::anyhow!("File lock poisoned"))?.take();
+            Ok(StorageFile {
+                path: f.path.clone(),
+                part: AtomicBool::new(f.part.load(Ordering::Relaxed)),
+                file: Mutex::new(file),
+                offset: f.offset,
+                length: f.length,
+                padding: f.padding,
+            })
+        }).collect::<Result<Vec<_>>>()?;
+
+        let files = Arc::new(files);
+        self.location.set_active(&files);
+
+        Ok(Box::new(Storage {
+            location: self.location.clone(),
+            files,
+            piece_length: self.piece_length,
+        }))
+    }
+}
+
+/// Remove the directories between `root` and the file at `relative` that
+/// are left empty, stopping at `root`
+fn remove_empty_parents(root: &Path, relative: &Path) {
+    let mut dir = relative.parent();
+    while let Some(d) = dir {
+        if d.as_os_str().is_empty() || std::fs::remove_dir(root.join(d)).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+}
+
+/// Creates a [`Storage`] for a torrent at its [`StorageLocation`]
+#[derive(Clone)]
+pub struct DiskStorageFactory {
+    location: Arc<StorageLocation>,
+}
+
+impl DiskStorageFactory {
+    pub fn new(location: Arc<StorageLocation>) -> Self {
+        Self { location }
+    }
+
+    pub fn boxed(location: Arc<StorageLocation>) -> BoxStorageFactory {
+        StorageFactoryExt::boxed(Self::new(location))
+    }
+}
+
+impl StorageFactory for DiskStorageFactory {
+    type Storage = Storage;
+
+    fn create(&self, _shared: &ManagedTorrentShared, metadata: &TorrentMetadata) -> Result<Storage> {
+        Storage::new(&metadata.info, &self.location)
+    }
+
+    fn clone_box(&self) -> BoxStorageFactory {
+        StorageFactoryExt::boxed(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitized(path: &str) -> SanitizedPath {
+        SanitizedPath { path: PathBuf::from(path), original: path.to_string(), renamed: false }
+    }
+
+    #[test]
+    fn dedupe_numbers_collisions() {
+        let mut seen = HashSet::new();
+        let names = ["a.txt", "A.TXT", "a.txt", "dir/b", "dir/B"];
+        let expected = ["a.txt", "A (1).TXT", "a (2).txt", "dir/b", "dir/B (1)"];
+        for (name, expected) in names.iter().zip(expected) {
+            let mut path = sanitized(name);
+            dedupe(&mut path, &mut seen);
+            assert_eq!(path.path, PathBuf::from(expected));
+            assert_eq!(path.renamed, *name != expected);
+        }
+    }
+
+    #[test]
+    fn dedupe_stays_within_name_limit() {
+        let mut seen = HashSet::new();
+        let name = format!("{}.mkv", "é".repeat(125));
+        assert_eq!(name.len(), 254);
+        for _ in 0..12 {
+            let mut path = sanitized(&name);
+            dedupe(&mut path, &mut seen);
+            let file_name = path.path.file_name().unwrap().to_str().unwrap();
+            assert!(file_name.len() <= MAX_COMPONENT_BYTES, "{}", file_name.len());
+            assert!(file_name.ends_with(".mkv"));
+        }
+        assert_eq!(seen.len(), 12);
+    }
 }
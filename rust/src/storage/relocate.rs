@@ -0,0 +1,298 @@
+// Moving a torrent's data to a new directory.
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::MutexGuard;
+use anyhow::{bail, Context, Result};
+use super::{open_file, remove_empty_parents, StorageLocation};
+
+const COPY_BUFFER_SIZE: usize = 1024 * 1024;
+
+impl StorageLocation {
+    /// Move every file of the torrent from the current root to `new_root`.
+    ///
+    /// The torrent must be paused. Files are renamed where possible and copied
+    /// then deleted across filesystems. If any file fails, the ones already
+    /// moved are put back and the torrent stays at its current root. If some
+    /// can't be put back either, the torrent is left at whichever root holds
+    /// most of its data and the error names the files at the other one.
+    /// `progress` is called with (bytes moved, total bytes).
+    pub fn relocate(&self, new_root: &Path, mut progress: impl FnMut(u64, u64)) -> Result<()> {
+        let old_root = self.root();
+        if old_root == new_root {
+            return Ok(());
+        }
+        let files = self.active().context("Torrent storage is not open")?;
+
+        // Hold every file lock for the whole move so nothing writes meanwhile,
+        // and close the handles: Windows can't rename open files, and a copied
+        // file has to be reopened at its new path anyway
+        let mut guards = files.iter()
+            .map(|f| f.file.lock().map_err(|_| anyhow::anyhow!("File lock poisoned")))
+            .collect::<Result<Vec<_>>>()?;
+        let was_open: Vec<bool> = guards.iter_mut().map(|g| g.take().is_some()).collect();
+
+        let to_move: Vec<(PathBuf, u64)> = files.iter()
+            .filter(|f| !f.padding && f.disk_path(&old_root).exists())
+            .map(|f| (f.disk_path(Path::new("")), f.length))
+            .collect();
+        let total: u64 = to_move.iter().map(|(_, len)| len).sum();
+        let mut done = 0u64;
+        progress(done, total);
+
+        let mut moved: Vec<(&PathBuf, u64)> = Vec::new();
+        for (relative, length) in &to_move {
+            let result = move_file(&old_root.join(relative), &new_root.join(relative), |n| {
+                progress(done + n, total);
+            });
+            if let Err(e) = result {
+                // Put back what was already moved
+                let mut stranded: Vec<(&PathBuf, u64)> = Vec::new();
+                for (relative, length) in moved.iter().rev() {
+                    match move_file(&new_root.join(relative), &old_root.join(relative), |_| {}) {
+                        Ok(()) => remove_empty_parents(new_root, relative),
+                        Err(e) => {
+                            println!("STORAGE: Failed to move {:?} back: {:#}", relative, e);
+                            stranded.push((relative, *length));
+                        }
+                    }
+                }
+                if stranded.is_empty() {
+                    reopen(&files, &mut guards, &was_open, &old_root)?;
+                    return Err(e).context(format!("Failed to move {:?}, nothing was moved", relative));
+                }
+
+                // The data is now split between both roots. Keep the torrent
+                // wherever most of it is and name the files at the other one
+                let stranded_bytes: u64 = stranded.iter().map(|(_, len)| len).sum();
+                let (root, other, left) = if 2 * stranded_bytes > total {
+                    let stranded: Vec<&PathBuf> = stranded.iter().map(|(p, _)| *p).collect();
+                    let left: Vec<&PathBuf> = to_move.iter()
+                        .map(|(p, _)| p)
+                        .filter(|p| !stranded.contains(p))
+                        .collect();
+                    (new_root, old_root.as_path(), left)
+                } else {
+                    (old_root.as_path(), new_root, stranded.iter().map(|(p, _)| *p).collect())
+                };
+                self.set_root(root.to_path_buf());
+                reopen(&files, &mut guards, &was_open, root)?;
+                return Err(e).context(format!(
+                    "Failed to move {:?} and to move everything back. The torrent is at {:?}; \
+                     these files were left in {:?}: {:?}",
+                    relative, root, other, left,
+                ));
+            }
+            moved.push((relative, *length));
+            done += length;
+            progress(done, total);
+        }
+
+        self.set_root(new_root.to_path_buf());
+        reopen(&files, &mut guards, &was_open, new_root)?;
+
+        for (relative, _) in &to_move {
+            remove_empty_parents(&old_root, relative);
+        }
+        Ok(())
+    }
+}
+
+fn reopen(
+    files: &[super::StorageFile],
+    guards: &mut [MutexGuard<'_, Option<File>>],
+    was_open: &[bool],
+    root: &Path,
+) -> Result<()> {
+    for ((f, guard), was_open) in files.iter().zip(guards.iter_mut()).zip(was_open) {
+        if *was_open {
+            **guard = Some(open_file(&f.disk_path(root))?);
+        }
+    }
+    Ok(())
+}
+
+/// Rename `src` to `dst`, falling back to copy + delete across filesystems.
+/// `progress` gets the number of bytes of this file moved so far.
+fn move_file(src: &Path, dst: &Path, mut progress: impl FnMut(u64)) -> Result<()> {
+    if dst.exists() {
+        bail!("Destination already exists: {:?}", dst);
+    }
+    if let Some(parent) = dst.parent() {
+        std::fs::create_dir_all(parent)
+            .context(format!("Failed to create directory: {:?}", parent))?;
+    }
+
+    if std::fs::rename(src, dst).is_ok() {
+        return Ok(());
+    }
+
+    if let Err(e) = copy_file(src, dst, &mut progress) {
+        let _ = std::fs::remove_file(dst);
+        return Err(e);
+    }
+    if let Err(e) = std::fs::remove_file(src) {
+        let _ = std::fs::remove_file(dst);
+        return Err(e).context(format!("Failed to remove {:?} after copying", src));
+    }
+    Ok(())
+}
+
+fn copy_file(src: &Path, dst: &Path, progress: &mut impl FnMut(u64)) -> Result<()> {
+    let mut reader = File::open(src).context(format!("Failed to open {:?}", src))?;
+    let mut writer = File::create(dst).context(format!("Failed to create {:?}", dst))?;
+    let mut buf = vec![0u8; COPY_BUFFER_SIZE];
+    let mut copied = 0u64;
+
+    loop {
+        let n = reader.read(&mut buf).context(format!("Failed to read {:?}", src))?;
+        if n == 0 {
+            break;
+        }
+        writer.write_all(&buf[..n]).context(format!("Failed to write {:?}", dst))?;
+        copied += n as u64;
+        progress(copied);
+    }
+
+    writer.sync_all().context(format!("Failed to sync {:?}", dst))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use librqbit::{torrent_from_bytes, ByteBufOwned};
+    use crate::storage::Storage;
+    use crate::test_util::{content, create_torrent};
+
+    const A_LENGTH: usize = 20_000;
+    const B_LENGTH: usize = 50_000;
+
+    /// Data of "album" with a.bin and sub/b.bin under the old root, opened
+    /// as a storage
+    struct Fixture {
+        _dir: tempfile::TempDir,
+        old_root: PathBuf,
+        new_root: PathBuf,
+        a: Vec<u8>,
+        b: Vec<u8>,
+        location: Arc<StorageLocation>,
+        storage: Storage,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let old_root = dir.path().join("old");
+            let new_root = dir.path().join("new");
+            let album = old_root.join("album");
+            std::fs::create_dir_all(album.join("sub")).unwrap();
+            let a = content(A_LENGTH);
+            let b: Vec<u8> = content(B_LENGTH).into_iter().rev().collect();
+            std::fs::write(album.join("a.bin"), &a).unwrap();
+            std::fs::write(album.join("sub/b.bin"), &b).unwrap();
+
+            let created = create_torrent(&album, Some(16 * 1024));
+            let torrent = torrent_from_bytes::<ByteBufOwned>(&created.torrent_bytes).unwrap();
+            let location = StorageLocation::new(&old_root, false);
+            let storage = Storage::new(&torrent.info, &location).unwrap();
+            Fixture { _dir: dir, old_root, new_root, a, b, location, storage }
+        }
+
+        /// Check both files are whole under `root`, and that the storage
+        /// reads them from there
+        fn assert_at(&self, root: &Path) {
+            assert_eq!(self.location.root(), root);
+            assert_eq!(std::fs::read(root.join("album/a.bin")).unwrap(), self.a);
+            assert_eq!(std::fs::read(root.join("album/sub/b.bin")).unwrap(), self.b);
+            assert_eq!(self.storage.read_piece(0, 16 * 1024).unwrap(), self.a[..16 * 1024]);
+        }
+    }
+
+    #[test]
+    fn moves_every_file() {
+        let fixture = Fixture::new();
+        let mut reports = Vec::new();
+        fixture.location.relocate(&fixture.new_root, |done, total| reports.push((done, total))).unwrap();
+
+        fixture.assert_at(&fixture.new_root);
+        // Emptied folders are removed
+        assert!(!fixture.old_root.join("album").exists());
+        let total = (A_LENGTH + B_LENGTH) as u64;
+        assert_eq!(reports.first(), Some(&(0, total)));
+        assert_eq!(reports.last(), Some(&(total, total)));
+        assert!(reports.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_existing_files() {
+        let fixture = Fixture::new();
+        std::fs::create_dir_all(fixture.new_root.join("album")).unwrap();
+        std::fs::write(fixture.new_root.join("album/a.bin"), b"someone else's").unwrap();
+
+        let err = fixture.location.relocate(&fixture.new_root, |_, _| {}).unwrap_err();
+        assert!(format!("{:#}", err).contains("Destination already exists"), "{:#}", err);
+        fixture.assert_at(&fixture.old_root);
+        assert_eq!(std::fs::read(fixture.new_root.join("album/a.bin")).unwrap(), b"someone else's");
+    }
+
+    #[test]
+    fn failures_mid_move_put_moved_files_back() {
+        let fixture = Fixture::new();
+        let blocker = fixture.new_root.join("album/sub/b.bin");
+        // a.bin is moved first, then b.bin finds its destination taken
+        let err = fixture.location.relocate(&fixture.new_root, |done, _| {
+            if done == A_LENGTH as u64 && !blocker.exists() {
+                std::fs::create_dir_all(blocker.parent().unwrap()).unwrap();
+                std::fs::write(&blocker, b"").unwrap();
+            }
+        }).unwrap_err();
+
+        assert!(format!("{:#}", err).contains("nothing was moved"), "{:#}", err);
+        fixture.assert_at(&fixture.old_root);
+        assert!(!fixture.new_root.join("album/a.bin").exists());
+    }
+
+    #[test]
+    fn stays_with_most_of_the_data_when_files_cant_go_back() {
+        let fixture = Fixture::new();
+        let blocker = fixture.new_root.join("album/sub/b.bin");
+        let intruder = fixture.old_root.join("album/a.bin");
+        // Once a.bin is moved, b.bin can't follow and a.bin can't go back
+        let err = fixture.location.relocate(&fixture.new_root, |done, _| {
+            if done == A_LENGTH as u64 && !blocker.exists() {
+                std::fs::create_dir_all(blocker.parent().unwrap()).unwrap();
+                std::fs::write(&blocker, b"").unwrap();
+                std::fs::write(&intruder, b"").unwrap();
+            }
+        }).unwrap_err();
+
+        // b.bin, most of the data, never left the old root
+        let message = format!("{:#}", err);
+        assert!(message.contains("these files were left in"), "{}", message);
+        assert!(message.contains("a.bin"), "{}", message);
+        assert_eq!(fixture.location.root(), fixture.old_root);
+        assert_eq!(std::fs::read(fixture.new_root.join("album/a.bin")).unwrap(), fixture.a);
+        assert_eq!(std::fs::read(fixture.old_root.join("album/sub/b.bin")).unwrap(), fixture.b);
+    }
+
+    #[test]
+    fn copies_report_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src.bin");
+        let data = content(3 * COPY_BUFFER_SIZE / 2);
+        std::fs::write(&src, &data).unwrap();
+
+        let dst = dir.path().join("dst.bin");
+        let mut reports = Vec::new();
+        copy_file(&src, &dst, &mut |n| reports.push(n)).unwrap();
+        assert_eq!(std::fs::read(&dst).unwrap(), data);
+        assert_eq!(reports.last(), Some(&(data.len() as u64)));
+
+        // A missing source leaves no destination behind
+        let missing = dir.path().join("missing.bin");
+        assert!(move_file(&missing, &dir.path().join("other.bin"), |_| {}).is_err());
+        assert!(!dir.path().join("other.bin").exists());
+    }
+}
@@ -0,0 +1,286 @@
+// Turns file names coming from torrent metadata into paths that are safe to
+// create under the download directory.
+//
+// Torrent names are untrusted input: a malicious .torrent can try to escape
+// the output folder ("..", "/etc/passwd", "C:\\"), use names the OS refuses
+// to create ("CON", "a?b") or exceed the filesystem's name length limit.
+use std::path::PathBuf;
+use anyhow::{bail, Result};
+
+/// Most filesystems (ext4, NTFS, exFAT, F2FS) cap a single name at 255 bytes.
+pub const MAX_COMPONENT_BYTES: usize = 255;
+
+/// Extensions longer than this are not worth preserving when truncating.
+const MAX_EXTENSION_BYTES: usize = 16;
+
+/// Windows and the FAT/exFAT/sdcardfs volumes used for Android shared storage
+/// share the same restrictions, so both get the strict rules.
+const STRICT: bool = cfg!(any(windows, target_os = "android"));
+
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL",
+    "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+    "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// A relative path built from torrent metadata after sanitising
+#[derive(Debug, Clone)]
+pub struct SanitizedPath {
+    pub path: PathBuf,
+    /// The path as written in the torrent, components joined with '/'
+    pub original: String,
+    /// True if any component had to be changed
+    pub renamed: bool,
+}
+
+fn is_illegal_char(c: char) -> bool {
+    if c == '/' || c == '\0' {
+        return true;
+    }
+    STRICT && (c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '\\' | '|' | '?' | '*'))
+}
+
+fn is_reserved_name(name: &str) -> bool {
+    // "CON.txt" and "con" are just as reserved as "CON"
+    let stem = name.split('.').next().unwrap_or(name).trim_end();
+    RESERVED_NAMES.iter().any(|r| r.eq_ignore_ascii_case(stem))
+}
+
+/// Reject anything that could resolve outside the directory it is pushed onto
+fn check_traversal(raw: &str) -> Result<()> {
+    // Check both separators regardless of platform: a torrent made on Windows
+    // must not be able to smuggle "..\\" past a Unix build and vice versa
+    if raw.split(['/', '\\']).any(|part| part == "..") {
+        bail!("Path traversal in torrent file name: {:?}", raw);
+    }
+    if raw.starts_with('/') || raw.starts_with('\\') {
+        bail!("Absolute path in torrent file name: {:?}", raw);
+    }
+    let bytes = raw.as_bytes();
+    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        bail!("Drive prefix in torrent file name: {:?}", raw);
+    }
+    Ok(())
+}
+
+/// The longest prefix of `s` that fits in `max` bytes and ends on a char boundary
+pub fn truncate_str(s: &str, max: usize) -> &str {
+    if s.len() <= max {
+        return s;
+    }
+    let mut end = max;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Cut `name` down to `max` bytes on a char boundary, keeping a short extension
+pub fn truncate_name(name: &str, max: usize) -> String {
+    if name.len() <= max {
+        return name.to_string();
+    }
+
+    let (stem, ext) = match name.rfind('.') {
+        Some(dot) if dot > 0 && name.len() - dot <= MAX_EXTENSION_BYTES => name.split_at(dot),
+        _ => (name, ""),
+    };
+
+    format!("{}{}", truncate_str(stem, max - ext.len()), ext)
+}
+
+/// Sanitise a single path component.
+///
+/// Returns `Ok(None)` for components that carry no name ("" and "."), and an
+/// error for components that would escape the output directory.
+pub fn sanitize_component(raw: &str) -> Result<Option<String>> {
+    check_traversal(raw)?;
+    if raw.is_empty() || raw == "." {
+        return Ok(None);
+    }
+
+    let mut name: String = raw
+        .chars()
+        .map(|c| if is_illegal_char(c) { '_' } else { c })
+        .collect();
+
+    if STRICT {
+        // Windows silently strips trailing dots and spaces, which would make
+        // "a." and "a" collide
+        let trimmed_len = name.trim_end_matches(['.', ' ']).len();
+        if trimmed_len < name.len() {
+            name.truncate(trimmed_len);
+            name.push('_');
+        }
+        if is_reserved_name(&name) {
+            name.insert(0, '_');
+        }
+    }
+
+    Ok(Some(truncate_name(&name, MAX_COMPONENT_BYTES)))
+}
+
+/// Sanitise a sequence of raw (possibly non-UTF-8) path components into a
+/// relative path.
+pub fn sanitize_path<'a, I>(components: I) -> Result<SanitizedPath>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let mut path = PathBuf::new();
+    let mut original = Vec::new();
+    let mut renamed = false;
+
+    for raw in components {
+        let decoded = String::from_utf8_lossy(raw);
+        match sanitize_component(&decoded)? {
+            Some(clean) => {
+                renamed |= clean.as_bytes() != raw;
+                path.push(clean);
+            }
+            None => renamed = true,
+        }
+        original.push(decoded.into_owned());
+    }
+
+    if path.as_os_str().is_empty() {
+        bail!("Torrent file name is empty after sanitising: {:?}", original.join("/"));
+    }
+
+    Ok(SanitizedPath {
+        path,
+        original: original.join("/"),
+        renamed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(raw: &str) -> Option<String> {
+        sanitize_component(raw).unwrap()
+    }
+
+    #[test]
+    fn traversal_is_rejected() {
+        for raw in ["..", "../x", "a/../b", "..\\x", "a\\..\\b", "a/.."] {
+            let err = sanitize_component(raw).unwrap_err().to_string();
+            assert!(err.contains("Path traversal"), "{:?}: {}", raw, err);
+        }
+    }
+
+    #[test]
+    fn absolute_paths_are_rejected() {
+        for raw in ["/etc", "/", "\\x", "\\\\server\\share"] {
+            let err = sanitize_component(raw).unwrap_err().to_string();
+            assert!(err.contains("Absolute path"), "{:?}: {}", raw, err);
+        }
+    }
+
+    #[test]
+    fn drive_prefixes_are_rejected() {
+        for raw in ["C:", "C:foo", "c:\\x", "z:/x"] {
+            let err = sanitize_component(raw).unwrap_err().to_string();
+            assert!(err.contains("Drive prefix"), "{:?}: {}", raw, err);
+        }
+    }
+
+    #[test]
+    fn empty_components_have_no_name() {
+        for raw in ["", "."] {
+            assert_eq!(component(raw), None, "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn illegal_chars_are_replaced() {
+        // (raw, strict, lenient)
+        let cases = [
+            ("a/b", "a_b", "a_b"),
+            ("a\0b", "a_b", "a_b"),
+            ("a?b", "a_b", "a?b"),
+            ("ab:c<d>e", "ab_c_d_e", "ab:c<d>e"),
+            ("a\"b|c*d", "a_b_c_d", "a\"b|c*d"),
+            ("a\u{1}b", "a_b", "a\u{1}b"),
+            ("...", "_", "..."),
+            ("plain name.txt", "plain name.txt", "plain name.txt"),
+        ];
+        for (raw, strict, lenient) in cases {
+            let expected = if STRICT { strict } else { lenient };
+            assert_eq!(component(raw).as_deref(), Some(expected), "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn reserved_names_are_prefixed() {
+        // (raw, strict, lenient)
+        let cases = [
+            ("CON", "_CON", "CON"),
+            ("con", "_con", "con"),
+            ("nul.txt", "_nul.txt", "nul.txt"),
+            ("LPT9.tar.gz", "_LPT9.tar.gz", "LPT9.tar.gz"),
+            ("COM1 ", "COM1_", "COM1 "),
+            ("name.", "name_", "name."),
+            ("CONSOLE", "CONSOLE", "CONSOLE"),
+            ("COM10", "COM10", "COM10"),
+        ];
+        for (raw, strict, lenient) in cases {
+            let expected = if STRICT { strict } else { lenient };
+            assert_eq!(component(raw).as_deref(), Some(expected), "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn long_names_are_truncated() {
+        let long = "a".repeat(300);
+        let long_ext = format!("{}.{}", "b".repeat(300), "x".repeat(20));
+        // 'é' is two bytes, so 255 bytes falls in the middle of one
+        let multibyte = "é".repeat(200);
+        // (raw, expected length, expected suffix)
+        let cases = [
+            (format!("{}.mkv", long), MAX_COMPONENT_BYTES, ".mkv"),
+            (long.clone(), MAX_COMPONENT_BYTES, "a"),
+            (long_ext, MAX_COMPONENT_BYTES, "b"),
+            (format!("{}.txt", multibyte), MAX_COMPONENT_BYTES - 1, "é.txt"),
+            ("short.txt".to_string(), 9, "short.txt"),
+        ];
+        for (raw, len, suffix) in cases {
+            let name = component(&raw).unwrap();
+            assert_eq!(name.len(), len, "{:?}", raw);
+            assert!(name.ends_with(suffix), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn truncate_str_keeps_char_boundaries() {
+        let cases = [("abc", 5, "abc"), ("abc", 2, "ab"), ("aé", 2, "a"), ("aé", 3, "aé"), ("", 0, "")];
+        for (s, max, expected) in cases {
+            assert_eq!(truncate_str(s, max), expected, "{:?} {}", s, max);
+        }
+    }
+
+    #[test]
+    fn paths_are_joined_and_flagged() {
+        // (components, path, original, renamed)
+        let cases: [(&[&[u8]], &str, &str, bool); 4] = [
+            (&[b"dir", b"file.txt"], "dir/file.txt", "dir/file.txt", false),
+            (&[b"dir", b".", b"file"], "dir/file", "dir/./file", true),
+            (&[b"a/b"], "a_b", "a/b", true),
+            (&[b"bad\xffname"], "bad\u{fffd}name", "bad\u{fffd}name", true),
+        ];
+        for (components, path, original, renamed) in cases {
+            let sanitized = sanitize_path(components.iter().copied()).unwrap();
+            assert_eq!(sanitized.path, PathBuf::from(path));
+            assert_eq!(sanitized.original, original);
+            assert_eq!(sanitized.renamed, renamed, "{:?}", original);
+        }
+    }
+
+    #[test]
+    fn bad_paths_are_errors() {
+        let cases: [&[&[u8]]; 4] = [&[], &[b"", b"."], &[b"ok", b".."], &[b"/abs"]];
+        for components in cases {
+            assert!(sanitize_path(components.iter().copied()).is_err(), "{:?}", components);
+        }
+    }
+}
@@ -0,0 +1,536 @@
+// Playing files while they download.
+//
+// Both features sit on top of librqbit's file streams: a stream prioritises
+// the pieces just ahead of its read position, and reads wait for missing
+// pieces to arrive instead of failing.
+//
+// - Sequential mode and High file priority keep a stream reading through
+//   files front to back, so their pieces arrive first and roughly in order.
+// - A small HTTP server on 127.0.0.1 serves files with Range support, which
+//   is what media players need to seek in a partially downloaded file.
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use anyhow::{bail, Context, Result};
+use librqbit::api::TorrentIdOrHash;
+use rand::Rng;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::OnceCell;
+use tokio::task::AbortHandle;
+use crate::{get_session, torrents};
+
+const READ_BUFFER_SIZE: usize = 256 * 1024;
+
+/// Requests with longer header lines or more headers than this are refused
+const MAX_HEADER_LINE: usize = 8 * 1024;
+const MAX_HEADERS: usize = 100;
+
+/// Why a torrent's files are being read through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadThrough {
+    /// set_sequential_download
+    Sequential,
+    /// Files with High priority
+    HighPriority,
+}
+
+impl ReadThrough {
+    /// Prefix of the reader's log lines
+    fn log_prefix(self) -> &'static str {
+        match self {
+            ReadThrough::Sequential => "SEQUENTIAL",
+            ReadThrough::HighPriority => "PRIORITY",
+        }
+    }
+}
+
+struct Reader {
+    file_ids: Vec<usize>,
+    task: AbortHandle,
+}
+
+static READERS: LazyLock<Mutex<HashMap<(usize, ReadThrough), Reader>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn readers() -> std::sync::MutexGuard<'static, HashMap<(usize, ReadThrough), Reader>> {
+    READERS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Download `file_ids` of torrent `id` in order, each front to back.
+/// Replaces any reader of the same kind already running for the torrent.
+pub fn start_read_through(id: usize, kind: ReadThrough, file_ids: Vec<usize>) {
+    let key = (id, kind);
+    let ids = file_ids.clone();
+    let task = tokio::spawn(async move {
+        for file_id in ids {
+            if !read_through_file(id, kind, file_id).await {
+                break;
+            }
+        }
+        // Unless a newer task replaced this one meanwhile
+        let mut readers = readers();
+        if readers.get(&key).is_some_and(|r| r.task.id() == tokio::task::id()) {
+            readers.remove(&key);
+        }
+    });
+    let previous = readers().insert(key, Reader { file_ids, task: task.abort_handle() });
+    if let Some(previous) = previous {
+        previous.task.abort();
+    }
+}
+
+pub fn stop_read_through(id: usize, kind: ReadThrough) {
+    if let Some(reader) = readers().remove(&(id, kind)) {
+        reader.task.abort();
+    }
+}
+
+/// Files the running reader of this kind was started with, if any
+pub fn read_through_files(id: usize, kind: ReadThrough) -> Option<Vec<usize>> {
+    readers().get(&(id, kind)).map(|r| r.file_ids.clone())
+}
+
+/// Read a file through a stream until the end, retrying from the same
+/// position when the stream fails (the torrent is paused, rechecked...).
+/// Returns false if the torrent is gone from the session.
+async fn read_through_file(id: usize, kind: ReadThrough, file_id: usize) -> bool {
+    let mut position = 0u64;
+    loop {
+        match read_from(id, file_id, &mut position).await {
+            Ok(()) => return true,
+            Err(e) => {
+                let session = match get_session().await {
+                    Ok(session) => session,
+                    Err(_) => return false,
+                };
+                if session.get(TorrentIdOrHash::Id(id)).is_none() && !torrents::is_readding(id) {
+                    return false;
+                }
+                println!("{}: torrent {} file {}: {:#}, retrying", kind.log_prefix(), id, file_id, e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+}
+
+async fn read_from(id: usize, file_id: usize, position: &mut u64) -> Result<()> {
+    let session = get_session().await?;
+    let handle = session.get(TorrentIdOrHash::Id(id)).context("Torrent not found")?;
+
+    // Files that are already complete (or never downloaded) don't need a stream
+    let (length, skip) = handle.with_metadata(|m| {
+        m.file_infos.get(file_id).map(|f| (f.len, f.attrs.padding))
+    })?.context("No such file")?;
+    let have = handle.stats().file_progress.get(file_id).copied().unwrap_or(0);
+    if skip || have >= length {
+        return Ok(());
+    }
+
+    let mut stream = handle.stream(file_id)?;
+    stream.seek(SeekFrom::Start(*position)).await?;
+    let mut buf = vec![0u8; READ_BUFFER_SIZE];
+    while *position < length {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            bail!("Stream ended at {} of {} bytes", position, length);
+        }
+        *position += n as u64;
+    }
+    Ok(())
+}
+
+struct Server {
+    port: u16,
+    // Part of every URL so other apps on the device can't enumerate torrents
+    token: String,
+}
+
+static SERVER: OnceCell<Server> = OnceCell::const_new();
+
+/// URL serving file `file_id` of torrent `id`, starting the server if needed
+pub async fn file_url(id: usize, file_id: usize, file_name: &str) -> Result<String> {
+    let server = SERVER.get_or_try_init(start_server).await?;
+    Ok(format!(
+        "http://127.0.0.1:{}/{}/{}/{}/{}",
+        server.port,
+        server.token,
+        id,
+        file_id,
+        url::form_urlencoded::byte_serialize(file_name.as_bytes()).collect::<String>(),
+    ))
+}
+
+async fn start_server() -> Result<Server> {
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await
+        .context("Failed to start the streaming server")?;
+    let port = listener.local_addr()?.port();
+    let token = hex::encode(rand::thread_rng().gen::<[u8; 16]>());
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((socket, _)) => {
+                    tokio::spawn(async move {
+                        let _ = serve_connection(socket).await;
+                    });
+                }
+                Err(e) => {
+                    println!("STREAMING: accept failed: {}", e);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+            }
+        }
+    });
+
+    Ok(Server { port, token })
+}
+
+struct Request {
+    method: String,
+    path: String,
+    range: Option<String>,
+    keep_alive: bool,
+}
+
+async fn serve_connection(socket: TcpStream) -> Result<()> {
+    let (reader, mut writer) = socket.into_split();
+    let mut reader = BufReader::new(reader);
+    while let Some(request) = read_request(&mut reader).await? {
+        respond(&request, &mut writer).await?;
+        if !request.keep_alive {
+            break;
+        }
+    }
+    Ok(())
+}
+
+async fn read_line<R: AsyncBufReadExt + Unpin>(reader: &mut R) -> Result<Option<String>> {
+    let mut line = Vec::new();
+    let n = (&mut *reader).take(MAX_HEADER_LINE as u64).read_until(b'\n', &mut line).await?;
+    if n == 0 {
+        return Ok(None);
+    }
+    if line.last() != Some(&b'\n') {
+        bail!("Header line too long");
+    }
+    Ok(Some(String::from_utf8_lossy(&line).trim_end().to_string()))
+}
+
+/// Read the next request on the connection, None once the client closes it
+async fn read_request<R: AsyncBufReadExt + Unpin>(reader: &mut R) -> Result<Option<Request>> {
+    let Some(request_line) = read_line(reader).await? else {
+        return Ok(None);
+    };
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path), Some(version)) = (parts.next(), parts.next(), parts.next()) else {
+        bail!("Malformed request line: {:?}", request_line);
+    };
+
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        range: None,
+        keep_alive: version == "HTTP/1.1",
+    };
+    for _ in 0..MAX_HEADERS {
+        let Some(header) = read_line(reader).await? else {
+            bail!("Connection closed in headers");
+        };
+        if header.is_empty() {
+            return Ok(Some(request));
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "range" => request.range = Some(value.to_string()),
+                "connection" => request.keep_alive = !value.eq_ignore_ascii_case("close"),
+                _ => {}
+            }
+        }
+    }
+    bail!("Too many headers")
+}
+
+/// Resolve a Range header against a file of `length` bytes into an inclusive
+/// (start, end). Only single ranges are supported, like most media servers.
+fn parse_range(range: &str, length: u64) -> Option<(u64, u64)> {
+    let spec = range.strip_prefix("bytes=")?.trim();
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = if start.is_empty() {
+        // "-N": the last N bytes
+        let suffix: u64 = end.parse().ok()?;
+        if suffix == 0 {
+            return None;
+        }
+        (length.saturating_sub(suffix), length.checked_sub(1)?)
+    } else {
+        let start: u64 = start.parse().ok()?;
+        let end = match end {
+            "" => length.checked_sub(1)?,
+            end => end.parse::<u64>().ok()?.min(length.checked_sub(1)?),
+        };
+        (start, end)
+    };
+    (start <= end && end < length).then_some((start, end))
+}
+
+fn content_type(file_name: &str) -> &'static str {
+    let extension = file_name.rsplit('.').next().unwrap_or("").to_ascii_lowercase();
+    match extension.as_str() {
+        "mp4" | "m4v" => "video/mp4",
+        "mkv" => "video/x-matroska",
+        "webm" => "video/webm",
+        "avi" => "video/x-msvideo",
+        "mov" => "video/quicktime",
+        "ts" => "video/mp2t",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "flac" => "audio/flac",
+        "ogg" => "audio/ogg",
+        "wav" => "audio/wav",
+        "srt" => "application/x-subrip",
+        "vtt" => "text/vtt",
+        _ => "application/octet-stream",
+    }
+}
+
+async fn write_head<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    status: &str,
+    headers: &[(&str, String)],
+    keep_alive: bool,
+) -> Result<()> {
+    let mut head = format!("HTTP/1.1 {}\r\n", status);
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str(if keep_alive { "Connection: keep-alive\r\n\r\n" } else { "Connection: close\r\n\r\n" });
+    writer.write_all(head.as_bytes()).await?;
+    Ok(())
+}
+
+async fn write_error<W: AsyncWrite + Unpin>(writer: &mut W, status: &str, keep_alive: bool) -> Result<()> {
+    write_head(writer, status, &[("Content-Length", "0".to_string())], keep_alive).await
+}
+
+async fn respond<W: AsyncWrite + Unpin>(request: &Request, writer: &mut W) -> Result<()> {
+    let keep_alive = request.keep_alive;
+    let is_head = match request.method.as_str() {
+        "GET" => false,
+        "HEAD" => true,
+        _ => return write_error(writer, "405 Method Not Allowed", keep_alive).await,
+    };
+
+    // /<token>/<torrent id>/<file index>/<file name>
+    let token = SERVER.get().map(|s| s.token.as_str());
+    let mut segments = request.path.trim_start_matches('/').split('/');
+    let target = match (segments.next(), segments.next(), segments.next()) {
+        (t, Some(id), Some(file_id)) if t == token => id.parse::<usize>().ok().zip(file_id.parse::<usize>().ok()),
+        _ => None,
+    };
+    let Some((id, file_id)) = target else {
+        return write_error(writer, "404 Not Found", keep_alive).await;
+    };
+
+    let session = get_session().await?;
+    let Some(handle) = session.get(TorrentIdOrHash::Id(id)) else {
+        return write_error(writer, "404 Not Found", keep_alive).await;
+    };
+    let file = handle.with_metadata(|m| {
+        m.file_infos.get(file_id).map(|f| (f.len, f.relative_filename.to_string_lossy().to_string()))
+    }).ok().flatten();
+    let Some((length, file_name)) = file else {
+        return write_error(writer, "404 Not Found", keep_alive).await;
+    };
+
+    let (status, start, end) = match &request.range {
+        Some(range) => match parse_range(range, length) {
+            Some((start, end)) => ("206 Partial Content", start, end),
+            None => {
+                return write_head(writer, "416 Range Not Satisfiable", &[
+                    ("Content-Range", format!("bytes */{}", length)),
+                    ("Content-Length", "0".to_string()),
+                ], keep_alive).await;
+            }
+        },
+        None => ("200 OK", 0, length.saturating_sub(1)),
+    };
+    let body_length = if length == 0 { 0 } else { end - start + 1 };
+
+    // Open the stream before answering so failures can still get a status
+    let stream = if is_head || body_length == 0 {
+        None
+    } else {
+        match handle.clone().stream(file_id) {
+            Ok(stream) => Some(stream),
+            Err(_) => return write_error(writer, "503 Service Unavailable", keep_alive).await,
+        }
+    };
+
+    let mut headers = vec![
+        ("Content-Type", content_type(&file_name).to_string()),
+        ("Content-Length", body_length.to_string()),
+        ("Accept-Ranges", "bytes".to_string()),
+    ];
+    if request.range.is_some() {
+        headers.push(("Content-Range", format!("bytes {}-{}/{}", start, end, length)));
+    }
+    write_head(writer, status, &headers, keep_alive).await?;
+
+    if let Some(mut stream) = stream {
+        stream.seek(SeekFrom::Start(start)).await?;
+        // Reads wait for missing pieces, which the stream asks for first
+        let copied = tokio::io::copy(&mut stream.take(body_length), writer).await?;
+        if copied < body_length {
+            bail!("Stream ended after {} of {} bytes", copied, body_length);
+        }
+    }
+    writer.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librqbit::{AddTorrent, AddTorrentOptions};
+    use crate::storage::StorageLocation;
+    use crate::test_util::{content, create_torrent, wait_for, with_session};
+
+    async fn request(text: &str) -> Result<Option<Request>> {
+        read_request(&mut BufReader::new(text.as_bytes())).await
+    }
+
+    #[test]
+    fn ranges_resolve_against_the_file_length() {
+        // (header, file length, inclusive range)
+        let cases = [
+            ("bytes=0-99", 1000, Some((0, 99))),
+            ("bytes=100-", 1000, Some((100, 999))),
+            ("bytes=-100", 1000, Some((900, 999))),
+            ("bytes=-5000", 1000, Some((0, 999))),
+            ("bytes=990-5000", 1000, Some((990, 999))),
+            ("bytes= 5-9", 1000, Some((5, 9))),
+            // Unsatisfiable
+            ("bytes=1000-", 1000, None),
+            ("bytes=1000-1001", 1000, None),
+            ("bytes=10-5", 1000, None),
+            ("bytes=-0", 1000, None),
+            ("bytes=0-", 0, None),
+            ("bytes=-1", 0, None),
+            // Malformed or unsupported
+            ("bytes=0-1,5-9", 1000, None),
+            ("bytes=5", 1000, None),
+            ("bytes=a-b", 1000, None),
+            ("bytes=--1", 1000, None),
+            ("items=0-99", 1000, None),
+            ("0-99", 1000, None),
+        ];
+        for (header, length, range) in cases {
+            assert_eq!(parse_range(header, length), range, "{} of {}", header, length);
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_are_read_one_after_another() {
+        let text = "GET /a HTTP/1.1\r\nRange: bytes=0-9\r\nX-Other: 1\r\n\r\nHEAD /b HTTP/1.1\r\nconnection: Close\r\n\r\n";
+        let mut reader = BufReader::new(text.as_bytes());
+        let first = read_request(&mut reader).await.unwrap().unwrap();
+        assert_eq!((first.method.as_str(), first.path.as_str()), ("GET", "/a"));
+        assert_eq!(first.range.as_deref(), Some("bytes=0-9"));
+        assert!(first.keep_alive);
+        let second = read_request(&mut reader).await.unwrap().unwrap();
+        assert_eq!((second.method.as_str(), second.range), ("HEAD", None));
+        assert!(!second.keep_alive);
+        assert!(read_request(&mut reader).await.unwrap().is_none());
+
+        assert!(!request("GET / HTTP/1.0\r\n\r\n").await.unwrap().unwrap().keep_alive);
+        assert!(request("GET /\r\n\r\n").await.is_err());
+        assert!(request("GET / HTTP/1.1\r\nRange: bytes=0-9\r\n").await.is_err());
+        let long = format!("GET / HTTP/1.1\r\nX: {}\r\n\r\n", "a".repeat(MAX_HEADER_LINE));
+        assert!(request(&long).await.is_err());
+        let many = format!("GET / HTTP/1.1\r\n{}\r\n", "X: 1\r\n".repeat(MAX_HEADERS + 1));
+        assert!(request(&many).await.is_err());
+    }
+
+    /// Status line, headers and body of a response
+    fn split_response(response: &[u8]) -> (String, Vec<u8>) {
+        let end = response.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+        (String::from_utf8_lossy(&response[..end]).into_owned(), response[end + 4..].to_vec())
+    }
+
+    #[test]
+    fn responses_serve_the_requested_range() {
+        with_session(async {
+            let dir = tempfile::tempdir().unwrap();
+            let data = content(50_000);
+            std::fs::write(dir.path().join("movie.mp4"), &data).unwrap();
+            let created = create_torrent(&dir.path().join("movie.mp4"), Some(16 * 1024));
+            let location = StorageLocation::new(dir.path(), false);
+            let id = torrents::allocate_id();
+            torrents::register(id, location.clone(), None);
+            let session = get_session().await.unwrap();
+            let handle = session
+                .add_torrent(AddTorrent::from_bytes(created.torrent_bytes), Some(AddTorrentOptions {
+                    preferred_id: Some(id),
+                    ..torrents::download_options(&location)
+                }))
+                .await.unwrap().into_handle().unwrap();
+            wait_for("the data to be checked", || handle.stats().finished).await;
+
+            let url = url::Url::parse(&file_url(id, 0, "movie.mp4").await.unwrap()).unwrap();
+            let respond_to = |method: &str, path: &str, range: Option<&str>| {
+                let request = Request {
+                    method: method.to_string(),
+                    path: path.to_string(),
+                    range: range.map(String::from),
+                    keep_alive: true,
+                };
+                async move {
+                    let mut out = Vec::new();
+                    respond(&request, &mut out).await.unwrap();
+                    split_response(&out)
+                }
+            };
+            let path = url.path();
+
+            let (head, body) = respond_to("GET", path, None).await;
+            assert!(head.starts_with("HTTP/1.1 200 OK\r\n"), "{}", head);
+            assert!(head.contains("Content-Type: video/mp4") && head.contains("Content-Length: 50000"), "{}", head);
+            assert_eq!(body, data);
+
+            // (Range header, status, Content-Range, body)
+            let cases = [
+                ("bytes=100-199", "206", "bytes 100-199/50000", &data[100..200]),
+                ("bytes=49000-", "206", "bytes 49000-49999/50000", &data[49000..]),
+                ("bytes=-10", "206", "bytes 49990-49999/50000", &data[49990..]),
+                ("bytes=50000-", "416", "bytes */50000", &[][..]),
+                ("bytes=0-1,5-6", "416", "bytes */50000", &[][..]),
+                ("lines=1-2", "416", "bytes */50000", &[][..]),
+            ];
+            for (range, status, content_range, expected) in cases {
+                let (head, body) = respond_to("GET", path, Some(range)).await;
+                assert!(head.starts_with(&format!("HTTP/1.1 {}", status)), "{}: {}", range, head);
+                assert!(head.contains(&format!("Content-Range: {}\r\n", content_range)), "{}: {}", range, head);
+                assert!(head.contains(&format!("Content-Length: {}\r\n", expected.len())), "{}: {}", range, head);
+                assert_eq!(body, expected, "{}", range);
+            }
+
+            let (head, body) = respond_to("HEAD", path, Some("bytes=0-9")).await;
+            assert!(head.starts_with("HTTP/1.1 206") && head.contains("Content-Length: 10\r\n"), "{}", head);
+            assert!(body.is_empty());
+            let (head, _) = respond_to("POST", path, None).await;
+            assert!(head.starts_with("HTTP/1.1 405"), "{}", head);
+            for path in [format!("/wrong{}", path), path.replacen(&format!("/{}/0/", id), &format!("/{}/1/", id), 1), "/".to_string()] {
+                let (head, _) = respond_to("GET", &path, None).await;
+                assert!(head.starts_with("HTTP/1.1 404"), "{}: {}", path, head);
+            }
+            drop(handle);
+            session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+}
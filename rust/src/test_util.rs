@@ -0,0 +1,215 @@
+// Loopback servers standing in for trackers, web seeds, gateways and other
+// HTTP endpoints in tests, and helpers for tests that use the app's session.
+use std::future::Future;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::Path;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+use librqbit::{AddTorrent, AddTorrentOptions, Session, SessionOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use crate::api::create::CreateTorrentOptions;
+use crate::create::{Created, Plan, Progress};
+
+/// A request as the server received it
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: String,
+    /// Path and query
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// Send a Content-Length header, otherwise the body ends with the
+    /// connection
+    pub send_length: bool,
+    /// Wait this long before responding
+    pub delay: Duration,
+}
+
+impl HttpResponse {
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into(),
+            send_length: true,
+            delay: Duration::ZERO,
+        }
+    }
+
+    pub fn status(status: u16) -> Self {
+        Self { status, ..Self::ok(Vec::new()) }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+type Handler = dyn Fn(&HttpRequest) -> HttpResponse + Send + Sync;
+
+/// An HTTP/1.1 server answering every request with `handler`, one request
+/// per connection
+pub struct HttpServer {
+    pub addr: SocketAddr,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl HttpServer {
+    pub async fn start(handler: impl Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    let _ = serve(stream, &*handler, &recorded).await;
+                });
+            }
+        });
+        Self { addr, requests }
+    }
+
+    pub fn url(&self, target: &str) -> String {
+        format!("http://{}{}", self.addr, target)
+    }
+
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn serve(mut stream: TcpStream, handler: &Handler, recorded: &Mutex<Vec<HttpRequest>>) -> std::io::Result<()> {
+    let mut buf = Vec::new();
+    let head_end = loop {
+        if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+        let mut chunk = [0u8; 4096];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default().to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+    let mut request = HttpRequest { method, target, headers, body: buf[head_end + 4..].to_vec() };
+    let length: usize = request.header("Content-Length").and_then(|l| l.parse().ok()).unwrap_or(0);
+    while request.body.len() < length {
+        let mut chunk = [0u8; 4096];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        request.body.extend_from_slice(&chunk[..n]);
+    }
+    recorded.lock().unwrap().push(request.clone());
+
+    let response = handler(&request);
+    tokio::time::sleep(response.delay).await;
+    let mut head = format!("HTTP/1.1 {} Status\r\nConnection: close\r\n", response.status);
+    if response.send_length {
+        head.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+    }
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&response.body).await?;
+    stream.shutdown().await
+}
+
+/// The app's session outlives any one test, and each #[tokio::test] has a
+/// runtime of its own that would take the session's tasks down with it
+static SESSION_RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap()
+});
+static SESSION_TESTS: Mutex<()> = Mutex::new(());
+
+/// Run a test that uses the app's session, one at a time
+pub fn with_session(test: impl Future<Output = ()>) {
+    let _guard = SESSION_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+    SESSION_RUNTIME.block_on(test);
+}
+
+/// Poll `condition` until it holds, failing the test after 30 seconds
+pub async fn wait_for(what: &str, mut condition: impl FnMut() -> bool) {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+    while !condition() {
+        assert!(tokio::time::Instant::now() < deadline, "Timed out waiting for {}", what);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// `length` bytes that differ from piece to piece
+pub fn content(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i / 7 % 251) as u8).collect()
+}
+
+/// A torrent of the file or folder at `path`
+pub fn create_torrent(path: &Path, piece_length: Option<u32>) -> Created {
+    let options = CreateTorrentOptions { piece_length, ..Default::default() };
+    Plan::new(path, options).unwrap().build(&Progress::default()).unwrap()
+}
+
+/// A session of its own seeding a torrent from `dir`, for the app's
+/// session to download from
+pub struct Seeder {
+    pub addr: SocketAddr,
+    session: Arc<Session>,
+}
+
+impl Seeder {
+    pub async fn start(torrent_bytes: &[u8], dir: &Path) -> Self {
+        let port = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap().local_addr().unwrap().port();
+        let session = Session::new_with_opts(dir.to_path_buf(), SessionOptions {
+            disable_dht: true,
+            persistence: None,
+            listen_port_range: Some(port..port + 1),
+            ..Default::default()
+        }).await.unwrap();
+        let handle = session.add_torrent(AddTorrent::from_bytes(torrent_bytes.to_vec()), Some(AddTorrentOptions {
+            output_folder: Some(dir.to_string_lossy().to_string()),
+            overwrite: true,
+            ..Default::default()
+        })).await.unwrap().into_handle().unwrap();
+        wait_for("the seeder to check its data", || handle.stats().finished).await;
+        Self { addr: (Ipv4Addr::LOCALHOST, port).into(), session }
+    }
+}
+
+impl Drop for Seeder {
+    fn drop(&mut self) {
+        let session = self.session.clone();
+        tokio::spawn(async move { session.stop().await });
+    }
+}
@@ -0,0 +1,186 @@
+// Downloading .torrent files from http(s) URLs.
+//
+// librqbit can fetch them itself, but with no limits at all, so they are
+// downloaded here and added from their contents. Responses are checked for
+// what a .torrent looks like, so a login or error page fails with a clear
+// error instead of as invalid metadata. They go through the tracker proxy.
+use std::time::Duration;
+use anyhow::{bail, Context, Result};
+use librqbit::ByteBufOwned;
+use librqbit_bencode::BencodeValue;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+use url::Url;
+use crate::proxy;
+
+/// Largest .torrent file downloaded
+const MAX_SIZE: u64 = 10 * 1024 * 1024;
+const MAX_REDIRECTS: usize = 5;
+const TIMEOUT: Duration = Duration::from_secs(30);
+/// Content types servers send .torrent files with
+const CONTENT_TYPES: &[&str] = &[
+    "application/x-bittorrent",
+    "application/octet-stream",
+    "binary/octet-stream",
+    "application/force-download",
+    "application/x-download",
+];
+
+/// Whether a source is an http(s) URL rather than a magnet link or path
+pub fn is_url(source: &str) -> bool {
+    let lower = source.to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+/// Download a .torrent file, returning its contents
+pub async fn fetch(source: &str) -> Result<Vec<u8>> {
+    fetch_within(source, TIMEOUT).await
+}
+
+async fn fetch_within(source: &str, timeout: Duration) -> Result<Vec<u8>> {
+    let url = Url::parse(source).context(format!("Invalid URL: {}", source))?;
+    let client = proxy::http_client_builder(proxy::tracker_proxy().as_ref())?
+        .redirect(Policy::limited(MAX_REDIRECTS))
+        .timeout(timeout)
+        .build()?;
+    let describe = |e: reqwest::Error| describe(&e, timeout);
+
+    let mut response = client.get(url.clone()).send().await
+        .map_err(|e| anyhow::anyhow!("Failed to download {}: {}", url, describe(e)))?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!("Failed to download {}: HTTP {}", url, status);
+    }
+    if let Some(content_type) = response.headers().get(CONTENT_TYPE) {
+        let content_type = content_type.to_str().unwrap_or_default();
+        let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        if !CONTENT_TYPES.contains(&mime.as_str()) {
+            bail!("Not a .torrent file: {} is {}", url, content_type);
+        }
+    }
+    if response.content_length().is_some_and(|length| length > MAX_SIZE) {
+        bail!("Torrent file too large: {} is over {} MiB", url, MAX_SIZE / (1024 * 1024));
+    }
+
+    // The length isn't always given, or right, so it is checked as it comes
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await
+        .map_err(|e| anyhow::anyhow!("Failed to download {}: {}", url, describe(e)))?
+    {
+        if bytes.len() as u64 + chunk.len() as u64 > MAX_SIZE {
+            bail!("Torrent file too large: {} is over {} MiB", url, MAX_SIZE / (1024 * 1024));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    // Bencoded metadata is a dictionary
+    if !matches!(librqbit_bencode::dyn_from_bytes::<ByteBufOwned>(&bytes), Ok(BencodeValue::Dict(_))) {
+        bail!("Not a .torrent file: {}", url);
+    }
+    Ok(bytes)
+}
+
+/// A reqwest error by its causes, without the URL it repeats
+fn describe(error: &reqwest::Error, timeout: Duration) -> String {
+    if error.is_timeout() {
+        return format!("timed out after {:?}", timeout);
+    }
+    if error.is_redirect() {
+        return format!("more than {} redirects", MAX_REDIRECTS);
+    }
+    let mut causes = Vec::new();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    if causes.is_empty() {
+        error.to_string()
+    } else {
+        causes.join(": ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{HttpResponse, HttpServer};
+
+    const TORRENT: &[u8] = b"d4:infod4:name1:a6:lengthi1eee";
+
+    async fn fetch_error(server: &HttpServer, target: &str) -> String {
+        fetch(&server.url(target)).await.err().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn fetches_torrent_files() {
+        let server = HttpServer::start(|request| match request.target.as_str() {
+            "/typed" => HttpResponse::ok(TORRENT).header("Content-Type", "application/x-bittorrent; charset=binary"),
+            _ => HttpResponse::ok(TORRENT),
+        }).await;
+        assert_eq!(fetch(&server.url("/typed")).await.unwrap(), TORRENT);
+        assert_eq!(fetch(&server.url("/untyped")).await.unwrap(), TORRENT);
+        assert_eq!(server.requests()[0].method, "GET");
+    }
+
+    #[tokio::test]
+    async fn rejects_what_isnt_a_torrent_file() {
+        let server = HttpServer::start(|request| match request.target.as_str() {
+            "/page" => HttpResponse::ok("<html>Log in</html>").header("Content-Type", "text/html; charset=utf-8"),
+            "/html" => HttpResponse::ok("<html>Log in</html>"),
+            "/truncated" => HttpResponse::ok(&TORRENT[..TORRENT.len() - 1]),
+            "/list" => HttpResponse::ok("l4:spame"),
+            _ => HttpResponse::status(404),
+        }).await;
+        // (target, error)
+        let cases = [
+            ("/page", "is text/html; charset=utf-8"),
+            ("/html", "Not a .torrent file"),
+            ("/truncated", "Not a .torrent file"),
+            ("/list", "Not a .torrent file"),
+            ("/missing", "HTTP 404 Not Found"),
+        ];
+        for (target, error) in cases {
+            let err = fetch_error(&server, target).await;
+            assert!(err.contains(error), "{}: {}", target, err);
+        }
+    }
+
+    #[tokio::test]
+    async fn limits_the_size() {
+        let server = HttpServer::start(|request| {
+            let mut body = TORRENT.to_vec();
+            body.resize(MAX_SIZE as usize + 1, b'e');
+            HttpResponse { send_length: request.target == "/length", ..HttpResponse::ok(body) }
+        }).await;
+        for target in ["/length", "/streamed"] {
+            let err = fetch_error(&server, target).await;
+            assert!(err.contains("Torrent file too large"), "{}: {}", target, err);
+        }
+    }
+
+    #[tokio::test]
+    async fn limits_redirects() {
+        // /n redirects to /n-1, and /0 is the file
+        let server = HttpServer::start(|request| {
+            let n: usize = request.target[1..].parse().unwrap();
+            match n {
+                0 => HttpResponse::ok(TORRENT),
+                n => HttpResponse::status(302).header("Location", &format!("/{}", n - 1)),
+            }
+        }).await;
+        let target = format!("/{}", MAX_REDIRECTS);
+        assert_eq!(fetch(&server.url(&target)).await.unwrap(), TORRENT);
+        let err = fetch_error(&server, &format!("/{}", MAX_REDIRECTS + 1)).await;
+        assert!(err.contains("more than 5 redirects"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn times_out() {
+        let server = HttpServer::start(|_| HttpResponse {
+            delay: Duration::from_secs(5),
+            ..HttpResponse::ok(TORRENT)
+        }).await;
+        let err = fetch_within(&server.url("/slow"), Duration::from_millis(200)).await.err().unwrap();
+        assert!(err.to_string().contains("timed out after 200ms"), "{}", err);
+    }
+}
@@ -0,0 +1,489 @@
+// App-side bookkeeping for torrents in the librqbit session.
+//
+// librqbit keeps the output folder of a torrent private, and some operations
+// (recheck, restarting the session) have to remove and re-add the torrent, so
+// everything we need to rebuild it is tracked here, keyed by librqbit's
+// torrent id.
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use anyhow::Context;
+use librqbit::api::TorrentIdOrHash;
+use librqbit::{torrent_from_bytes, AddTorrent, AddTorrentOptions, ByteBufOwned, ManagedTorrent, Session};
+use librqbit_bencode::raw_value::RawValue;
+use librqbit_bencode::BencodeValue;
+use url::Url;
+use crate::api::simple::FilePriority;
+use crate::storage::{DiskStorageFactory, StorageLocation};
+
+#[derive(Clone)]
+pub struct TorrentEntry {
+    pub location: Arc<StorageLocation>,
+    /// Set while the torrent is being removed and re-added
+    pub readding: bool,
+    /// (bytes moved, total bytes) while move_storage is running
+    pub move_progress: Option<(u64, u64)>,
+    /// Where the data goes once the download finishes, if not where it is
+    pub complete_dir: Option<PathBuf>,
+    /// Set once the completion watcher is done with the torrent
+    pub finished: bool,
+    /// Set if finishing the torrent (renaming or moving files) failed
+    pub error: Option<String>,
+    /// Per-file priorities, empty until set_file_priorities is called
+    pub file_priorities: Vec<FilePriority>,
+    /// Peers added with add_peer, given to librqbit whenever the torrent is added
+    pub peers: Vec<SocketAddr>,
+    /// Trackers by tier, set once add_trackers or remove_tracker changed them.
+    /// librqbit gets them when the torrent is re-added with them.
+    pub trackers: Option<Vec<Vec<Url>>>,
+    /// Paused by the interface watchdog, to resume when the interface is back
+    pub network_paused: bool,
+    /// BEP 19 web seeds of the torrent, from its url-list or the magnet's ws=
+    pub web_seeds: Vec<Url>,
+    /// Set while the torrent is out of the session because adding it back
+    /// failed, for the next re-add or session restart to try again
+    pub unadded: Option<SavedTorrent>,
+}
+
+/// Torrent ids are handed out here rather than by librqbit: a restarted
+/// session would count from 0 again and reuse the ids of re-added torrents
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+static TORRENTS: LazyLock<Mutex<HashMap<usize, TorrentEntry>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn with_torrents<R>(f: impl FnOnce(&mut HashMap<usize, TorrentEntry>) -> R) -> R {
+    let mut torrents = TORRENTS.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut torrents)
+}
+
+/// Remember where a newly added torrent stores its data.
+/// Torrents that are already registered keep their existing entry, and
+/// false is returned for them.
+pub fn register(id: usize, location: Arc<StorageLocation>, complete_dir: Option<PathBuf>) -> bool {
+    with_torrents(|t| {
+        if t.contains_key(&id) {
+            return false;
+        }
+        t.insert(id, TorrentEntry {
+            location,
+            readding: false,
+            move_progress: None,
+            complete_dir,
+            finished: false,
+            error: None,
+            file_priorities: Vec::new(),
+            peers: Vec::new(),
+            trackers: None,
+            network_paused: false,
+            web_seeds: Vec::new(),
+            unadded: None,
+        });
+        true
+    })
+}
+
+/// Forget a torrent that never made it into the session
+pub fn unregister(id: usize) {
+    with_torrents(|t| t.remove(&id));
+}
+
+pub fn get(id: usize) -> Option<TorrentEntry> {
+    with_torrents(|t| t.get(&id).cloned())
+}
+
+fn update(id: usize, f: impl FnOnce(&mut TorrentEntry)) {
+    with_torrents(|t| {
+        if let Some(entry) = t.get_mut(&id) {
+            f(entry);
+        }
+    });
+}
+
+pub fn set_readding(id: usize, readding: bool) {
+    update(id, |e| e.readding = readding);
+}
+
+pub fn is_readding(id: usize) -> bool {
+    with_torrents(|t| t.get(&id).is_some_and(|e| e.readding))
+}
+
+pub fn set_move_progress(id: usize, progress: Option<(u64, u64)>) {
+    update(id, |e| e.move_progress = progress);
+}
+
+pub fn set_complete_dir(id: usize, complete_dir: Option<PathBuf>) {
+    update(id, |e| e.complete_dir = complete_dir);
+}
+
+pub fn set_finished(id: usize, error: Option<String>) {
+    update(id, |e| {
+        e.finished = true;
+        e.error = error;
+    });
+}
+
+pub fn set_file_priorities(id: usize, priorities: Vec<FilePriority>) {
+    update(id, |e| e.file_priorities = priorities);
+}
+
+pub fn set_network_paused(id: usize, paused: bool) {
+    update(id, |e| e.network_paused = paused);
+}
+
+pub fn set_web_seeds(id: usize, web_seeds: Vec<Url>) {
+    update(id, |e| e.web_seeds = web_seeds);
+}
+
+/// Mark a finished torrent as unfinished again, e.g. because more of its
+/// files were selected. Returns true if it was finished.
+pub fn reopen(id: usize) -> bool {
+    with_torrents(|t| match t.get_mut(&id) {
+        Some(entry) if entry.finished => {
+            entry.finished = false;
+            entry.error = None;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Add a peer to use for a torrent from its next (re-)add on.
+/// Returns false if it was already there.
+pub fn add_peer(id: usize, peer: SocketAddr) -> bool {
+    with_torrents(|t| match t.get_mut(&id) {
+        Some(entry) if !entry.peers.contains(&peer) => {
+            entry.peers.push(peer);
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Replace the trackers of a torrent from its next (re-)add on
+pub fn set_trackers(id: usize, tiers: Vec<Vec<Url>>) {
+    update(id, |e| e.trackers = Some(tiers));
+}
+
+/// Trackers of a torrent by tier: the ones set with set_trackers, or those
+/// of its metadata followed by any other librqbit knows of (magnet links
+/// still fetching metadata, trackers added with the torrent) as one more tier
+pub fn tracker_tiers(handle: &ManagedTorrent) -> Vec<Vec<Url>> {
+    if let Some(tiers) = get(handle.id()).and_then(|e| e.trackers) {
+        return tiers;
+    }
+
+    let mut tiers: Vec<Vec<Url>> = handle
+        .with_metadata(|m| torrent_from_bytes::<ByteBufOwned>(&m.torrent_bytes).ok())
+        .ok()
+        .flatten()
+        .map(|torrent| {
+            let tiers = if torrent.announce_list.iter().flatten().next().is_some() {
+                torrent.announce_list
+            } else {
+                torrent.announce.into_iter().map(|a| vec![a]).collect()
+            };
+            tiers.iter()
+                .map(|tier| tier.iter()
+                    .filter_map(|url| Url::parse(&String::from_utf8_lossy(url.as_ref())).ok())
+                    .collect::<Vec<_>>())
+                .filter(|tier| !tier.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut others: Vec<Url> = handle.shared().trackers.iter()
+        .filter(|url| !tiers.iter().flatten().any(|t| t == *url))
+        .cloned()
+        .collect();
+    if !others.is_empty() {
+        others.sort();
+        tiers.push(others);
+    }
+    tiers
+}
+
+/// A .torrent file for `info_bytes` announcing to `tiers`. The info
+/// dictionary is copied as is, so the info hash doesn't change, and the
+/// other keys of `torrent_bytes`, the file the torrent was added from (web
+/// seeds, v2 piece layers), are kept.
+fn torrent_file(torrent_bytes: &[u8], info_bytes: &[u8], tiers: &[Vec<Url>]) -> anyhow::Result<Vec<u8>> {
+    fn encode(value: impl serde::Serialize) -> anyhow::Result<RawValue<Vec<u8>>> {
+        let mut out = Vec::new();
+        librqbit_bencode::bencode_serialize_to_writer(value, &mut out)?;
+        Ok(RawValue(out))
+    }
+
+    // Torrents resolved from a magnet link have no file of their own
+    let original: HashMap<ByteBufOwned, BencodeValue<ByteBufOwned>> =
+        librqbit_bencode::from_bytes(torrent_bytes).unwrap_or_default();
+    // Sorted by key on the way out, as bencode requires
+    let mut keys = BTreeMap::new();
+    for (key, value) in original {
+        if !matches!(key.as_ref(), b"announce" | b"announce-list" | b"info") {
+            keys.insert(key, encode(value)?);
+        }
+    }
+    if let Some(first) = tiers.iter().flatten().next() {
+        keys.insert(ByteBufOwned::from(&b"announce"[..]), encode(first.as_str())?);
+        let tiers: Vec<Vec<&str>> = tiers.iter()
+            .map(|tier| tier.iter().map(Url::as_str).collect())
+            .collect();
+        keys.insert(ByteBufOwned::from(&b"announce-list"[..]), encode(tiers)?);
+    }
+    keys.insert(ByteBufOwned::from(&b"info"[..]), RawValue(info_bytes.to_vec()));
+    encode(keys).map(|raw| raw.0)
+}
+
+/// Id for a torrent about to be added, to pass as `preferred_id`
+pub fn allocate_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Options used for every torrent added for download at `location`
+pub fn download_options(location: &Arc<StorageLocation>) -> AddTorrentOptions {
+    AddTorrentOptions {
+        output_folder: Some(location.root().to_string_lossy().to_string()),
+        overwrite: true, // This should handle existing torrents
+        storage_factory: Some(DiskStorageFactory::boxed(location.clone())),
+        ..Default::default()
+    }
+}
+
+/// Everything needed to add a torrent back to a session
+pub struct SavedTorrent {
+    id: usize,
+    torrent: AddTorrent<'static>,
+    paused: bool,
+    only_files: Option<Vec<usize>>,
+}
+
+impl Clone for SavedTorrent {
+    fn clone(&self) -> Self {
+        SavedTorrent {
+            id: self.id,
+            torrent: match &self.torrent {
+                AddTorrent::Url(url) => AddTorrent::Url(url.clone()),
+                AddTorrent::TorrentFileBytes(bytes) => AddTorrent::TorrentFileBytes(bytes.clone()),
+            },
+            paused: self.paused,
+            only_files: self.only_files.clone(),
+        }
+    }
+}
+
+fn save(handle: &ManagedTorrent) -> SavedTorrent {
+    // The torrent file is rebuilt with the trackers librqbit used, which
+    // include any added with the torrent or with add_trackers
+    let tiers = tracker_tiers(handle);
+    let file = handle.with_metadata(|m| torrent_file(&m.torrent_bytes, &m.info_bytes, &tiers));
+    let torrent = match file {
+        Ok(Ok(file)) => AddTorrent::from_bytes(file),
+        // Still fetching metadata: resolve it again from the info hash
+        _ => {
+            let mut magnet = format!("magnet:?xt=urn:btih:{}", handle.info_hash().as_string());
+            for tracker in tiers.iter().flatten() {
+                magnet.push_str("&tr=");
+                magnet.extend(url::form_urlencoded::byte_serialize(tracker.as_str().as_bytes()));
+            }
+            AddTorrent::from_url(magnet)
+        }
+    };
+    SavedTorrent {
+        id: handle.id(),
+        torrent,
+        paused: handle.is_paused(),
+        only_files: handle.only_files(),
+    }
+}
+
+/// Add a saved torrent to a session. If that fails, the torrent stays in
+/// the registry as unadded, still flagged as being re-added so its tasks
+/// wait for it, and the next re-add or session restart tries again.
+async fn add_saved(session: &Arc<Session>, saved: SavedTorrent) -> anyhow::Result<()> {
+    let id = saved.id;
+    let options = match get(id) {
+        Some(entry) => AddTorrentOptions {
+            initial_peers: (!entry.peers.is_empty()).then_some(entry.peers),
+            ..download_options(&entry.location)
+        },
+        None => AddTorrentOptions::default(),
+    };
+
+    set_readding(id, true);
+    let copy = saved.clone();
+    let result = session.add_torrent(copy.torrent, Some(AddTorrentOptions {
+        paused: copy.paused,
+        only_files: copy.only_files,
+        preferred_id: Some(id),
+        ..options
+    })).await;
+    match result {
+        Ok(_) => {
+            set_readding(id, false);
+            Ok(())
+        }
+        Err(e) => {
+            update(id, |entry| entry.unadded = Some(saved));
+            Err(e)
+        }
+    }
+}
+
+fn take_unadded(id: usize) -> Option<SavedTorrent> {
+    with_torrents(|t| t.get_mut(&id).and_then(|e| e.unadded.take()))
+}
+
+/// Remove a torrent from the session and add it back under the same id with
+/// its registered options. librqbit re-verifies the data on disk as it would
+/// for a new torrent, and pieces that fail are downloaded again.
+///
+/// A torrent that a previous re-add failed to add back is added again. If
+/// adding it back fails, it is kept for the next try rather than lost.
+pub async fn readd(session: &Arc<Session>, id: usize) -> anyhow::Result<()> {
+    let saved = match session.get(TorrentIdOrHash::Id(id)) {
+        Some(handle) => {
+            let saved = save(&handle);
+            drop(handle);
+
+            // Flagged before the delete so status loops wait instead of ending
+            set_readding(id, true);
+            if let Err(e) = session.delete(TorrentIdOrHash::Id(id), false).await {
+                set_readding(id, false);
+                return Err(e);
+            }
+            saved
+        }
+        None => take_unadded(id).ok_or(anyhow::anyhow!("Torrent not found: {}", id))?,
+    };
+    add_saved(session, saved).await
+        .context("Failed to add the torrent back; it is tried again on the next recheck or session restart")
+}
+
+/// Save every torrent of a session that is about to be stopped, and those
+/// that failed to be added back to it
+pub fn save_all(session: &Session) -> Vec<SavedTorrent> {
+    let mut saved: Vec<SavedTorrent> = session.with_torrents(|torrents| {
+        torrents.map(|(_, handle)| save(handle)).collect()
+    });
+    saved.extend(take_all_unadded());
+    for torrent in &saved {
+        set_readding(torrent.id, true);
+    }
+    saved
+}
+
+/// Keep saved torrents that no session could be started for, to be added
+/// to the next one
+pub fn keep_unadded(saved: Vec<SavedTorrent>) {
+    for torrent in saved {
+        update(torrent.id, |e| e.unadded = Some(torrent.clone()));
+    }
+}
+
+/// Take every torrent that is waiting to be added back to a session
+pub fn take_all_unadded() -> Vec<SavedTorrent> {
+    with_torrents(|t| t.values_mut().filter_map(|e| e.unadded.take()).collect())
+}
+
+/// Add saved torrents to a new session. Torrents still fetching metadata
+/// take as long as that does, so each one is added in the background.
+pub fn restore_all(session: &Arc<Session>, saved: Vec<SavedTorrent>) {
+    for torrent in saved {
+        let session = session.clone();
+        tokio::spawn(async move {
+            let id = torrent.id;
+            if let Err(e) = add_saved(&session, torrent).await {
+                println!("SESSION: failed to restore torrent {}: {:#}", id, e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::StorageLocation;
+    use crate::test_util::{content, create_torrent, wait_for, with_session};
+
+    const INFO: &[u8] = b"d6:lengthi5e4:name5:a.txt12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaae";
+
+    /// A .torrent file with `before` and `after` the info dictionary
+    fn torrent(before: &[u8], after: &[u8]) -> Vec<u8> {
+        [b"d".as_slice(), before, b"4:info", INFO, after, b"e"].concat()
+    }
+
+    #[test]
+    fn rebuilt_torrents_keep_their_other_keys() {
+        let original = torrent(
+            b"8:announce14:http://old/ann7:comment2:hi",
+            b"12:piece layersd2:xx2:yye8:url-listl13:http://seed/ae",
+        );
+        let tiers = vec![
+            vec![Url::parse("http://a/announce").unwrap()],
+            vec![Url::parse("udp://b:80").unwrap(), Url::parse("udp://c:80").unwrap()],
+        ];
+        let file = torrent_file(&original, INFO, &tiers).unwrap();
+        assert_eq!(file, torrent(
+            b"8:announce17:http://a/announce13:announce-listll17:http://a/announceel10:udp://b:8010:udp://c:80ee7:comment2:hi",
+            b"12:piece layersd2:xx2:yye8:url-listl13:http://seed/ae",
+        ));
+        let before = torrent_from_bytes::<ByteBufOwned>(&original).unwrap();
+        let after = torrent_from_bytes::<ByteBufOwned>(&file).unwrap();
+        assert_eq!(before.info_hash, after.info_hash);
+    }
+
+    #[test]
+    fn rebuilt_torrents_without_trackers_or_a_file() {
+        let original = torrent(b"8:announce14:http://old/ann", b"");
+        assert_eq!(torrent_file(&original, INFO, &[]).unwrap(), torrent(b"", b""));
+        // Resolved from a magnet link
+        assert_eq!(torrent_file(b"", INFO, &[]).unwrap(), torrent(b"", b""));
+    }
+
+    #[test]
+    fn failed_readds_keep_the_torrent() {
+        with_session(async {
+            let dir = tempfile::tempdir().unwrap();
+            let root = dir.path().join("data");
+            std::fs::create_dir(&root).unwrap();
+            std::fs::write(root.join("a.bin"), content(50_000)).unwrap();
+            let created = create_torrent(&root.join("a.bin"), None);
+
+            let session = crate::get_session().await.unwrap();
+            let location = StorageLocation::new(&root, false);
+            let id = allocate_id();
+            register(id, location.clone(), None);
+            session.add_torrent(AddTorrent::from_bytes(created.torrent_bytes), Some(AddTorrentOptions {
+                preferred_id: Some(id),
+                ..download_options(&location)
+            })).await.unwrap();
+            let in_session = |session: &Arc<Session>| session.get(TorrentIdOrHash::Id(id));
+            wait_for("the data check", || in_session(&session).is_some_and(|h| h.stats().finished)).await;
+
+            // The folder is gone, a file in its place: the storage can't open
+            std::fs::rename(&root, dir.path().join("moved")).unwrap();
+            std::fs::write(&root, b"").unwrap();
+            let err = readd(&session, id).await.err().unwrap();
+            assert!(format!("{:#}", err).contains("tried again"), "{:#}", err);
+            assert!(in_session(&session).is_none());
+            assert!(get(id).unwrap().unadded.is_some() && is_readding(id));
+
+            // Still failing in a new session, and still kept
+            crate::replace_session().await.unwrap();
+            let session = crate::get_session().await.unwrap();
+            wait_for("the restore to fail", || get(id).unwrap().unadded.is_some()).await;
+            assert!(in_session(&session).is_none());
+
+            // Added back by the next re-add once the data is reachable again
+            std::fs::remove_file(&root).unwrap();
+            std::fs::rename(dir.path().join("moved"), &root).unwrap();
+            readd(&session, id).await.unwrap();
+            assert!(get(id).unwrap().unadded.is_none() && !is_readding(id));
+            wait_for("the data check", || in_session(&session).is_some_and(|h| h.stats().finished)).await;
+            session.delete(TorrentIdOrHash::Id(id), false).await.unwrap();
+        });
+    }
+}
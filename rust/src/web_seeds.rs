@@ -0,0 +1,518 @@
+// Web seeds (BEP 19): plain HTTP servers holding a copy of a torrent's files.
+//
+// librqbit only downloads from peers, so web seeds are handled here. A
+// worker per torrent fetches the pieces of its unfinished selected files
+// with Range requests, from the end of the torrent backwards so it meets
+// librqbit's downloads rather than racing them, checks them against the
+// piece hashes and writes them through the torrent's storage. librqbit
+// doesn't learn of pieces written behind its back, so once every wanted
+// piece is on disk the torrent is re-added, and librqbit verifies them as it
+// would existing data.
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
+use librqbit::api::TorrentIdOrHash;
+use librqbit::{ByteBufOwned, FileIteratorName, ManagedTorrent, TorrentStatsState};
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+use url::Url;
+use crate::storage::{file_layout, FileLayout, StorageLocation};
+use crate::{get_session, interface, proxy, torrents};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+/// Longest wait for one piece from a web seed
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+/// Wait before using a web seed again after it failed
+const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+/// Download rates are averaged over this window
+const RATE_WINDOW: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize)]
+struct UrlListTorrent {
+    #[serde(rename = "url-list")]
+    url_list: Option<UrlList>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UrlList {
+    One(ByteBufOwned),
+    Many(Vec<ByteBufOwned>),
+}
+
+/// The http(s) URLs among `urls`; others can't be fetched and are skipped
+pub fn parse_urls<'a>(urls: impl IntoIterator<Item = &'a str>) -> Vec<Url> {
+    let mut parsed: Vec<Url> = Vec::new();
+    for url in urls {
+        match Url::parse(url.trim()) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {
+                if !parsed.contains(&url) {
+                    parsed.push(url);
+                }
+            }
+            _ => println!("WEBSEED: skipping unsupported web seed: {}", url),
+        }
+    }
+    parsed
+}
+
+/// The web seeds in a .torrent file's url-list, a single URL or a list
+pub fn torrent_web_seeds(torrent_bytes: &[u8]) -> Vec<Url> {
+    let urls = match librqbit_bencode::from_bytes::<UrlListTorrent>(torrent_bytes) {
+        Ok(UrlListTorrent { url_list: Some(UrlList::One(url)) }) => vec![url],
+        Ok(UrlListTorrent { url_list: Some(UrlList::Many(urls)) }) => urls,
+        _ => Vec::new(),
+    };
+    let urls: Vec<String> = urls.iter()
+        .map(|url| String::from_utf8_lossy(url.as_ref()).to_string())
+        .filter(|url| !url.is_empty())
+        .collect();
+    parse_urls(urls.iter().map(|url| url.as_str()))
+}
+
+/// Downloads from one web seed
+#[derive(Debug, Clone, Default)]
+pub struct WebSeedState {
+    pub downloaded: u64,
+    /// Set if the last request failed
+    pub last_error: Option<String>,
+    failed_at: Option<Instant>,
+}
+
+#[derive(Default)]
+struct TorrentState {
+    seeds: HashMap<Url, WebSeedState>,
+    // Bytes received and when, within the rate window
+    received: VecDeque<(Instant, u64)>,
+}
+
+static STATES: LazyLock<Mutex<HashMap<usize, TorrentState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn with_state<R>(id: usize, f: impl FnOnce(&mut TorrentState) -> R) -> R {
+    let mut states = STATES.lock().unwrap_or_else(|e| e.into_inner());
+    let state = states.entry(id).or_default();
+    state.received.retain(|(at, _)| at.elapsed() < RATE_WINDOW);
+    f(state)
+}
+
+/// Downloads from the web seeds of a torrent so far
+pub fn seed_states(id: usize) -> HashMap<Url, WebSeedState> {
+    with_state(id, |s| s.seeds.clone())
+}
+
+/// Bytes per second received from a torrent's web seeds, and in total
+pub fn download_rate(id: usize) -> (f64, u64) {
+    with_state(id, |s| {
+        let recent: u64 = s.received.iter().map(|(_, bytes)| bytes).sum();
+        let total = s.seeds.values().map(|seed| seed.downloaded).sum();
+        (recent as f64 / RATE_WINDOW.as_secs_f64(), total)
+    })
+}
+
+fn record_received(id: usize, seed: &Url, bytes: u64) {
+    with_state(id, |s| {
+        s.seeds.entry(seed.clone()).or_default().downloaded += bytes;
+        s.received.push_back((Instant::now(), bytes));
+    });
+}
+
+fn record_result(id: usize, seed: &Url, error: Option<String>) {
+    with_state(id, |s| {
+        let state = s.seeds.entry(seed.clone()).or_default();
+        state.failed_at = error.as_ref().map(|_| Instant::now());
+        state.last_error = error;
+    });
+}
+
+fn is_usable(id: usize, seed: &Url) -> bool {
+    with_state(id, |s| {
+        s.seeds.get(seed)
+            .and_then(|state| state.failed_at)
+            .is_none_or(|at| at.elapsed() >= RETRY_INTERVAL)
+    })
+}
+
+/// Fetch pieces of a registered torrent from its web seeds while it is
+/// live, until it is removed from the session
+pub fn spawn_worker(id: usize) {
+    tokio::spawn(async move {
+        // Pieces known to be on disk, fetched here or found there already
+        let mut on_disk = HashSet::new();
+        let mut written = 0;
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+            let Ok(session) = get_session().await else {
+                continue;
+            };
+            let handle = match session.get(TorrentIdOrHash::Id(id)) {
+                Some(handle) => handle,
+                None if torrents::is_readding(id) => continue,
+                None => break, // Removed from the session
+            };
+            let Some(entry) = torrents::get(id) else {
+                break;
+            };
+            if entry.web_seeds.is_empty() || !is_active(&handle) || entry.move_progress.is_some() {
+                continue;
+            }
+
+            match fetch_missing(&handle, &entry.location, &entry.web_seeds, &mut on_disk, &mut written).await {
+                Ok(true) if written > 0 => {
+                    println!("WEBSEED: torrent {}: {} pieces fetched, re-adding it to verify them", id, written);
+                    written = 0;
+                    on_disk.clear();
+                    if let Err(e) = torrents::readd(&session, id).await {
+                        println!("WEBSEED: failed to re-add torrent {}: {:#}", id, e);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => println!("WEBSEED: torrent {}: {:#}", id, e),
+            }
+        }
+        STATES.lock().unwrap_or_else(|e| e.into_inner()).remove(&id);
+    });
+}
+
+fn is_active(handle: &ManagedTorrent) -> bool {
+    matches!(handle.stats().state, TorrentStatsState::Live) && !handle.is_paused() && interface::is_up()
+}
+
+/// A file of the torrent, with the path segments web seed URLs take for it
+struct SeedFile {
+    layout: FileLayout,
+    // None for single-file torrents
+    segments: Option<Vec<String>>,
+}
+
+/// Fetch the wanted pieces that aren't on disk yet, for as long as the
+/// torrent stays live. Returns true once every wanted piece is on disk,
+/// false if there are none or some are still missing.
+async fn fetch_missing(
+    handle: &ManagedTorrent,
+    location: &Arc<StorageLocation>,
+    seeds: &[Url],
+    on_disk: &mut HashSet<usize>,
+    written: &mut usize,
+) -> Result<bool> {
+    let info = handle.with_metadata(|m| m.info.clone())?;
+    let name = info.name.as_ref()
+        .map(|n| String::from_utf8_lossy(n.as_ref()).to_string())
+        .unwrap_or_default();
+    let files: Vec<SeedFile> = file_layout(&info)?
+        .into_iter()
+        .zip(info.iter_file_details()?)
+        .map(|(layout, details)| SeedFile {
+            layout,
+            segments: match details.filename {
+                FileIteratorName::Single(_) => None,
+                FileIteratorName::Tree(parts) => Some(parts.iter()
+                    .map(|p| String::from_utf8_lossy(p.as_ref()).to_string())
+                    .collect()),
+            },
+        })
+        .collect();
+
+    let piece_length = info.piece_length as u64;
+    let total_length: u64 = files.iter().map(|f| f.layout.length).sum();
+    let only_files = handle.only_files();
+    let progress = handle.stats().file_progress;
+    let pieces: BTreeSet<usize> = files.iter()
+        .enumerate()
+        .filter(|(index, file)| {
+            !file.layout.padding
+                && file.layout.length > 0
+                && only_files.as_ref().is_none_or(|only| only.contains(index))
+                && progress.get(*index).copied().unwrap_or(0) < file.layout.length
+        })
+        .flat_map(|(_, file)| {
+            let start = file.layout.offset / piece_length;
+            let end = (file.layout.offset + file.layout.length - 1) / piece_length;
+            start as usize..=end as usize
+        })
+        .collect();
+    if pieces.is_empty() {
+        return Ok(false);
+    }
+
+    let storage = location.active_storage(piece_length)?;
+    let client = proxy::http_client(proxy::peer_proxy())?;
+    for &piece in pieces.iter().rev() {
+        if on_disk.contains(&piece) {
+            continue;
+        }
+        if !is_active(handle) {
+            return Ok(false);
+        }
+        let start = piece as u64 * piece_length;
+        let length = piece_length.min(total_length - start) as usize;
+        let hash = info.pieces.as_ref().get(piece * 20..piece * 20 + 20)
+            .context(format!("No hash for piece {}", piece))?;
+
+        // Already there, e.g. downloaded by librqbit or before a restart
+        if storage.read_piece(piece, length).is_ok_and(|data| Sha1::digest(&data).as_slice() == hash) {
+            on_disk.insert(piece);
+            continue;
+        }
+
+        let Some(data) = fetch_piece(handle.id(), &client, seeds, &name, &files, start, length, hash).await else {
+            return Ok(false); // No web seed could send it
+        };
+        storage.write_piece(piece, &data)?;
+        on_disk.insert(piece);
+        *written += 1;
+    }
+    Ok(true)
+}
+
+/// Fetch a piece from the first web seed that sends it intact
+#[allow(clippy::too_many_arguments)]
+async fn fetch_piece(
+    id: usize,
+    client: &reqwest::Client,
+    seeds: &[Url],
+    name: &str,
+    files: &[SeedFile],
+    start: u64,
+    length: usize,
+    hash: &[u8],
+) -> Option<Vec<u8>> {
+    for seed in seeds.iter().filter(|seed| is_usable(id, seed)) {
+        let result = tokio::time::timeout(REQUEST_TIMEOUT, fetch_range(id, client, seed, name, files, start, length))
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("Web seed timed out")))
+            .and_then(|data| {
+                if Sha1::digest(&data).as_slice() != hash {
+                    bail!("Web seed sent data that failed verification");
+                }
+                Ok(data)
+            });
+        match result {
+            Ok(data) => {
+                record_result(id, seed, None);
+                return Some(data);
+            }
+            Err(e) => {
+                println!("WEBSEED: {}: {:#}", seed, e);
+                record_result(id, seed, Some(format!("{:#}", e)));
+            }
+        }
+    }
+    None
+}
+
+/// URL of a file on a web seed. As BEP 19 has it, a single-file torrent's
+/// URL points at the file unless it ends in a slash, and multi-file URLs
+/// point at the directory holding the torrent's folder.
+fn file_url(seed: &Url, name: &str, segments: Option<&[String]>) -> Result<Url> {
+    if segments.is_none() && !seed.path().ends_with('/') {
+        return Ok(seed.clone());
+    }
+    let mut url = seed.clone();
+    url.path_segments_mut()
+        .map_err(|_| anyhow::anyhow!("Invalid web seed URL: {}", seed))?
+        .pop_if_empty()
+        .push(name)
+        .extend(segments.unwrap_or_default());
+    Ok(url)
+}
+
+/// Fetch `length` bytes at `start` of the torrent's data, with one Range
+/// request per file they span. Padding files are zeroes, not fetched.
+async fn fetch_range(
+    id: usize,
+    client: &reqwest::Client,
+    seed: &Url,
+    name: &str,
+    files: &[SeedFile],
+    start: u64,
+    length: usize,
+) -> Result<Vec<u8>> {
+    let end = start + length as u64;
+    let mut data = Vec::with_capacity(length);
+    for file in files {
+        let file_start = file.layout.offset;
+        let file_end = file.layout.offset + file.layout.length;
+        if file_end <= start || file_start >= end {
+            continue;
+        }
+        let from = start.max(file_start) - file_start;
+        let to = end.min(file_end) - file_start;
+        let expected = data.len() + (to - from) as usize;
+        if file.layout.padding {
+            data.resize(expected, 0);
+            continue;
+        }
+
+        let url = file_url(seed, name, file.segments.as_deref())?;
+        let mut response = client.get(url)
+            .header(reqwest::header::RANGE, format!("bytes={}-{}", from, to - 1))
+            .send().await?;
+        let whole_file = from == 0 && to == file.layout.length;
+        match response.status() {
+            reqwest::StatusCode::PARTIAL_CONTENT => {}
+            reqwest::StatusCode::OK if whole_file => {}
+            status => bail!("Web seed responded with {}", status),
+        }
+        while let Some(chunk) = response.chunk().await? {
+            if data.len() + chunk.len() > expected {
+                bail!("Web seed sent more data than requested");
+            }
+            data.extend_from_slice(&chunk);
+            record_received(id, seed, chunk.len() as u64);
+        }
+        if data.len() != expected {
+            bail!("Web seed sent less data than requested");
+        }
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sanitize::sanitize_path;
+    use crate::test_util::{HttpRequest, HttpResponse, HttpServer};
+
+    const A: &[u8] = b"0123456789";
+    const C: &[u8] = b"abcdefghijklmnopqrst";
+
+    /// "t/a" (10 bytes), 6 bytes of padding, then "t/b/c" (20 bytes)
+    fn files() -> Vec<SeedFile> {
+        let file = |segments: &[&str], offset, length, padding| SeedFile {
+            layout: FileLayout {
+                path: sanitize_path(std::iter::once("t").chain(segments.iter().copied()).map(str::as_bytes)).unwrap(),
+                offset,
+                length,
+                padding,
+            },
+            segments: Some(segments.iter().map(|s| s.to_string()).collect()),
+        };
+        vec![
+            file(&["a"], 0, 10, false),
+            file(&[".pad", "6"], 10, 6, true),
+            file(&["b", "c"], 16, 20, false),
+        ]
+    }
+
+    /// Serves the files' ranges, or the whole file without a Range header
+    fn serve(request: &HttpRequest) -> HttpResponse {
+        let file = match request.target.as_str() {
+            "/seed/t/a" => A,
+            "/seed/t/b/c" => C,
+            _ => return HttpResponse::status(404),
+        };
+        let Some(range) = request.header("Range").and_then(|r| r.strip_prefix("bytes=")) else {
+            return HttpResponse::ok(file);
+        };
+        let (from, to) = range.split_once('-').unwrap();
+        let (from, to): (usize, usize) = (from.parse().unwrap(), to.parse().unwrap());
+        let mut response = HttpResponse::ok(&file[from..=to]);
+        response.status = 206;
+        response
+    }
+
+    fn seed_url(server: &HttpServer, path: &str) -> Url {
+        Url::parse(&server.url(path)).unwrap()
+    }
+
+    #[test]
+    fn builds_file_urls() {
+        let seed = Url::parse("http://host/files").unwrap();
+        let dir = Url::parse("http://host/files/").unwrap();
+        let segments = ["sub dir".to_string(), "a#b.txt".to_string()];
+        // (seed, segments, url)
+        let cases = [
+            (&seed, None, "http://host/files"),
+            (&dir, None, "http://host/files/name"),
+            (&seed, Some(&segments[..]), "http://host/files/name/sub%20dir/a%23b.txt"),
+            (&dir, Some(&segments[..]), "http://host/files/name/sub%20dir/a%23b.txt"),
+        ];
+        for (seed, segments, url) in cases {
+            assert_eq!(file_url(seed, "name", segments).unwrap().as_str(), url);
+        }
+    }
+
+    #[tokio::test]
+    async fn fetches_ranges_across_files() {
+        let server = HttpServer::start(serve).await;
+        let seed = seed_url(&server, "/seed/");
+        let client = reqwest::Client::new();
+        let files = files();
+
+        // The end of a, the padding, and the start of c
+        let data = fetch_range(1001, &client, &seed, "t", &files, 4, 20).await.unwrap();
+        assert_eq!(data, [&A[4..], &[0; 6], &C[..8]].concat());
+        let ranges: Vec<_> = server.requests().iter()
+            .map(|r| (r.target.clone(), r.header("Range").unwrap_or_default().to_string()))
+            .collect();
+        assert_eq!(ranges, [
+            ("/seed/t/a".to_string(), "bytes=4-9".to_string()),
+            ("/seed/t/b/c".to_string(), "bytes=0-7".to_string()),
+        ]);
+        assert_eq!(download_rate(1001).1, 14);
+
+        // A whole file may come back as 200 rather than 206
+        let server = HttpServer::start(|request| {
+            let mut response = serve(request);
+            response.status = 200;
+            response
+        }).await;
+        let seed = seed_url(&server, "/seed");
+        let data = fetch_range(1002, &client, &seed, "t", &files, 0, 16).await.unwrap();
+        assert_eq!(data, [A, &[0; 6]].concat());
+        let err = fetch_range(1002, &client, &seed, "t", &files, 16, 16).await.err().unwrap();
+        assert!(err.to_string().contains("responded with 200 OK"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_responses() {
+        let client = reqwest::Client::new();
+        let files = files();
+        type Respond = fn(&HttpRequest) -> HttpResponse;
+        // (response, error)
+        let cases: [(Respond, &str); 4] = [
+            (|_| HttpResponse::status(404), "responded with 404 Not Found"),
+            (|_| HttpResponse::status(500), "responded with 500 Internal Server Error"),
+            (|_| HttpResponse { status: 206, ..HttpResponse::ok(&A[..4]) }, "less data than requested"),
+            (|_| HttpResponse { status: 206, send_length: false, ..HttpResponse::ok(A) }, "more data than requested"),
+        ];
+        for (respond, error) in cases {
+            let server = HttpServer::start(respond).await;
+            let err = fetch_range(1003, &client, &seed_url(&server, "/"), "t", &files, 0, 8).await.err().unwrap();
+            assert!(err.to_string().contains(error), "{}: {}", error, err);
+        }
+    }
+
+    #[tokio::test]
+    async fn pieces_come_from_the_first_seed_that_sends_them_intact() {
+        let good = HttpServer::start(serve).await;
+        let corrupt = HttpServer::start(|request| {
+            let mut response = serve(request);
+            response.body.iter_mut().for_each(|b| *b = b'x');
+            response
+        }).await;
+        let missing = HttpServer::start(|_| HttpResponse::status(404)).await;
+        let seeds = [seed_url(&missing, "/seed/"), seed_url(&corrupt, "/seed/"), seed_url(&good, "/seed/")];
+        let client = reqwest::Client::new();
+        let files = files();
+
+        let piece = [A, &[0; 6]].concat();
+        let hash = Sha1::digest(&piece);
+        let data = fetch_piece(1004, &client, &seeds, "t", &files, 0, 16, &hash).await;
+        assert_eq!(data.unwrap(), piece);
+
+        let states = seed_states(1004);
+        assert!(states[&seeds[0]].last_error.as_ref().unwrap().contains("404"));
+        assert!(states[&seeds[1]].last_error.as_ref().unwrap().contains("failed verification"));
+        assert_eq!(states[&seeds[2]].last_error, None);
+        assert_eq!(states[&seeds[2]].downloaded, 10);
+
+        // Failed seeds are left alone for a while
+        assert!(!is_usable(1004, &seeds[0]) && !is_usable(1004, &seeds[1]));
+        let data = fetch_piece(1004, &client, &seeds[..2], "t", &files, 0, 16, &hash).await;
+        assert!(data.is_none());
+        assert_eq!(missing.requests().len(), 1);
+    }
+}